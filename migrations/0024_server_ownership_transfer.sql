@@ -0,0 +1,2 @@
+-- ── Server Ownership Transfer ──────────────────────
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'server_owner_transfer';
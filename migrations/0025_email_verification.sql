@@ -0,0 +1,17 @@
+ALTER TABLE users ADD COLUMN email_verified BOOLEAN NOT NULL DEFAULT false;
+
+-- Accounts created before verification existed are treated as verified
+UPDATE users SET email_verified = true;
+
+-- A token confirms ownership of `email` for `user_id`. When `email` differs from
+-- the user's current address, confirming it completes an email change.
+CREATE TABLE email_verification_tokens (
+    id             UUID PRIMARY KEY,
+    user_id        UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    email          TEXT NOT NULL,
+    token_hash     TEXT NOT NULL UNIQUE,
+    expires_at     TIMESTAMPTZ NOT NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_email_verification_tokens_user ON email_verification_tokens(user_id);
@@ -0,0 +1,13 @@
+-- When the user last had a gateway connection open (set on identify and disconnect)
+ALTER TABLE users ADD COLUMN last_seen_at TIMESTAMPTZ;
+UPDATE users SET last_seen_at = now();
+
+-- Per-user unread-mention digest settings. Users without a row get the
+-- instance default frequency.
+CREATE TABLE email_digest_settings (
+    user_id            UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    frequency          TEXT NOT NULL DEFAULT 'daily' CHECK (frequency IN ('daily', 'weekly', 'never')),
+    unsubscribe_token  TEXT NOT NULL UNIQUE,
+    last_digest_at     TIMESTAMPTZ,
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,38 @@
+-- ── Registration Modes ──────────────────────────────
+CREATE TYPE registration_mode AS ENUM ('open', 'invite_only', 'approval', 'closed');
+
+-- Single-row table of instance settings that admins can change at runtime
+CREATE TABLE instance_settings (
+    id                      BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    registration_mode       registration_mode NOT NULL DEFAULT 'invite_only',
+    -- Sign-up questionnaire: a JSON array of question strings
+    registration_questions  JSONB NOT NULL DEFAULT '[]',
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+INSERT INTO instance_settings DEFAULT VALUES;
+
+-- ── Registration Requests ───────────────────────────
+-- Sign-ups with their questionnaire answers. In approval mode these wait as
+-- 'pending' until an admin decides; otherwise they're recorded as 'approved'.
+CREATE TYPE registration_request_status AS ENUM ('pending', 'approved', 'rejected');
+
+CREATE TABLE registration_requests (
+    id             UUID PRIMARY KEY,
+    username       TEXT NOT NULL,
+    email          TEXT NOT NULL,
+    password_hash  TEXT,
+    answers        JSONB NOT NULL DEFAULT '[]',
+    status         registration_request_status NOT NULL DEFAULT 'pending',
+    user_id        UUID REFERENCES users(id) ON DELETE SET NULL,
+    reviewer_id    UUID REFERENCES users(id) ON DELETE SET NULL,
+    review_reason  TEXT,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    reviewed_at    TIMESTAMPTZ
+);
+
+CREATE INDEX idx_registration_requests_status ON registration_requests(status, created_at);
+CREATE UNIQUE INDEX idx_registration_requests_pending_email
+    ON registration_requests(email) WHERE status = 'pending';
+CREATE UNIQUE INDEX idx_registration_requests_pending_username
+    ON registration_requests(username) WHERE status = 'pending';
@@ -0,0 +1,33 @@
+-- ── Member Timeouts ─────────────────────────────────
+-- While set and in the future, the member can read but not post
+ALTER TABLE server_members ADD COLUMN communication_disabled_until TIMESTAMPTZ;
+
+-- ── AutoMod ─────────────────────────────────────────
+CREATE TYPE automod_trigger_type AS ENUM (
+    'keyword', 'regex', 'invite_link', 'mention_spam', 'repeated_message', 'link_filter'
+);
+
+CREATE TABLE automod_rules (
+    id                  UUID PRIMARY KEY,
+    server_id           UUID NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    creator_id          UUID REFERENCES users(id) ON DELETE SET NULL,
+    name                TEXT NOT NULL,
+    enabled             BOOLEAN NOT NULL DEFAULT true,
+    trigger_type        automod_trigger_type NOT NULL,
+    -- Keywords, regex patterns, limits and domain lists depending on trigger_type
+    trigger_metadata    JSONB NOT NULL DEFAULT '{}',
+    -- Array of {"type": "block_message" | "delete_message" | "timeout" | "send_alert", ...}
+    actions             JSONB NOT NULL DEFAULT '[]',
+    exempt_role_ids     UUID[] NOT NULL DEFAULT '{}',
+    exempt_channel_ids  UUID[] NOT NULL DEFAULT '{}',
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_automod_rules_server ON automod_rules(server_id);
+
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'automod_rule_create';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'automod_rule_update';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'automod_rule_delete';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'automod_trigger';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'member_timeout';
@@ -0,0 +1,37 @@
+-- ── Reports ─────────────────────────────────────────
+CREATE TYPE report_category AS ENUM (
+    'spam', 'harassment', 'hate', 'nsfw', 'violence', 'self_harm', 'impersonation', 'other'
+);
+CREATE TYPE report_status AS ENUM ('open', 'claimed', 'resolved', 'dismissed');
+
+-- A report against a message or a user. Reports with a server_id go to that
+-- server's moderation queue; reports without one (DMs, profiles) and reports
+-- escalated by server moderators go to the instance admins.
+CREATE TABLE reports (
+    id                UUID PRIMARY KEY,
+    server_id         UUID REFERENCES servers(id) ON DELETE CASCADE,
+    reporter_id       UUID REFERENCES users(id) ON DELETE SET NULL,
+    target_user_id    UUID REFERENCES users(id) ON DELETE SET NULL,
+    channel_id        UUID REFERENCES channels(id) ON DELETE SET NULL,
+    message_id        UUID REFERENCES messages(id) ON DELETE SET NULL,
+    -- Snapshot so the report still makes sense after the message is edited or deleted
+    message_content   TEXT,
+    category          report_category NOT NULL,
+    reason            TEXT,
+    status            report_status NOT NULL DEFAULT 'open',
+    assignee_id       UUID REFERENCES users(id) ON DELETE SET NULL,
+    resolution_note   TEXT,
+    action_taken      TEXT,
+    escalated         BOOLEAN NOT NULL DEFAULT false,
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT now(),
+    closed_at         TIMESTAMPTZ
+);
+
+CREATE INDEX idx_reports_server_status ON reports(server_id, status, created_at);
+CREATE INDEX idx_reports_instance_queue ON reports(status, created_at)
+    WHERE server_id IS NULL OR escalated;
+
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'report_resolve';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'report_dismiss';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'report_escalate';
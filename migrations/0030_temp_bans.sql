@@ -0,0 +1,5 @@
+-- ── Temporary Bans ──────────────────────────────────
+-- NULL = permanent; otherwise the scheduler lifts the ban after this time
+ALTER TABLE bans ADD COLUMN expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_bans_expires_at ON bans(expires_at) WHERE expires_at IS NOT NULL;
@@ -0,0 +1,2 @@
+-- ── Bulk Message Delete ────────────────────────────
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'message_bulk_delete';
@@ -0,0 +1,3 @@
+-- ── Slowmode ───────────────────────────────────────
+-- Seconds a member must wait between messages in a text channel or thread (0 = off)
+ALTER TABLE channels ADD COLUMN rate_limit_per_user INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,28 @@
+-- ── Verification Levels & Raid Protection ──────────
+CREATE TYPE verification_level AS ENUM ('none', 'low', 'medium', 'high');
+
+-- One row per server, created on first change. Servers without a row have no
+-- verification requirements and no raid protection.
+CREATE TABLE server_safety_settings (
+    server_id               UUID PRIMARY KEY REFERENCES servers(id) ON DELETE CASCADE,
+    verification_level      verification_level NOT NULL DEFAULT 'none',
+    raid_protection         BOOLEAN NOT NULL DEFAULT false,
+    -- Joins per raid_window_secs that count as a raid, server-wide and per invite
+    raid_join_threshold     INTEGER NOT NULL DEFAULT 20,
+    raid_invite_threshold   INTEGER NOT NULL DEFAULT 10,
+    raid_window_secs        INTEGER NOT NULL DEFAULT 60,
+    -- What an automatic lockdown does and how long it lasts
+    raid_pause_invites      BOOLEAN NOT NULL DEFAULT true,
+    raid_quarantine_joins   BOOLEAN NOT NULL DEFAULT true,
+    raid_lockdown_secs      INTEGER NOT NULL DEFAULT 1800,
+    -- Current lockdown; NULL lockdown_until means until lifted by a moderator
+    lockdown_since          TIMESTAMPTZ,
+    lockdown_until          TIMESTAMPTZ,
+    lockdown_pause_invites  BOOLEAN NOT NULL DEFAULT false,
+    lockdown_quarantine     BOOLEAN NOT NULL DEFAULT false,
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'server_safety_update';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'server_lockdown';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'raid_detected';
@@ -0,0 +1,29 @@
+-- ── Member Screening ───────────────────────────────
+-- Rules new members must accept before they can chat. Rules and questions
+-- are JSON arrays of strings.
+CREATE TABLE server_screening (
+    server_id         UUID PRIMARY KEY REFERENCES servers(id) ON DELETE CASCADE,
+    enabled           BOOLEAN NOT NULL DEFAULT false,
+    description       TEXT,
+    rules             JSONB NOT NULL DEFAULT '[]',
+    questions         JSONB NOT NULL DEFAULT '[]',
+    -- The one channel pending members can read
+    rules_channel_id  UUID REFERENCES channels(id) ON DELETE SET NULL,
+    -- Role granted on acceptance
+    accept_role_id    UUID REFERENCES roles(id) ON DELETE SET NULL,
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Members who joined while screening was on and haven't accepted yet
+ALTER TABLE server_members ADD COLUMN pending BOOLEAN NOT NULL DEFAULT false;
+
+-- What members answered when they accepted, stored with the questions as asked
+CREATE TABLE screening_responses (
+    server_id    UUID NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    user_id      UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    answers      JSONB NOT NULL DEFAULT '[]',
+    accepted_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (server_id, user_id)
+);
+
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'screening_update';
@@ -0,0 +1,13 @@
+-- ── Audit Coverage ─────────────────────────────────
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'member_role_add';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'member_role_remove';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'channel_override_update';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'channel_override_delete';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'soundboard_sound_create';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'soundboard_sound_delete';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'thread_update';
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'poll_close';
+
+-- Filtering the log by target
+CREATE INDEX IF NOT EXISTS idx_audit_log_target ON audit_log(server_id, target_id)
+    WHERE target_id IS NOT NULL;
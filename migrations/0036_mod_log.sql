@@ -0,0 +1,15 @@
+-- ── Mod Log Channel ────────────────────────────────
+-- Where a server's audit entries and member/message events are posted, and
+-- which categories to post. No row (or no channel) means logging is off.
+CREATE TABLE server_log_settings (
+    server_id            UUID PRIMARY KEY REFERENCES servers(id) ON DELETE CASCADE,
+    channel_id           UUID REFERENCES channels(id) ON DELETE SET NULL,
+    log_moderation       BOOLEAN NOT NULL DEFAULT true,
+    log_member_joins     BOOLEAN NOT NULL DEFAULT true,
+    log_member_leaves    BOOLEAN NOT NULL DEFAULT true,
+    log_message_edits    BOOLEAN NOT NULL DEFAULT true,
+    log_message_deletes  BOOLEAN NOT NULL DEFAULT true,
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+ALTER TYPE audit_action ADD VALUE IF NOT EXISTS 'log_settings_update';
@@ -0,0 +1,17 @@
+-- ── Message Edit History ───────────────────────────
+-- Earlier versions of edited messages. Each revision holds the content a
+-- message had from `created_at` until an edit replaced it at `replaced_at`.
+-- Revisions go away with their message.
+CREATE TABLE message_revisions (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    message_id   UUID NOT NULL REFERENCES messages(id) ON DELETE CASCADE,
+    content      TEXT,
+    created_at   TIMESTAMPTZ NOT NULL,
+    replaced_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_message_revisions_message ON message_revisions(message_id, replaced_at);
+CREATE INDEX idx_message_revisions_replaced ON message_revisions(replaced_at);
+
+-- Days to keep revisions for; 0 keeps them as long as the message exists
+ALTER TABLE servers ADD COLUMN edit_history_retention_days INTEGER NOT NULL DEFAULT 0;
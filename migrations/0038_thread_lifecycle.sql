@@ -0,0 +1,28 @@
+-- ── Thread Lifecycle ───────────────────────────────
+-- Who started the thread, when it was archived, and how long it may sit
+-- idle before the scheduler archives it.
+ALTER TABLE thread_metadata
+    ADD COLUMN owner_id              UUID REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN auto_archive_minutes  INTEGER NOT NULL DEFAULT 1440,
+    ADD COLUMN archived_at           TIMESTAMPTZ,
+    ADD COLUMN last_activity_at      TIMESTAMPTZ NOT NULL DEFAULT now();
+
+UPDATE thread_metadata SET last_activity_at = created_at;
+
+CREATE INDEX idx_thread_metadata_active ON thread_metadata(last_activity_at)
+    WHERE archived = false;
+
+-- Members following a thread; only they are notified of its messages
+CREATE TABLE thread_members (
+    channel_id  UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    user_id     UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    joined_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (channel_id, user_id)
+);
+
+CREATE INDEX idx_thread_members_user ON thread_members(user_id);
+
+-- Posting in threads now needs SEND_MESSAGES_IN_THREADS (1 << 38); keep
+-- existing servers working by granting it wherever @everyone can send.
+UPDATE roles SET permissions = permissions | (1::BIGINT << 38)
+WHERE is_default AND permissions & (1::BIGINT << 11) <> 0;
@@ -0,0 +1,26 @@
+-- ── Forum Channels ─────────────────────────────────
+-- Forums hold no messages of their own; every top-level post is a thread.
+ALTER TYPE channel_type ADD VALUE IF NOT EXISTS 'forum';
+
+-- Whether new forum posts must carry at least one tag
+ALTER TABLE channels ADD COLUMN require_tag BOOLEAN NOT NULL DEFAULT false;
+
+-- The tag set a forum's posts can pick from
+CREATE TABLE forum_tags (
+    id          UUID PRIMARY KEY,
+    channel_id  UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    name        TEXT NOT NULL,
+    emoji       TEXT,
+    position    INTEGER NOT NULL DEFAULT 0,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (channel_id, name)
+);
+
+-- Tags applied to a forum post (keyed by the post's thread channel)
+CREATE TABLE forum_post_tags (
+    channel_id  UUID NOT NULL REFERENCES channels(id) ON DELETE CASCADE,
+    tag_id      UUID NOT NULL REFERENCES forum_tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (channel_id, tag_id)
+);
+
+CREATE INDEX idx_forum_post_tags_tag ON forum_post_tags(tag_id);
@@ -0,0 +1,17 @@
+-- ── Announcement Channels ──────────────────────────
+ALTER TYPE channel_type ADD VALUE IF NOT EXISTS 'announcement';
+
+-- A follower webhook relays messages published in `source_channel_id`
+-- (an announcement channel, usually on another server) into its channel.
+ALTER TABLE webhooks
+    ADD COLUMN source_channel_id UUID REFERENCES channels(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_webhooks_source_channel ON webhooks(source_channel_id)
+    WHERE source_channel_id IS NOT NULL;
+
+-- Announcement messages that have been pushed out to followers
+CREATE TABLE published_messages (
+    message_id    UUID PRIMARY KEY REFERENCES messages(id) ON DELETE CASCADE,
+    published_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    published_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,14 @@
+-- ── Stage Channels ─────────────────────────────────
+-- Voice channels where only approved speakers publish audio and everyone
+-- else listens.
+ALTER TYPE channel_type ADD VALUE IF NOT EXISTS 'stage';
+
+-- A live stage and its topic; the row is removed when the stage ends
+CREATE TABLE stage_instances (
+    channel_id  UUID PRIMARY KEY REFERENCES channels(id) ON DELETE CASCADE,
+    server_id   UUID NOT NULL REFERENCES servers(id) ON DELETE CASCADE,
+    topic       TEXT NOT NULL,
+    started_by  UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
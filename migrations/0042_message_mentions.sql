@@ -0,0 +1,8 @@
+-- ── Message Mentions ─────────────────────────────────
+-- Who a message actually pinged, resolved when it was sent: users named
+-- directly, roles mentioned with <@&role_id>, and whether @everyone/@here
+-- went through.
+ALTER TABLE messages
+    ADD COLUMN mentions         UUID[]  NOT NULL DEFAULT '{}',
+    ADD COLUMN mention_roles    UUID[]  NOT NULL DEFAULT '{}',
+    ADD COLUMN mention_everyone BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,4 @@
+-- ── Silent Messages ─────────────────────────────────
+-- Messages sent with `silent` are delivered normally but don't bump mention
+-- counts or send push notifications; clients skip sounds for them too.
+ALTER TABLE messages ADD COLUMN silent BOOLEAN NOT NULL DEFAULT FALSE;
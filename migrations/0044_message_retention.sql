@@ -0,0 +1,22 @@
+-- ── Message Retention ─────────────────────────────────
+-- Per-channel (and per-DM) limits on how long messages are kept: a maximum
+-- age in days, and a disappearing-message timer counted from sending.
+-- Admins can cap retention for the whole instance. 0 means no limit.
+ALTER TABLE channels
+    ADD COLUMN retention_days      INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN message_ttl_minutes INTEGER NOT NULL DEFAULT 0;
+
+-- Stamped at send time from the channel's timer, so changing the timer only
+-- affects new messages
+ALTER TABLE messages ADD COLUMN expires_at TIMESTAMPTZ;
+CREATE INDEX idx_messages_expires_at ON messages(expires_at) WHERE expires_at IS NOT NULL;
+CREATE INDEX idx_messages_created_at ON messages(created_at);
+
+ALTER TABLE instance_settings
+    ADD COLUMN message_retention_days INTEGER NOT NULL DEFAULT 0;
+
+-- Purged messages can still have replies; keep the replies and drop the link
+ALTER TABLE messages
+    DROP CONSTRAINT messages_reply_to_id_fkey,
+    ADD CONSTRAINT messages_reply_to_id_fkey
+        FOREIGN KEY (reply_to_id) REFERENCES messages(id) ON DELETE SET NULL;
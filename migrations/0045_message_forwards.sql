@@ -0,0 +1,16 @@
+-- ── Message Forwards ─────────────────────────────────
+-- A forwarded message carries a snapshot of the original, taken when it was
+-- forwarded, so it survives edits and deletion of the source. The source
+-- references are only shown to viewers who can see the source channel.
+CREATE TABLE message_forwards (
+    message_id         UUID PRIMARY KEY REFERENCES messages(id) ON DELETE CASCADE,
+    source_message_id  UUID REFERENCES messages(id) ON DELETE SET NULL,
+    source_channel_id  UUID REFERENCES channels(id) ON DELETE SET NULL,
+    content            TEXT,
+    attachments        JSONB NOT NULL DEFAULT '[]',
+    source_created_at  TIMESTAMPTZ NOT NULL,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Lets the retention purge keep files that a snapshot still shows
+CREATE INDEX idx_message_forwards_attachments ON message_forwards USING GIN (attachments jsonb_path_ops);
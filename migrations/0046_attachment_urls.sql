@@ -0,0 +1,3 @@
+-- Follower copies of announcements share the source's files; the retention
+-- purge looks up other messages still using a URL before deleting it
+CREATE INDEX idx_attachments_url ON attachments(url);
@@ -13,7 +13,11 @@ use crate::state::AppState;
 use chrono::DateTime;
 use serde::Serialize;
 
-use crate::types::entities::CreateRegistrationCodeRequest;
+use crate::services::auth as auth_service;
+use crate::types::entities::{
+    CloseReportRequest, CreateRegistrationCodeRequest, RegistrationRequestStatus, ReportQuery,
+    ReportStatus, UpdateRegistrationSettingsRequest, UpdateRetentionSettingsRequest,
+};
 
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -35,6 +39,40 @@ pub fn routes() -> Router<AppState> {
             "/channels/{channel_id}/messages",
             axum::routing::delete(admin_purge_channel),
         )
+        .route(
+            "/servers/{server_id}/transfer",
+            axum::routing::post(admin_transfer_server),
+        )
+        .route(
+            "/registration-settings",
+            get(get_registration_settings).patch(update_registration_settings),
+        )
+        .route(
+            "/retention-settings",
+            get(get_retention_settings).patch(update_retention_settings),
+        )
+        .route("/registration-requests", get(list_registration_requests))
+        .route(
+            "/registration-requests/{request_id}/approve",
+            axum::routing::post(approve_registration_request),
+        )
+        .route(
+            "/registration-requests/{request_id}/reject",
+            axum::routing::post(reject_registration_request),
+        )
+        .route("/reports", get(list_instance_reports))
+        .route(
+            "/reports/{report_id}/claim",
+            axum::routing::post(admin_claim_report),
+        )
+        .route(
+            "/reports/{report_id}/resolve",
+            axum::routing::post(admin_resolve_report),
+        )
+        .route(
+            "/reports/{report_id}/dismiss",
+            axum::routing::post(admin_dismiss_report),
+        )
 }
 
 fn generate_code() -> String {
@@ -226,3 +264,263 @@ async fn admin_purge_channel(
 
     Ok(Json(serde_json::json!({ "purged": count })))
 }
+
+#[derive(serde::Deserialize)]
+struct AdminTransferServerRequest {
+    new_owner_id: Uuid,
+    reason: Option<String>,
+}
+
+/// Instance-admin override for abandoned servers whose owner can no longer
+/// confirm the transfer themselves.
+async fn admin_transfer_server(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<AdminTransferServerRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    let updated = crate::api::servers::apply_ownership_transfer(
+        &state,
+        server,
+        body.new_owner_id,
+        user.user_id,
+        body.reason.as_deref(),
+    )
+    .await?;
+
+    Ok(Json(updated))
+}
+
+// ── Registration Mode & Approval Queue ────────────────
+
+async fn get_registration_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let settings = queries::get_instance_settings(&state.db).await?;
+    Ok(Json(settings))
+}
+
+async fn update_registration_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(body): Json<UpdateRegistrationSettingsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+
+    let questions = body.questions.map(|qs| {
+        qs.into_iter()
+            .map(|q| q.trim().to_string())
+            .collect::<Vec<_>>()
+    });
+    if let Some(ref qs) = questions {
+        if qs.len() > 10 {
+            return Err(ApiError::InvalidInput(
+                "At most 10 sign-up questions are allowed".into(),
+            ));
+        }
+        if qs.iter().any(|q| q.is_empty() || q.len() > 300) {
+            return Err(ApiError::InvalidInput(
+                "Questions must be 1-300 characters".into(),
+            ));
+        }
+    }
+
+    let settings =
+        queries::update_registration_settings(&state.db, body.mode, questions.as_deref()).await?;
+
+    tracing::info!(
+        admin_id = %user.user_id,
+        mode = ?settings.registration_mode,
+        "Registration settings updated"
+    );
+
+    Ok(Json(settings))
+}
+
+// ── Message Retention ─────────────────────────────────
+
+/// Longest instance-wide retention cap (10 years)
+const MAX_INSTANCE_RETENTION_DAYS: i32 = 3650;
+
+async fn get_retention_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let settings = queries::get_instance_settings(&state.db).await?;
+    Ok(Json(serde_json::json!({
+        "message_retention_days": settings.message_retention_days,
+    })))
+}
+
+/// PATCH /admin/retention-settings
+///
+/// Cap how long messages are kept anywhere on the instance. Channels can
+/// still set a shorter period of their own.
+async fn update_retention_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(body): Json<UpdateRetentionSettingsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+
+    if !(0..=MAX_INSTANCE_RETENTION_DAYS).contains(&body.message_retention_days) {
+        return Err(ApiError::InvalidInput(format!(
+            "Retention must be between 0 and {MAX_INSTANCE_RETENTION_DAYS} days"
+        )));
+    }
+
+    let settings =
+        queries::update_retention_settings(&state.db, body.message_retention_days).await?;
+
+    tracing::info!(
+        admin_id = %user.user_id,
+        days = settings.message_retention_days,
+        "Message retention settings updated"
+    );
+
+    Ok(Json(serde_json::json!({
+        "message_retention_days": settings.message_retention_days,
+    })))
+}
+
+#[derive(serde::Deserialize)]
+struct RegistrationRequestsQuery {
+    status: Option<RegistrationRequestStatus>,
+    limit: Option<i64>,
+}
+
+async fn list_registration_requests(
+    State(state): State<AppState>,
+    user: AuthUser,
+    axum::extract::Query(params): axum::extract::Query<RegistrationRequestsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let limit = params.limit.unwrap_or(50).clamp(1, 100);
+    let requests = queries::list_registration_requests(&state.db, params.status, limit).await?;
+    Ok(Json(requests))
+}
+
+async fn approve_registration_request(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(request_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let request = auth_service::approve_registration(
+        &state.db,
+        &state.config,
+        state.mailer.as_deref(),
+        request_id,
+        user.user_id,
+    )
+    .await?;
+    Ok(Json(request))
+}
+
+#[derive(serde::Deserialize)]
+struct RejectRegistrationRequest {
+    reason: Option<String>,
+}
+
+async fn reject_registration_request(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(request_id): Path<Uuid>,
+    Json(body): Json<RejectRegistrationRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let request = auth_service::reject_registration(
+        &state.db,
+        state.mailer.as_deref(),
+        request_id,
+        user.user_id,
+        body.reason.as_deref().map(str::trim).filter(|r| !r.is_empty()),
+    )
+    .await?;
+    Ok(Json(request))
+}
+
+// ── Escalated Reports ─────────────────────────────────
+
+/// Reports outside any server (DMs, profiles) plus those escalated by server
+/// moderators.
+async fn list_instance_reports(
+    State(state): State<AppState>,
+    user: AuthUser,
+    axum::extract::Query(params): axum::extract::Query<ReportQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let limit = params.limit.unwrap_or(50).clamp(1, 100);
+    let reports = queries::list_instance_reports(&state.db, params.status, limit).await?;
+    Ok(Json(reports))
+}
+
+async fn get_instance_report(
+    state: &AppState,
+    report_id: Uuid,
+) -> Result<crate::types::entities::Report, ApiError> {
+    queries::get_report(&state.db, report_id)
+        .await?
+        .filter(|r| r.server_id.is_none() || r.escalated)
+        .ok_or(ApiError::NotFound("Report"))
+}
+
+async fn admin_claim_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(report_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    get_instance_report(&state, report_id).await?;
+    let report = queries::claim_report(&state.db, report_id, user.user_id)
+        .await?
+        .ok_or(ApiError::InvalidInput("Report is already closed".into()))?;
+    Ok(Json(report))
+}
+
+async fn admin_resolve_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(report_id): Path<Uuid>,
+    Json(body): Json<CloseReportRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let report = get_instance_report(&state, report_id).await?;
+    let report = crate::api::reports::close_report(
+        &state,
+        report,
+        user.user_id,
+        ReportStatus::Resolved,
+        &body,
+    )
+    .await?;
+    Ok(Json(report))
+}
+
+async fn admin_dismiss_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(report_id): Path<Uuid>,
+    Json(body): Json<CloseReportRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_admin(&state, user.user_id).await?;
+    let report = get_instance_report(&state, report_id).await?;
+    let report = crate::api::reports::close_report(
+        &state,
+        report,
+        user.user_id,
+        ReportStatus::Dismissed,
+        &body,
+    )
+    .await?;
+    Ok(Json(report))
+}
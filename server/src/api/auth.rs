@@ -2,15 +2,15 @@ use std::net::SocketAddr;
 
 use axum::extract::{ConnectInfo, FromRef, State};
 use axum::http::request::Parts;
-use axum::http::HeaderMap;
-use axum::response::IntoResponse;
-use axum::routing::post;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
 use axum::{Json, Router};
 use uuid::Uuid;
 
 use crate::db::queries;
 use crate::error::ApiError;
-use crate::services::auth as auth_service;
+use crate::services::auth::{self as auth_service, RegisterOutcome};
 use crate::state::AppState;
 use crate::types::entities::{LoginRequest, RefreshRequest, RegisterRequest};
 use crate::types::events::ServerMemberAddEvent;
@@ -59,19 +59,39 @@ pub async fn check_rate_limit(
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/register", post(register))
+        .route("/registration-info", get(registration_info))
         .route("/login", post(login))
         .route("/refresh", post(refresh))
         .route("/forgot-password", post(forgot_password))
         .route("/reset-password", post(reset_password))
+        .route("/verify-email", post(verify_email))
+        .route("/verify-email/resend", post(resend_verification))
 }
 
 async fn register(
     State(state): State<AppState>,
     Json(body): Json<RegisterRequest>,
-) -> Result<impl IntoResponse, ApiError> {
-    let (response, joined_server_id) =
-        auth_service::register(&state.db, &state.config, &body.username, &body.email, &body.password, body.invite_code.as_deref())
-            .await?;
+) -> Result<Response, ApiError> {
+    let outcome =
+        auth_service::register(&state.db, &state.config, state.mailer.as_deref(), &body).await?;
+
+    let (response, joined_server_id) = match outcome {
+        RegisterOutcome::Registered {
+            auth,
+            joined_server_id,
+        } => (auth, joined_server_id),
+        RegisterOutcome::PendingApproval(request) => {
+            return Ok((
+                StatusCode::ACCEPTED,
+                Json(serde_json::json!({
+                    "status": "pending",
+                    "request_id": request.id,
+                    "message": "Your sign-up is awaiting approval by an administrator",
+                })),
+            )
+                .into_response());
+        }
+    };
 
     // If user was auto-joined to a server via invite, notify existing members
     if let Some(server_id) = joined_server_id {
@@ -87,9 +107,28 @@ async fn register(
                 .gateway
                 .broadcast_to_server(server_id, "SERVER_MEMBER_ADD", &event, None);
         }
+        crate::services::safety::record_join(
+            &state,
+            server_id,
+            response.user.id,
+            body.invite_code.as_deref(),
+        )
+        .await;
+        crate::services::mod_log::member_join(&state, server_id, response.user.id);
     }
 
-    Ok(Json(response))
+    Ok(Json(response).into_response())
+}
+
+/// Public: what the sign-up form needs to show before the user submits.
+async fn registration_info(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let settings = queries::get_instance_settings(&state.db).await?;
+    Ok(Json(serde_json::json!({
+        "mode": settings.registration_mode,
+        "questions": settings.registration_questions,
+    })))
 }
 
 async fn login(
@@ -153,7 +192,12 @@ async fn forgot_password(
     let rate_key = format!("pw_reset:{}", body.email.to_lowercase());
     check_rate_limit(&mut redis, &rate_key, 10, 900).await?;
 
-    auth_service::request_password_reset(&state.db, &state.config, &body.email).await?;
+    auth_service::request_password_reset(
+        &state.db,
+        &state.config,
+        state.mailer.as_deref(),
+        &body.email,
+    ).await?;
 
     Ok(Json(serde_json::json!({
         "message": "If an account with that email exists, a reset link has been sent."
@@ -171,6 +215,44 @@ async fn reset_password(
     })))
 }
 
+// ── Email Verification ────────────────────────────────
+
+#[derive(Debug, serde::Deserialize)]
+struct VerifyEmailRequest {
+    token: String,
+}
+
+async fn verify_email(
+    State(state): State<AppState>,
+    Json(body): Json<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_service::verify_email(&state.db, state.mailer.as_deref(), &body.token).await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "Email address verified."
+    })))
+}
+
+async fn resend_verification(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut redis = state.redis.clone();
+    let rate_key = format!("verify_email:{}", user.user_id);
+    check_rate_limit(&mut redis, &rate_key, 5, 900).await?;
+
+    auth_service::resend_verification_email(
+        &state.db,
+        &state.config,
+        state.mailer.as_deref(),
+        user.user_id,
+    ).await?;
+
+    Ok(Json(serde_json::json!({
+        "message": "Verification email sent."
+    })))
+}
+
 // ── Auth Extractors ───────────────────────────────────
 
 /// Extract user from Authorization header only (standard API requests).
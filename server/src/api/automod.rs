@@ -0,0 +1,259 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use uuid::Uuid;
+
+use crate::api::auth::AuthUser;
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::automod as automod_service;
+use crate::services::permissions as perm_service;
+use crate::state::AppState;
+use crate::types::entities::{
+    AuditAction, AutoModAction, CreateAutoModRuleRequest, UpdateAutoModRuleRequest,
+};
+use crate::types::permissions::Permissions;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{server_id}/automod/rules",
+            get(list_rules).post(create_rule),
+        )
+        .route(
+            "/{server_id}/automod/rules/{rule_id}",
+            get(get_rule).patch(update_rule).delete(delete_rule),
+        )
+}
+
+/// AutoMod configuration requires MANAGE_SERVER.
+async fn require_manage_server(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    if !perm_service::has_server_permission(
+        &state.db,
+        server_id,
+        user_id,
+        server.owner_id,
+        Permissions::MANAGE_SERVER,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+    Ok(())
+}
+
+fn validate_name(name: &str) -> Result<(), ApiError> {
+    if name.trim().is_empty() || name.len() > 100 {
+        return Err(ApiError::InvalidInput(
+            "Rule name must be 1-100 characters".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Exempt roles/channels and alert channels must belong to this server.
+async fn validate_server_refs(
+    state: &AppState,
+    server_id: Uuid,
+    actions: &[AutoModAction],
+    role_ids: &[Uuid],
+    channel_ids: &[Uuid],
+) -> Result<(), ApiError> {
+    for role_id in role_ids {
+        let role = queries::get_role_by_id(&state.db, *role_id).await?;
+        if role.is_none_or(|r| r.server_id != server_id) {
+            return Err(ApiError::InvalidInput(format!("Unknown role {role_id}")));
+        }
+    }
+
+    let alert_channels = actions.iter().filter_map(|a| match a {
+        AutoModAction::SendAlert { channel_id } => Some(channel_id),
+        _ => None,
+    });
+    for channel_id in channel_ids.iter().chain(alert_channels) {
+        let channel = queries::get_channel_by_id(&state.db, *channel_id).await?;
+        if channel.is_none_or(|c| c.server_id != Some(server_id)) {
+            return Err(ApiError::InvalidInput(format!("Unknown channel {channel_id}")));
+        }
+    }
+    Ok(())
+}
+
+async fn list_rules(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+    let rules = queries::get_server_automod_rules(&state.db, server_id).await?;
+    Ok(Json(rules))
+}
+
+async fn get_rule(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, rule_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+    let rule = queries::get_automod_rule(&state.db, rule_id)
+        .await?
+        .filter(|r| r.server_id == server_id)
+        .ok_or(ApiError::NotFound("AutoMod rule"))?;
+    Ok(Json(rule))
+}
+
+async fn create_rule(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<CreateAutoModRuleRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+
+    validate_name(&body.name)?;
+    automod_service::validate_rule(body.trigger_type, &body.trigger_metadata, &body.actions)
+        .map_err(ApiError::InvalidInput)?;
+    validate_server_refs(
+        &state,
+        server_id,
+        &body.actions,
+        &body.exempt_role_ids,
+        &body.exempt_channel_ids,
+    )
+    .await?;
+
+    let existing = queries::get_server_automod_rules(&state.db, server_id).await?;
+    if existing.len() >= automod_service::MAX_RULES_PER_SERVER {
+        return Err(ApiError::InvalidInput(format!(
+            "Servers can have at most {} AutoMod rules",
+            automod_service::MAX_RULES_PER_SERVER
+        )));
+    }
+
+    let rule = queries::create_automod_rule(
+        &state.db,
+        Uuid::now_v7(),
+        server_id,
+        user.user_id,
+        body.name.trim(),
+        body.enabled.unwrap_or(true),
+        body.trigger_type,
+        &body.trigger_metadata,
+        &body.actions,
+        &body.exempt_role_ids,
+        &body.exempt_channel_ids,
+    )
+    .await?;
+    automod_service::cache_rule(&rule);
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::AutomodRuleCreate,
+        Some(rule.id),
+        None,
+        Some(serde_json::json!({ "name": rule.name, "trigger_type": rule.trigger_type })),
+    )
+    .await;
+
+    Ok(Json(rule))
+}
+
+async fn update_rule(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, rule_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<UpdateAutoModRuleRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+
+    let rule = queries::get_automod_rule(&state.db, rule_id)
+        .await?
+        .filter(|r| r.server_id == server_id)
+        .ok_or(ApiError::NotFound("AutoMod rule"))?;
+
+    if let Some(ref name) = body.name {
+        validate_name(name)?;
+    }
+    let metadata = body.trigger_metadata.as_ref().unwrap_or(&rule.trigger_metadata.0);
+    let actions = body.actions.as_deref().unwrap_or(rule.actions.as_slice());
+    automod_service::validate_rule(rule.trigger_type, metadata, actions)
+        .map_err(ApiError::InvalidInput)?;
+    validate_server_refs(
+        &state,
+        server_id,
+        body.actions.as_deref().unwrap_or_default(),
+        body.exempt_role_ids.as_deref().unwrap_or_default(),
+        body.exempt_channel_ids.as_deref().unwrap_or_default(),
+    )
+    .await?;
+
+    let updated = queries::update_automod_rule(
+        &state.db,
+        rule_id,
+        body.name.as_deref().map(str::trim),
+        body.enabled,
+        body.trigger_metadata.as_ref(),
+        body.actions.as_deref(),
+        body.exempt_role_ids.as_deref(),
+        body.exempt_channel_ids.as_deref(),
+    )
+    .await?;
+    automod_service::cache_rule(&updated);
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::AutomodRuleUpdate,
+        Some(rule_id),
+        None,
+        Some(audit_service::diff(&rule, &updated)),
+    )
+    .await;
+
+    Ok(Json(updated))
+}
+
+async fn delete_rule(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, rule_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+
+    let rule = queries::get_automod_rule(&state.db, rule_id)
+        .await?
+        .filter(|r| r.server_id == server_id)
+        .ok_or(ApiError::NotFound("AutoMod rule"))?;
+
+    queries::delete_automod_rule(&state.db, rule_id).await?;
+    automod_service::forget_rule(rule_id);
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::AutomodRuleDelete,
+        Some(rule_id),
+        None,
+        Some(serde_json::json!({ "name": rule.name })),
+    )
+    .await;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
@@ -1,20 +1,38 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
 use axum::extract::{Path, Query, State};
+use axum::http::header;
 use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::{Json, Router};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::api::auth::AuthUser;
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::automod::MAX_TIMEOUT_SECS;
+use crate::services::email::EmailTemplate;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
 use crate::types::entities::{
-    AuditAction, AuditLogEntryWithUser, AuditLogQuery, BanWithUser, CreateBanRequest, PublicUser,
+    AuditAction, AuditExportFormat, AuditLogEntry, AuditLogEntryWithUser, AuditLogExportQuery,
+    AuditLogQuery, Ban, BanWithUser, CreateBanRequest, PublicUser, Server, SetMemberTimeoutRequest,
+};
+use crate::types::events::{
+    BanCreateEvent, BanDeleteEvent, MessageDeleteBulkEvent, ServerMemberRemoveEvent,
+    ServerMemberUpdateEvent,
 };
-use crate::types::events::{BanCreateEvent, BanDeleteEvent, ServerMemberRemoveEvent};
 use crate::types::permissions::Permissions;
 
+/// Temporary bans can last up to a year
+const MAX_BAN_DURATION_SECS: i64 = 365 * 86400;
+/// Purging on ban reaches back at most 7 days
+const MAX_DELETE_MESSAGE_SECS: i64 = 7 * 86400;
+const PURGE_BATCH_SIZE: i64 = 100;
+
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route(
@@ -29,7 +47,12 @@ pub fn routes() -> Router<AppState> {
             "/{server_id}/kick/{user_id}",
             axum::routing::post(kick_member),
         )
+        .route(
+            "/{server_id}/members/{user_id}/timeout",
+            axum::routing::put(timeout_member),
+        )
         .route("/{server_id}/audit-log", get(get_audit_log))
+        .route("/{server_id}/audit-log/export", get(export_audit_log))
 }
 
 async fn get_bans(
@@ -92,65 +115,55 @@ async fn ban_member(
         return Err(ApiError::Forbidden);
     }
 
-    // Can't ban the owner
-    if target_id == server.owner_id {
-        return Err(ApiError::InvalidInput("Cannot ban the server owner".into()));
-    }
-
-    // Can't ban yourself
-    if target_id == user.user_id {
-        return Err(ApiError::InvalidInput("Cannot ban yourself".into()));
-    }
+    let expires_at = match body.duration_secs {
+        None => None,
+        Some(secs @ 60..=MAX_BAN_DURATION_SECS) => {
+            Some(Utc::now() + chrono::Duration::seconds(secs))
+        }
+        Some(_) => {
+            return Err(ApiError::InvalidInput(
+                "Ban duration must be between 1 minute and 1 year".into(),
+            ));
+        }
+    };
+    let purge_since = match body.delete_message_seconds {
+        None | Some(0) => None,
+        Some(secs @ 1..=MAX_DELETE_MESSAGE_SECS) => {
+            Some(Utc::now() - chrono::Duration::seconds(secs))
+        }
+        Some(_) => {
+            return Err(ApiError::InvalidInput(
+                "delete_message_seconds must be at most 7 days".into(),
+            ));
+        }
+    };
 
-    // Create the ban
-    let ban = queries::create_ban(
-        &state.db,
-        server_id,
+    let ban = apply_ban(
+        &state,
+        &server,
         target_id,
         user.user_id,
         body.reason.as_deref(),
+        expires_at,
     )
     .await?;
 
-    // Remove from server if member
-    if queries::get_server_member(&state.db, server_id, target_id)
-        .await?
-        .is_some()
-    {
-        queries::remove_server_member(&state.db, server_id, target_id).await?;
-
-        let remove_event = ServerMemberRemoveEvent {
-            server_id,
-            user_id: target_id,
-        };
-        state.gateway.broadcast_to_server(
-            server_id,
-            "SERVER_MEMBER_REMOVE",
-            &remove_event,
-            None,
-        );
+    // Purging can take a while on busy servers; don't hold up the response
+    if let Some(since) = purge_since {
+        let state = state.clone();
+        tokio::spawn(async move {
+            match purge_member_messages(&state, server_id, target_id, since).await {
+                Ok(count) => tracing::info!(
+                    server_id = %server_id,
+                    user_id = %target_id,
+                    count,
+                    "Purged messages of banned user"
+                ),
+                Err(e) => tracing::error!(error = ?e, "Failed to purge messages of banned user"),
+            }
+        });
     }
 
-    // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
-        server_id,
-        user.user_id,
-        AuditAction::MemberBan,
-        Some(target_id),
-        body.reason.as_deref(),
-        None,
-    )
-    .await;
-
-    let event = BanCreateEvent {
-        server_id,
-        user_id: target_id,
-    };
-    state
-        .gateway
-        .broadcast_to_server(server_id, "BAN_CREATE", &event, None);
-
     Ok(Json(ban))
 }
 
@@ -178,8 +191,8 @@ async fn unban_member(
     queries::delete_ban(&state.db, server_id, target_id).await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::MemberUnban,
@@ -221,65 +234,376 @@ async fn kick_member(
         return Err(ApiError::Forbidden);
     }
 
+    apply_kick(&state, &server, target_id, user.user_id, None).await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Ban `target_id`, remove them from the server and write the audit entry.
+/// Shared by the ban endpoint and the report queue.
+pub(crate) async fn apply_ban(
+    state: &AppState,
+    server: &Server,
+    target_id: Uuid,
+    actor_id: Uuid,
+    reason: Option<&str>,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<Ban, ApiError> {
+    // Can't ban the owner
+    if target_id == server.owner_id {
+        return Err(ApiError::InvalidInput("Cannot ban the server owner".into()));
+    }
+
+    // Can't ban yourself
+    if target_id == actor_id {
+        return Err(ApiError::InvalidInput("Cannot ban yourself".into()));
+    }
+
+    // Create the ban
+    let ban = queries::create_ban(
+        &state.db,
+        server.id,
+        target_id,
+        actor_id,
+        reason,
+        expires_at,
+    )
+    .await?;
+
+    // Remove from server if member
+    if queries::get_server_member(&state.db, server.id, target_id)
+        .await?
+        .is_some()
+    {
+        queries::remove_server_member(&state.db, server.id, target_id).await?;
+
+        let remove_event = ServerMemberRemoveEvent {
+            server_id: server.id,
+            user_id: target_id,
+        };
+        state.gateway.broadcast_to_server(
+            server.id,
+            "SERVER_MEMBER_REMOVE",
+            &remove_event,
+            None,
+        );
+    }
+
+    // Audit log
+    audit_service::record(
+        state,
+        server.id,
+        actor_id,
+        AuditAction::MemberBan,
+        Some(target_id),
+        reason,
+        expires_at.map(|t| serde_json::json!({ "expires_at": t })),
+    )
+    .await;
+
+    let notice = match expires_at {
+        Some(t) => format!(
+            "you have been banned from {} until {}.",
+            server.name,
+            t.format("%Y-%m-%d %H:%M UTC")
+        ),
+        None => format!("you have been banned from {}.", server.name),
+    };
+    send_moderation_notice(state, target_id, server, notice, reason);
+
+    let event = BanCreateEvent {
+        server_id: server.id,
+        user_id: target_id,
+    };
+    state
+        .gateway
+        .broadcast_to_server(server.id, "BAN_CREATE", &event, None);
+
+    Ok(ban)
+}
+
+/// Delete a member's messages across the server since `since`, in batches,
+/// with one MESSAGE_DELETE_BULK per channel per batch. Returns the count.
+pub(crate) async fn purge_member_messages(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+    since: DateTime<Utc>,
+) -> Result<u64, ApiError> {
+    let mut total = 0;
+    loop {
+        let batch = queries::get_member_message_ids_since(
+            &state.db,
+            server_id,
+            user_id,
+            since,
+            PURGE_BATCH_SIZE,
+        )
+        .await?;
+        if batch.is_empty() {
+            break;
+        }
+
+        let ids: Vec<Uuid> = batch.iter().map(|(id, _)| *id).collect();
+        total += crate::services::uploads::delete_messages_and_attachments(state, &ids).await?;
+
+        let mut by_channel: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for (id, channel_id) in &batch {
+            by_channel.entry(*channel_id).or_default().push(*id);
+        }
+        for (channel_id, ids) in by_channel {
+            let event = MessageDeleteBulkEvent {
+                ids,
+                channel_id,
+                server_id: Some(server_id),
+            };
+            state
+                .gateway
+                .broadcast_to_server(server_id, "MESSAGE_DELETE_BULK", &event, None);
+        }
+
+        if (batch.len() as i64) < PURGE_BATCH_SIZE {
+            break;
+        }
+    }
+    Ok(total)
+}
+
+/// Remove `target_id` from the server and write the audit entry.
+pub(crate) async fn apply_kick(
+    state: &AppState,
+    server: &Server,
+    target_id: Uuid,
+    actor_id: Uuid,
+    reason: Option<&str>,
+) -> Result<(), ApiError> {
     if target_id == server.owner_id {
         return Err(ApiError::InvalidInput("Cannot kick the server owner".into()));
     }
 
-    if target_id == user.user_id {
+    if target_id == actor_id {
         return Err(ApiError::InvalidInput("Cannot kick yourself".into()));
     }
 
-    queries::get_server_member(&state.db, server_id, target_id)
+    queries::get_server_member(&state.db, server.id, target_id)
         .await?
         .ok_or(ApiError::NotFound("Member"))?;
 
-    queries::remove_server_member(&state.db, server_id, target_id).await?;
+    queries::remove_server_member(&state.db, server.id, target_id).await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
-        server_id,
-        user.user_id,
+    audit_service::record(
+        state,
+        server.id,
+        actor_id,
         AuditAction::MemberKick,
         Some(target_id),
-        None,
+        reason,
         None,
     )
     .await;
 
     let event = ServerMemberRemoveEvent {
-        server_id,
+        server_id: server.id,
         user_id: target_id,
     };
     state
         .gateway
-        .broadcast_to_server(server_id, "SERVER_MEMBER_REMOVE", &event, None);
+        .broadcast_to_server(server.id, "SERVER_MEMBER_REMOVE", &event, None);
 
-    Ok(axum::http::StatusCode::NO_CONTENT)
+    Ok(())
 }
 
-async fn get_audit_log(
+/// Email `user_id` a moderation notice in the background, if email is set up
+/// and their address is verified. `notice` finishes "Hi <username>, ".
+pub(crate) fn send_moderation_notice(
+    state: &AppState,
+    user_id: Uuid,
+    server: &Server,
+    notice: String,
+    note: Option<&str>,
+) {
+    if state.mailer.is_none() {
+        return;
+    }
+    let state = state.clone();
+    let server_name = server.name.clone();
+    let note = note.unwrap_or("No note was given.").to_string();
+    tokio::spawn(async move {
+        let Some(mailer) = state.mailer.as_deref() else {
+            return;
+        };
+        let user = match queries::get_user_by_id(&state.db, user_id).await {
+            Ok(Some(user)) => user,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!(error = ?e, "Failed to load user for moderation notice");
+                return;
+            }
+        };
+        let Some(email) = user.email.filter(|_| user.email_verified) else {
+            return;
+        };
+        if let Err(e) = mailer
+            .send(
+                &email,
+                EmailTemplate::ModerationNotice,
+                &[
+                    ("username", user.username.as_str()),
+                    ("server_name", server_name.as_str()),
+                    ("notice", notice.as_str()),
+                    ("note", note.as_str()),
+                ],
+            )
+            .await
+        {
+            tracing::error!(error = %e, "Failed to send moderation notice email");
+        }
+    });
+}
+
+/// Set or lift a member's timeout. Timed-out members keep read access but
+/// can't post until it expires.
+async fn timeout_member(
     State(state): State<AppState>,
     user: AuthUser,
-    Path(server_id): Path<Uuid>,
-    Query(query): Query<AuditLogQuery>,
+    Path((server_id, target_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<SetMemberTimeoutRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let server = queries::get_server_by_id(&state.db, server_id)
         .await?
         .ok_or(ApiError::NotFound("Server"))?;
 
-    // Only users with VIEW_AUDIT_LOG can see it
     if !perm_service::has_server_permission(
         &state.db,
         server_id,
         user.user_id,
         server.owner_id,
+        Permissions::MODERATE_MEMBERS,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+
+    if target_id == user.user_id {
+        return Err(ApiError::InvalidInput("Cannot time out yourself".into()));
+    }
+    if target_id == server.owner_id {
+        return Err(ApiError::InvalidInput("Cannot time out the server owner".into()));
+    }
+
+    let until = match body.duration_secs {
+        None | Some(0) => None,
+        Some(secs @ 1..=MAX_TIMEOUT_SECS) => Some(Utc::now() + chrono::Duration::seconds(secs)),
+        Some(_) => {
+            return Err(ApiError::InvalidInput(
+                "Timeouts must be between 1 second and 28 days".into(),
+            ));
+        }
+    };
+
+    let previous = queries::get_server_member(&state.db, server_id, target_id)
+        .await?
+        .ok_or(ApiError::NotFound("Member"))?;
+    let member = queries::set_member_timeout(&state.db, server_id, target_id, until)
+        .await?
+        .ok_or(ApiError::NotFound("Member"))?;
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::MemberTimeout,
+        Some(target_id),
+        body.reason.as_deref(),
+        Some(audit_service::change(
+            "communication_disabled_until",
+            previous.communication_disabled_until,
+            member.communication_disabled_until,
+        )),
+    )
+    .await;
+
+    if let Some(until) = until {
+        let notice = format!(
+            "you have been timed out in {} until {}. You can still read channels, but can't post.",
+            server.name,
+            until.format("%Y-%m-%d %H:%M UTC")
+        );
+        send_moderation_notice(&state, target_id, &server, notice, body.reason.as_deref());
+    }
+
+    state.gateway.broadcast_to_server(
+        server_id,
+        "SERVER_MEMBER_UPDATE",
+        &ServerMemberUpdateEvent {
+            server_id,
+            member: member.clone(),
+        },
+        None,
+    );
+
+    Ok(Json(member))
+}
+
+async fn require_view_audit_log(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    // Only users with VIEW_AUDIT_LOG can see it
+    if !perm_service::has_server_permission(
+        &state.db,
+        server_id,
+        user_id,
+        server.owner_id,
         Permissions::VIEW_AUDIT_LOG,
     )
     .await?
     {
         return Err(ApiError::Forbidden);
     }
+    Ok(())
+}
+
+/// Attach the acting user to each entry, looking each user up once.
+async fn with_users(
+    state: &AppState,
+    entries: Vec<AuditLogEntry>,
+) -> Result<Vec<AuditLogEntryWithUser>, ApiError> {
+    let mut users: HashMap<Uuid, Option<PublicUser>> = HashMap::new();
+    let mut result = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let user = match users.entry(entry.user_id) {
+            Entry::Occupied(cached) => cached.into_mut(),
+            Entry::Vacant(slot) => {
+                let user = queries::get_user_by_id(&state.db, entry.user_id).await?;
+                slot.insert(user.map(PublicUser::from))
+            }
+        };
+        if let Some(u) = user {
+            result.push(AuditLogEntryWithUser {
+                entry,
+                user: u.clone(),
+            });
+        }
+    }
+    Ok(result)
+}
+
+async fn get_audit_log(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_view_audit_log(&state, server_id, user.user_id).await?;
 
     let limit = query.limit.unwrap_or(50).min(100);
     let entries = queries::get_audit_log(
@@ -287,22 +611,58 @@ async fn get_audit_log(
         server_id,
         query.action,
         query.user_id,
+        query.target_id,
+        query.since,
+        query.until,
         query.before,
         limit,
     )
     .await?;
 
-    // Enrich with user info
-    let mut result = Vec::new();
-    for entry in entries {
-        let user_data = queries::get_user_by_id(&state.db, entry.user_id).await?;
-        if let Some(u) = user_data {
-            result.push(AuditLogEntryWithUser {
-                entry,
-                user: PublicUser::from(u),
-            });
-        }
-    }
+    Ok(Json(with_users(&state, entries).await?))
+}
 
-    Ok(Json(result))
+/// Download the (filtered) audit log as CSV or JSON Lines, newest first.
+async fn export_audit_log(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Query(query): Query<AuditLogExportQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_view_audit_log(&state, server_id, user.user_id).await?;
+
+    let entries = queries::get_audit_log(
+        &state.db,
+        server_id,
+        query.action,
+        query.user_id,
+        query.target_id,
+        query.since,
+        query.until,
+        None,
+        audit_service::MAX_EXPORT_ENTRIES,
+    )
+    .await?;
+    let entries = with_users(&state, entries).await?;
+
+    let (content_type, extension, body) = match query.format {
+        AuditExportFormat::Csv => {
+            let mut body = String::from(audit_service::CSV_HEADER);
+            body.extend(entries.iter().map(audit_service::csv_row));
+            ("text/csv; charset=utf-8", "csv", body)
+        }
+        AuditExportFormat::Jsonl => {
+            let body: String = entries.iter().map(audit_service::jsonl_row).collect();
+            ("application/x-ndjson", "jsonl", body)
+        }
+    };
+    let disposition = format!("attachment; filename=\"audit-log-{server_id}.{extension}\"");
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        body,
+    ))
 }
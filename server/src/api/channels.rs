@@ -11,17 +11,24 @@ use uuid::Uuid;
 use crate::api::auth::{check_rate_limit, AuthUser};
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::automod as automod_service;
+use crate::services::mod_log;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
 use crate::types::entities::{
-    AckMessageRequest, AuditAction, ChannelType, CreateThreadRequest, EditMessageRequest,
-    MessageQuery, PublicUser, ReactionGroup, SendMessageRequest, SetChannelOverrideRequest,
-    UpdateChannelRequest, UploadUrlResponse,
+    AckMessageRequest, AllowedMentionType, AllowedMentions, AuditAction,
+    BulkDeleteMessagesRequest, BulkDeleteResponse, ChannelType, CreateThreadRequest,
+    EditMessageRequest, ForwardMessageRequest, ForwardOrigin, MessageForward, MessageLinkQuery,
+    MessageQuery, PublicUser, PurgeMessagesRequest, ReactionGroup, SendMessageRequest,
+    SetChannelOverrideRequest, ThreadListQuery, ThreadMetadata, UpdateChannelRequest,
+    UpdateThreadRequest, UploadUrlResponse,
 };
 use crate::types::events::{
-    ChannelOverrideUpdateEvent, MessageAckEvent, MessageCreateEvent, MessageDeleteEvent,
-    MessagePinEvent, MessageUpdateEvent, ReactionAddEvent, ReactionRemoveEvent,
-    ThreadCreateEvent, TypingStartEvent,
+    ChannelOverrideUpdateEvent, ForumPostTagsUpdateEvent, MessageAckEvent, MessageCreateEvent,
+    MessageCreateWithForwardEvent, MessageDeleteBulkEvent, MessageDeleteEvent, MessagePinEvent, MessagePublishEvent,
+    MessageUpdateEvent, ReactionAddEvent, ReactionRemoveEvent, ThreadCreateEvent,
+    ThreadMembersUpdateEvent, ThreadUpdateEvent, TypingStartEvent,
 };
 use crate::types::permissions::Permissions;
 
@@ -41,6 +48,26 @@ pub fn routes() -> Router<AppState> {
             "/{channel_id}/messages/{message_id}",
             axum::routing::patch(edit_message).delete(delete_message),
         )
+        .route(
+            "/{channel_id}/messages/{message_id}/history",
+            get(get_message_history),
+        )
+        .route(
+            "/{channel_id}/messages/{message_id}/crosspost",
+            axum::routing::post(publish_message),
+        )
+        .route(
+            "/{channel_id}/messages/{message_id}/forward",
+            axum::routing::post(forward_message),
+        )
+        .route(
+            "/{channel_id}/messages/bulk-delete",
+            axum::routing::post(bulk_delete_messages),
+        )
+        .route(
+            "/{channel_id}/messages/purge",
+            axum::routing::post(purge_messages),
+        )
         .route(
             "/{channel_id}/messages/{message_id}/reactions/{emoji}",
             put(add_reaction).delete(remove_reaction),
@@ -60,10 +87,21 @@ pub fn routes() -> Router<AppState> {
             "/{channel_id}/threads",
             get(list_threads).post(create_thread),
         )
+        .route("/{channel_id}/thread", axum::routing::patch(update_thread))
+        .route("/{channel_id}/thread-members", get(list_thread_members))
+        .route(
+            "/{channel_id}/thread-members/@me",
+            put(join_thread).delete(leave_thread),
+        )
         .route("/{channel_id}/typing", axum::routing::post(typing_start))
         .route("/{channel_id}/ack", put(ack_message))
 }
 
+/// Top-level routes for resolving message links
+pub fn message_link_routes() -> Router<AppState> {
+    Router::new().route("/messages/{message_id}/link", get(resolve_message_link))
+}
+
 /// Helper: resolve channel and verify VIEW_CHANNEL permission.
 /// Returns (channel, server_id, owner_id) — server_id/owner_id are None for DMs.
 pub(crate) async fn resolve_channel_with_perm(
@@ -116,6 +154,109 @@ pub(crate) async fn resolve_channel_with_perm(
     }
 }
 
+/// Longest slowmode a channel can have (6 hours)
+const MAX_SLOWMODE_SECS: i32 = 21600;
+/// Longest retention period a channel can set (10 years)
+const MAX_RETENTION_DAYS: i32 = 3650;
+/// Longest disappearing-message timer (30 days)
+const MAX_MESSAGE_TTL_MINUTES: i32 = 43200;
+/// Returned when an AutoMod `DeleteMessage` action removes what was just posted
+const AUTOMOD_REMOVED_MESSAGE: &str = "Your message was removed by this server's AutoMod";
+
+/// Check a channel's requested retention settings against the allowed
+/// ranges and the instance-wide cap.
+async fn validate_retention(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    body: &UpdateChannelRequest,
+) -> Result<(), ApiError> {
+    if body.retention_days.is_none() && body.message_ttl_minutes.is_none() {
+        return Ok(());
+    }
+    if channel.channel_type == ChannelType::Category {
+        return Err(ApiError::InvalidInput(
+            "Categories don't hold messages".into(),
+        ));
+    }
+    if let Some(minutes) = body.message_ttl_minutes
+        && !(0..=MAX_MESSAGE_TTL_MINUTES).contains(&minutes)
+    {
+        return Err(ApiError::InvalidInput(format!(
+            "Disappearing messages timer must be between 0 and {MAX_MESSAGE_TTL_MINUTES} minutes"
+        )));
+    }
+    if let Some(days) = body.retention_days {
+        if !(0..=MAX_RETENTION_DAYS).contains(&days) {
+            return Err(ApiError::InvalidInput(format!(
+                "Retention must be between 0 and {MAX_RETENTION_DAYS} days"
+            )));
+        }
+        let cap = queries::get_instance_settings(&state.db)
+            .await?
+            .message_retention_days;
+        if cap > 0 && days > cap {
+            return Err(ApiError::InvalidInput(format!(
+                "This instance keeps messages for at most {cap} days"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Enforce the channel's slowmode for `user_id`. Members who can manage
+/// messages or the channel aren't slowed down.
+pub(crate) async fn enforce_slowmode(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let (Some(server_id), secs @ 1..) = (channel.server_id, channel.rate_limit_per_user) else {
+        return Ok(());
+    };
+
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+    let perms = perm_service::compute_channel_permissions(
+        &state.db,
+        server_id,
+        channel.id,
+        user_id,
+        server.owner_id,
+    )
+    .await?;
+    if perms.intersects(Permissions::MANAGE_MESSAGES | Permissions::MANAGE_CHANNELS) {
+        return Ok(());
+    }
+
+    // The key exists for as long as the member has to wait
+    let mut redis = state.redis.clone();
+    let key = format!("slowmode:{}:{}", channel.id, user_id);
+    let acquired: Result<Option<String>, _> = redis::cmd("SET")
+        .arg(&key)
+        .arg(1)
+        .arg("NX")
+        .arg("EX")
+        .arg(secs)
+        .query_async(&mut redis)
+        .await;
+    if !matches!(acquired, Ok(None)) {
+        return Ok(());
+    }
+
+    let remaining_ms: i64 = redis::cmd("PTTL")
+        .arg(&key)
+        .query_async(&mut redis)
+        .await
+        .unwrap_or(0);
+    let retry_after_ms = if remaining_ms > 0 {
+        remaining_ms as u64
+    } else {
+        secs as u64 * 1000
+    };
+    Err(ApiError::RateLimited { retry_after_ms })
+}
+
 async fn get_channel(
     State(state): State<AppState>,
     user: AuthUser,
@@ -137,9 +278,37 @@ async fn update_channel(
         resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::MANAGE_CHANNELS)
             .await?;
 
-    let server_id = server_id.ok_or(ApiError::InvalidInput(
-        "Cannot update DM channels".into(),
-    ))?;
+    validate_retention(&state, &channel, &body).await?;
+
+    // Any DM member can change a DM's retention, but nothing else about it
+    let Some(server_id) = server_id else {
+        if body.name.is_some()
+            || body.topic.is_some()
+            || body.rate_limit_per_user.is_some()
+            || body.require_tag.is_some()
+        {
+            return Err(ApiError::InvalidInput(
+                "Only message retention can be changed on DM channels".into(),
+            ));
+        }
+        let updated = queries::update_channel(
+            &state.db,
+            channel_id,
+            None,
+            None,
+            None,
+            None,
+            body.retention_days,
+            body.message_ttl_minutes,
+        )
+        .await?;
+        for member in queries::get_dm_members(&state.db, channel_id).await? {
+            state
+                .gateway
+                .dispatch_to_user(member.id, "CHANNEL_UPDATE", &updated);
+        }
+        return Ok(Json(updated));
+    };
 
     if let Some(ref name) = body.name {
         if name.is_empty() || name.len() > 100 {
@@ -148,23 +317,45 @@ async fn update_channel(
             ));
         }
     }
+    if let Some(secs) = body.rate_limit_per_user {
+        if !matches!(channel.channel_type, ChannelType::Text | ChannelType::Announcement) {
+            return Err(ApiError::InvalidInput(
+                "Slowmode is only available in text and announcement channels and threads".into(),
+            ));
+        }
+        if !(0..=MAX_SLOWMODE_SECS).contains(&secs) {
+            return Err(ApiError::InvalidInput(format!(
+                "Slowmode must be between 0 and {MAX_SLOWMODE_SECS} seconds"
+            )));
+        }
+    }
+
+    if body.require_tag.is_some() && channel.channel_type != ChannelType::Forum {
+        return Err(ApiError::InvalidInput(
+            "Required tags are only available in forum channels".into(),
+        ));
+    }
 
     let updated = queries::update_channel(
         &state.db,
         channel_id,
         body.name.as_deref(),
         body.topic.as_deref(),
+        body.rate_limit_per_user,
+        body.require_tag,
+        body.retention_days,
+        body.message_ttl_minutes,
     )
     .await?;
 
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::ChannelUpdate,
         Some(channel_id),
         None,
-        Some(serde_json::json!({ "name": updated.name, "topic": updated.topic })),
+        Some(audit_service::diff(&channel, &updated)),
     )
     .await;
 
@@ -190,8 +381,8 @@ async fn delete_channel(
 
     queries::delete_channel(&state.db, channel_id).await?;
 
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::ChannelDelete,
@@ -225,10 +416,27 @@ async fn get_messages(
     )
     .await?;
 
-    let limit = query.limit.unwrap_or(50).min(100);
-    let messages =
-        queries::get_messages(&state.db, channel_id, query.before, query.after, limit).await?;
+    let limit = query.limit.unwrap_or(50).clamp(1, 100);
+    let messages = queries::get_messages(
+        &state.db,
+        channel_id,
+        query.before,
+        query.after,
+        query.around,
+        limit,
+    )
+    .await?;
+
+    Ok(Json(with_message_extras(&state, messages, user.user_id).await?))
+}
 
+/// Attach reaction groups (with `me` set for the viewer), poll results and
+/// forward snapshots to a page of messages.
+async fn with_message_extras(
+    state: &AppState,
+    messages: Vec<crate::types::entities::Message>,
+    viewer_id: Uuid,
+) -> Result<Vec<serde_json::Value>, ApiError> {
     // Batch-load reactions for all messages
     let message_ids: Vec<Uuid> = messages.iter().map(|m| m.id).collect();
     let all_reactions =
@@ -241,7 +449,7 @@ async fn get_messages(
         let groups = reaction_map.entry(reaction.message_id).or_default();
         if let Some(group) = groups.iter_mut().find(|g| g.emoji_name == reaction.emoji_name) {
             group.count += 1;
-            if reaction.user_id == user.user_id {
+            if reaction.user_id == viewer_id {
                 group.me = true;
             }
         } else {
@@ -249,7 +457,7 @@ async fn get_messages(
                 emoji_name: reaction.emoji_name.clone(),
                 emoji_id: reaction.emoji_id,
                 count: 1,
-                me: reaction.user_id == user.user_id,
+                me: reaction.user_id == viewer_id,
             });
         }
     }
@@ -281,7 +489,7 @@ async fn get_messages(
                 let votes = votes_by_poll.get(&poll.id).map(|v| v.as_slice()).unwrap_or(&[]);
                 let msg_id = poll.message_id;
                 let results = crate::api::polls::build_poll_results(
-                    poll, options, votes, user.user_id,
+                    poll, options, votes, viewer_id,
                 );
                 (msg_id, results)
             })
@@ -290,6 +498,12 @@ async fn get_messages(
         std::collections::HashMap::new()
     };
 
+    // Batch-load forward snapshots, with origins the viewer may follow
+    let mut forwards = queries::get_forwards_for_messages(&state.db, &message_ids).await?;
+    resolve_forward_origins(state, &mut forwards, viewer_id).await;
+    let forward_map: std::collections::HashMap<Uuid, MessageForward> =
+        forwards.into_iter().map(|f| (f.message_id, f)).collect();
+
     let result: Vec<serde_json::Value> = messages
         .iter()
         .filter_map(|msg| {
@@ -308,11 +522,61 @@ async fn get_messages(
                     serde_json::to_value(poll_results).ok()?,
                 );
             }
+            if let Some(forward) = forward_map.get(&msg.id) {
+                obj.insert("forward".to_string(), serde_json::to_value(forward).ok()?);
+            }
             Some(val)
         })
         .collect();
 
-    Ok(Json(result))
+    Ok(result)
+}
+
+/// GET /messages/{message_id}/link
+///
+/// Resolve a message link (search result, bookmark, pin, reply target) in
+/// one call: the channel, its server, and a window of messages around it.
+async fn resolve_message_link(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(message_id): Path<Uuid>,
+    Query(query): Query<MessageLinkQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let message = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    let (channel, server_id, _) = resolve_channel_with_perm(
+        &state,
+        message.channel_id,
+        user.user_id,
+        Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY,
+    )
+    .await?;
+
+    let server = match server_id {
+        Some(sid) => queries::get_server_by_id(&state.db, sid).await?,
+        None => None,
+    };
+
+    let limit = query.limit.unwrap_or(50).clamp(1, 100);
+    let messages = queries::get_messages(
+        &state.db,
+        channel.id,
+        None,
+        None,
+        Some(message_id),
+        limit,
+    )
+    .await?;
+    let messages = with_message_extras(&state, messages, user.user_id).await?;
+
+    Ok(Json(serde_json::json!({
+        "channel": channel,
+        "server": server,
+        "message_id": message_id,
+        "messages": messages,
+    })))
 }
 
 async fn send_message(
@@ -331,14 +595,15 @@ async fn send_message(
             "Message must be 1-4000 characters".into(),
         ));
     }
+    if let Some(ref allowed) = body.allowed_mentions {
+        validate_allowed_mentions(allowed)?;
+    }
 
-    let (channel, server_id_opt, owner_id_opt) = resolve_channel_with_perm(
-        &state,
-        channel_id,
-        user.user_id,
-        Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
-    )
-    .await?;
+    let (channel, server_id_opt, owner_id_opt, thread) =
+        resolve_post_target(&state, channel_id, user.user_id).await?;
+    let automod =
+        automod_service::check_message(&state, &channel, user.user_id, false, &body.content)
+            .await?;
 
     let instance_id =
         queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
@@ -352,37 +617,59 @@ async fn send_message(
         user.user_id,
         &body.content,
         body.reply_to_id,
+        body.silent,
     )
     .await?;
 
     // Update the channel's last_message_id for unread tracking
     let _ = queries::update_channel_last_message(&state.db, channel_id, message_id).await;
 
+    if let Some(metadata) = thread {
+        record_thread_message(&state, &channel, server_id_opt, metadata, user.user_id).await?;
+    }
+
     // Check if author can use @everyone/@here (requires MENTION_EVERYONE permission)
-    let can_mention_everyone = match (server_id_opt, owner_id_opt) {
-        (Some(sid), Some(oid)) => {
-            perm_service::has_channel_permission(
-                &state.db, sid, channel_id, user.user_id, oid,
-                Permissions::MENTION_EVERYONE,
-            )
-            .await
-            .unwrap_or(false)
-        }
-        _ => false,
-    };
+    let can_mention_everyone =
+        can_mention_everyone(&state, server_id_opt, owner_id_opt, channel_id, user.user_id).await;
 
-    // Parse mentions and increment mention counts
-    let mentioned_user_ids = parse_mentions(
+    // Parse mentions, store them on the message and increment mention counts
+    let mut mentions = parse_mentions(
         &state.db,
         &state.gateway,
         &body.content,
         user.user_id,
         channel.server_id,
         can_mention_everyone,
+        body.allowed_mentions.as_ref(),
     )
     .await;
-    if !mentioned_user_ids.is_empty() {
-        let _ = queries::increment_mention_counts(&state.db, channel_id, &mentioned_user_ids).await;
+    // Replies only ping the replied-to author when the client opts in
+    let ping_reply_to = body
+        .reply_to_id
+        .filter(|_| body.allowed_mentions.as_ref().is_some_and(|a| a.replied_user));
+    if let Some(reply_to_id) = ping_reply_to {
+        let reply_author_id = queries::get_message_by_id(&state.db, reply_to_id)
+            .await?
+            .filter(|m| m.channel_id == channel_id)
+            .and_then(|m| m.author_id);
+        if let Some(uid) = reply_author_id.filter(|uid| *uid != user.user_id) {
+            mentions.add_user(uid);
+        }
+    }
+    let message = if mentions.is_empty() {
+        message
+    } else {
+        queries::set_message_mentions(
+            &state.db,
+            message_id,
+            &mentions.users,
+            &mentions.roles,
+            mentions.everyone,
+        )
+        .await?
+    };
+    if !body.silent && !mentions.notified.is_empty() {
+        let _ = queries::increment_mention_counts(&state.db, channel_id, &mentions.notified).await;
     }
 
     let author = queries::get_user_by_id(&state.db, user.user_id)
@@ -394,141 +681,625 @@ async fn send_message(
         author: PublicUser::from(author),
     };
 
-    if let Some(sid) = channel.server_id {
-        state
-            .gateway
-            .broadcast_to_server(sid, "MESSAGE_CREATE", &event, None);
-    } else {
-        // DM/GroupDM — reopen for any members who closed it, then dispatch
-        queries::reopen_dm_for_members(&state.db, channel_id).await?;
-        // Re-fetch channel to get the updated last_message_id (set above)
-        let updated_channel = queries::get_channel_by_id(&state.db, channel_id)
-            .await?
-            .ok_or(ApiError::NotFound("Channel"))?;
-        let members = queries::get_dm_members(&state.db, channel_id).await?;
-        let recipients: Vec<PublicUser> = members.iter().map(|m| PublicUser::from(m.clone())).collect();
-        let dm_event = crate::types::events::DmChannelCreateEvent {
-            channel: updated_channel,
-            recipients,
-        };
-        for member in &members {
-            state
-                .gateway
-                .dispatch_to_user(member.id, "DM_CHANNEL_CREATE", &dm_event);
-            state
-                .gateway
-                .dispatch_to_user(member.id, "MESSAGE_CREATE", &event);
-        }
+    dispatch_message_create(&state, &channel, &event).await?;
+
+    if automod.delete_after_send
+        && let Some(sid) = channel.server_id
+    {
+        automod_service::remove_message(&state, sid, channel_id, message_id).await?;
+        return Err(ApiError::InvalidInput(AUTOMOD_REMOVED_MESSAGE.into()));
     }
 
-    // Send push notifications to offline users (fire-and-forget)
-    if let Some(ref push) = state.push {
-        let push = std::sync::Arc::clone(push);
-        let state = state.clone();
-        let author_name = event
-            .author
-            .display_name
-            .clone()
-            .unwrap_or_else(|| event.author.username.clone());
-        let content = body.content.clone();
-        let server_id = channel.server_id;
-        let mentioned = mentioned_user_ids.clone();
-        tokio::spawn(async move {
-            crate::services::push::send_push_for_message(
-                &state,
-                &push,
-                channel_id,
-                server_id,
-                user.user_id,
-                &author_name,
-                &content,
-                &mentioned,
-            )
-            .await;
-        });
+    // Send push notifications to offline users, unless silent
+    if !body.silent {
+        spawn_message_push(&state, &channel, &event.author, &body.content, mentions.notified);
     }
 
     Ok(Json(message))
 }
 
-// ── Message Edit / Delete ────────────────────────────
+/// Resolve a channel `user_id` is about to post in, applying everything that
+/// gates sending: account state, the send permission (SEND_MESSAGES_IN_THREADS
+/// in threads), forum, announcement and locked-thread rules, timeouts and
+/// slowmode. Returns the channel, server and owner IDs, and thread metadata.
+pub(crate) async fn resolve_post_target(
+    state: &AppState,
+    channel_id: Uuid,
+    user_id: Uuid,
+) -> Result<
+    (
+        crate::types::entities::Channel,
+        Option<Uuid>,
+        Option<Uuid>,
+        Option<ThreadMetadata>,
+    ),
+    ApiError,
+> {
+    crate::services::auth::ensure_can_post(&state.db, &state.config, user_id).await?;
 
-async fn edit_message(
-    State(state): State<AppState>,
-    user: AuthUser,
-    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
-    Json(body): Json<EditMessageRequest>,
-) -> Result<impl IntoResponse, ApiError> {
-    if body.content.is_empty() || body.content.len() > 4000 {
+    // Threads are gated on SEND_MESSAGES_IN_THREADS rather than SEND_MESSAGES
+    let thread = queries::get_thread_metadata(&state.db, channel_id).await?;
+    let send_permission = if thread.is_some() {
+        Permissions::SEND_MESSAGES_IN_THREADS
+    } else {
+        Permissions::SEND_MESSAGES
+    };
+    let (channel, server_id, owner_id) = resolve_channel_with_perm(
+        state,
+        channel_id,
+        user_id,
+        Permissions::VIEW_CHANNEL | send_permission,
+    )
+    .await?;
+
+    if channel.channel_type == ChannelType::Forum {
         return Err(ApiError::InvalidInput(
-            "Message must be 1-4000 characters".into(),
+            "Forum channels only accept posts; reply inside a post instead".into(),
         ));
     }
+    ensure_can_announce(state, &channel, owner_id, user_id).await?;
 
-    let (channel, _, _) =
-        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
-            .await?;
-
-    let message = queries::get_message_by_id(&state.db, message_id)
-        .await?
-        .ok_or(ApiError::NotFound("Message"))?;
-
-    if message.channel_id != channel_id {
-        return Err(ApiError::NotFound("Message"));
+    if let Some(ref metadata) = thread
+        && metadata.locked
+        && !can_manage_thread(state, server_id, owner_id, metadata, user_id).await?
+    {
+        return Err(ApiError::Forbidden);
     }
 
-    // Only the author can edit their own message
-    if message.author_id != Some(user.user_id) {
-        return Err(ApiError::Forbidden);
+    if let Some(sid) = server_id {
+        automod_service::ensure_not_timed_out(state, sid, user_id).await?;
+        crate::services::safety::ensure_can_post(state, sid, user_id).await?;
     }
+    enforce_slowmode(state, &channel, user_id).await?;
 
-    let updated = queries::update_message_content(&state.db, message_id, &body.content).await?;
-    let attachments = queries::get_message_attachments(&state.db, message_id).await?;
-    let reactions = build_reaction_groups(&state, message_id, user.user_id).await?;
+    Ok((channel, server_id, owner_id, thread))
+}
 
-    let event = MessageUpdateEvent {
-        message: updated,
-        attachments,
-        reactions,
+/// Whether `user_id` can use @everyone/@here and ping roles that aren't
+/// mentionable in a channel, i.e. has MENTION_EVERYONE there. Never in DMs.
+async fn can_mention_everyone(
+    state: &AppState,
+    server_id: Option<Uuid>,
+    owner_id: Option<Uuid>,
+    channel_id: Uuid,
+    user_id: Uuid,
+) -> bool {
+    let (Some(sid), Some(oid)) = (server_id, owner_id) else {
+        return false;
+    };
+    perm_service::has_channel_permission(
+        &state.db,
+        sid,
+        channel_id,
+        user_id,
+        oid,
+        Permissions::MENTION_EVERYONE,
+    )
+    .await
+    .unwrap_or(false)
+}
+
+/// Send push notifications for a new message to offline recipients
+/// (fire-and-forget). Does nothing when push isn't configured.
+fn spawn_message_push(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    author: &PublicUser,
+    content: &str,
+    mentioned: Vec<Uuid>,
+) {
+    let Some(push) = state.push.clone() else {
+        return;
     };
+    let state = state.clone();
+    let channel_id = channel.id;
+    let server_id = channel.server_id;
+    let author_id = author.id;
+    let author_name = author
+        .display_name
+        .clone()
+        .unwrap_or_else(|| author.username.clone());
+    let content = content.to_string();
+    tokio::spawn(async move {
+        crate::services::push::send_push_for_message(
+            &state,
+            &push,
+            channel_id,
+            server_id,
+            author_id,
+            &author_name,
+            &content,
+            &mentioned,
+        )
+        .await;
+    });
+}
 
+/// Send MESSAGE_CREATE for a new message: to the whole server for server
+/// channels, or to each member of a DM (reopening it for anyone who closed it).
+async fn dispatch_message_create<T: serde::Serialize>(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    event: &T,
+) -> Result<(), ApiError> {
     if let Some(sid) = channel.server_id {
         state
             .gateway
-            .broadcast_to_server(sid, "MESSAGE_UPDATE", &event, None);
-    } else {
-        let members = queries::get_dm_members(&state.db, channel_id).await?;
-        for member in &members {
-            state
-                .gateway
-                .dispatch_to_user(member.id, "MESSAGE_UPDATE", &event);
-        }
+            .broadcast_to_server(sid, "MESSAGE_CREATE", event, None);
+        return Ok(());
     }
 
-    Ok(Json(event))
-}
-
-async fn delete_message(
-    State(state): State<AppState>,
-    user: AuthUser,
-    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
-) -> Result<impl IntoResponse, ApiError> {
-    let (channel, server_id, owner_id) =
-        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
-            .await?;
-
-    let message = queries::get_message_by_id(&state.db, message_id)
+    // DM/GroupDM — reopen for any members who closed it, then dispatch
+    queries::reopen_dm_for_members(&state.db, channel.id).await?;
+    // Re-fetch channel to get the updated last_message_id
+    let updated_channel = queries::get_channel_by_id(&state.db, channel.id)
         .await?
-        .ok_or(ApiError::NotFound("Message"))?;
-
-    if message.channel_id != channel_id {
-        return Err(ApiError::NotFound("Message"));
+        .ok_or(ApiError::NotFound("Channel"))?;
+    let members = queries::get_dm_members(&state.db, channel.id).await?;
+    let recipients: Vec<PublicUser> = members.iter().map(|m| PublicUser::from(m.clone())).collect();
+    let dm_event = crate::types::events::DmChannelCreateEvent {
+        channel: updated_channel,
+        recipients,
+    };
+    for member in &members {
+        state
+            .gateway
+            .dispatch_to_user(member.id, "DM_CHANNEL_CREATE", &dm_event);
+        state
+            .gateway
+            .dispatch_to_user(member.id, "MESSAGE_CREATE", event);
     }
+    Ok(())
+}
 
-    // Author can delete own messages; MANAGE_MESSAGES or global admin can delete any
-    if message.author_id != Some(user.user_id) {
-        let caller = queries::get_user_by_id(&state.db, user.user_id).await?;
-        let is_global_admin = caller.map(|u| u.is_admin).unwrap_or(false);
+/// Posting in an announcement channel needs SEND_ANNOUNCEMENTS on top of
+/// the usual send permission. Other channels pass through.
+pub(crate) async fn ensure_can_announce(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    owner_id: Option<Uuid>,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    if channel.channel_type != ChannelType::Announcement {
+        return Ok(());
+    }
+    let (Some(server_id), Some(owner_id)) = (channel.server_id, owner_id) else {
+        return Ok(());
+    };
+    if !perm_service::has_channel_permission(
+        &state.db,
+        server_id,
+        channel.id,
+        user_id,
+        owner_id,
+        Permissions::SEND_ANNOUNCEMENTS,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+    Ok(())
+}
+
+/// Bookkeeping after a message lands in a thread: push back auto-archive,
+/// reopen it if it was archived, and make the author a member.
+async fn record_thread_message(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    server_id: Option<Uuid>,
+    metadata: ThreadMetadata,
+    author_id: Uuid,
+) -> Result<(), ApiError> {
+    queries::record_thread_activity(&state.db, channel.id).await?;
+
+    if metadata.archived {
+        let reopened = queries::update_thread_metadata(
+            &state.db,
+            channel.id,
+            false,
+            metadata.locked,
+            metadata.auto_archive_minutes,
+        )
+        .await?;
+        let event = ThreadUpdateEvent {
+            channel: channel.clone(),
+            metadata: reopened,
+            server_id,
+        };
+        dispatch_thread_event(
+            state,
+            server_id,
+            metadata.parent_channel_id,
+            "THREAD_UPDATE",
+            &event,
+        )
+        .await?;
+    }
+
+    if queries::add_thread_member(&state.db, channel.id, author_id).await? {
+        dispatch_thread_members_update(state, server_id, &metadata, vec![author_id], vec![])
+            .await?;
+    }
+    Ok(())
+}
+
+// ── Announcements ────────────────────────────────────
+
+/// Publish an announcement to every channel following this one. Authors
+/// can publish their own messages; anyone else's needs MANAGE_MESSAGES.
+async fn publish_message(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (channel, server_id, owner_id) =
+        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
+            .await?;
+    let (Some(sid), Some(oid)) = (server_id, owner_id) else {
+        return Err(ApiError::InvalidInput(
+            "Only announcement channels can publish messages".into(),
+        ));
+    };
+    if channel.channel_type != ChannelType::Announcement {
+        return Err(ApiError::InvalidInput(
+            "Only announcement channels can publish messages".into(),
+        ));
+    }
+
+    let message = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .filter(|m| m.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    if message.author_id == Some(user.user_id) {
+        ensure_can_announce(&state, &channel, owner_id, user.user_id).await?;
+    } else if !perm_service::has_channel_permission(
+        &state.db,
+        sid,
+        channel_id,
+        user.user_id,
+        oid,
+        Permissions::MANAGE_MESSAGES,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+
+    let content = message.content.clone().unwrap_or_default();
+    let attachments = queries::get_message_attachments(&state.db, message_id).await?;
+    if content.is_empty() && attachments.is_empty() {
+        return Err(ApiError::InvalidInput("Message has nothing to publish".into()));
+    }
+
+    if !queries::mark_message_published(&state.db, message_id, user.user_id).await? {
+        return Err(ApiError::InvalidInput(
+            "Message has already been published".into(),
+        ));
+    }
+
+    let delivered =
+        crate::api::webhooks::publish_to_followers(&state, &channel, &content, &attachments)
+            .await?;
+
+    let event = MessagePublishEvent {
+        channel_id,
+        message_id,
+        follower_count: delivered,
+    };
+    state
+        .gateway
+        .broadcast_to_server(sid, "MESSAGE_PUBLISH", &event, None);
+
+    Ok(Json(event))
+}
+
+// ── Forwarding ───────────────────────────────────────
+
+/// POST /channels/{channel_id}/messages/{message_id}/forward
+///
+/// Copy a message into another channel or DM as a snapshot of its content
+/// and attachments, with an optional comment. Forwards don't ping anyone.
+async fn forward_message(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<ForwardMessageRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    // Shares the send rate limit: 10 messages per 10 seconds per user
+    let mut redis = state.redis.clone();
+    let rate_key = format!("msg_send:{}", user.user_id);
+    check_rate_limit(&mut redis, &rate_key, 10, 10).await?;
+
+    let comment = body.content.as_deref().map(str::trim).unwrap_or_default();
+    if comment.len() > 4000 {
+        return Err(ApiError::InvalidInput(
+            "Message must be at most 4000 characters".into(),
+        ));
+    }
+
+    let (_, source_server_id, _) = resolve_channel_with_perm(
+        &state,
+        channel_id,
+        user.user_id,
+        Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY,
+    )
+    .await?;
+    let source = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .filter(|m| m.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    // Forwarding a forward passes its snapshot along
+    let inner = queries::get_forwards_for_messages(&state.db, &[source.id])
+        .await?
+        .pop();
+    let (content, attachments) = match inner {
+        Some(inner) => (inner.content, inner.attachments.0),
+        None => (
+            source.content.clone(),
+            queries::get_message_attachments(&state.db, source.id).await?,
+        ),
+    };
+    if content.as_deref().unwrap_or_default().is_empty() && attachments.is_empty() {
+        return Err(ApiError::InvalidInput("Message has nothing to forward".into()));
+    }
+
+    let (channel, server_id, owner_id, thread) =
+        resolve_post_target(&state, body.channel_id, user.user_id).await?;
+    let can_attach = match (server_id, owner_id) {
+        (Some(sid), Some(oid)) if !attachments.is_empty() => {
+            perm_service::has_channel_permission(
+                &state.db,
+                sid,
+                channel.id,
+                user.user_id,
+                oid,
+                Permissions::ATTACH_FILES,
+            )
+            .await?
+        }
+        _ => true,
+    };
+    if !can_attach {
+        return Err(ApiError::Forbidden);
+    }
+    let automod = automod_service::check_message(
+        &state,
+        &channel,
+        user.user_id,
+        false,
+        &format!("{comment}\n{}", content.as_deref().unwrap_or_default()),
+    )
+    .await?;
+
+    let instance_id =
+        queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
+
+    let forward_id = Uuid::now_v7();
+    let message = queries::create_message(
+        &state.db,
+        forward_id,
+        instance_id,
+        channel.id,
+        user.user_id,
+        comment,
+        None,
+        false,
+    )
+    .await?;
+    let forward = queries::create_message_forward(
+        &state.db,
+        forward_id,
+        source.id,
+        source.channel_id,
+        content.as_deref(),
+        &attachments,
+        source.created_at,
+    )
+    .await?;
+
+    let _ = queries::update_channel_last_message(&state.db, channel.id, forward_id).await;
+    if let Some(metadata) = thread {
+        record_thread_message(&state, &channel, server_id, metadata, user.user_id).await?;
+    }
+
+    let author = queries::get_user_by_id(&state.db, user.user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+
+    // Everyone gets the snapshot; the origin is resolved per viewer on fetch
+    let mut event = MessageCreateWithForwardEvent {
+        message,
+        author: PublicUser::from(author),
+        forward,
+    };
+    dispatch_message_create(&state, &channel, &event).await?;
+
+    if automod.delete_after_send
+        && let Some(sid) = server_id
+    {
+        automod_service::remove_message(&state, sid, channel.id, forward_id).await?;
+        return Err(ApiError::InvalidInput(AUTOMOD_REMOVED_MESSAGE.into()));
+    }
+
+    event.forward.origin = Some(ForwardOrigin {
+        message_id: source.id,
+        channel_id: source.channel_id,
+        server_id: source_server_id,
+    });
+    Ok((axum::http::StatusCode::CREATED, Json(event)))
+}
+
+/// Fill in `origin` on the forwards whose source message still exists in a
+/// channel `viewer_id` can read. The rest keep only their snapshot.
+async fn resolve_forward_origins(
+    state: &AppState,
+    forwards: &mut [MessageForward],
+    viewer_id: Uuid,
+) {
+    // Source channel -> Some(server_id) when the viewer can read it
+    let mut readable: std::collections::HashMap<Uuid, Option<Option<Uuid>>> =
+        std::collections::HashMap::new();
+    for forward in forwards.iter_mut() {
+        let (Some(message_id), Some(channel_id)) =
+            (forward.source_message_id, forward.source_channel_id)
+        else {
+            continue;
+        };
+        let server_id = match readable.get(&channel_id) {
+            Some(cached) => *cached,
+            None => {
+                let resolved = resolve_channel_with_perm(
+                    state,
+                    channel_id,
+                    viewer_id,
+                    Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY,
+                )
+                .await
+                .ok()
+                .map(|(_, server_id, _)| server_id);
+                readable.insert(channel_id, resolved);
+                resolved
+            }
+        };
+        if let Some(server_id) = server_id {
+            forward.origin = Some(ForwardOrigin {
+                message_id,
+                channel_id,
+                server_id,
+            });
+        }
+    }
+}
+
+// ── Message Edit / Delete ────────────────────────────
+
+async fn edit_message(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<EditMessageRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if body.content.is_empty() || body.content.len() > 4000 {
+        return Err(ApiError::InvalidInput(
+            "Message must be 1-4000 characters".into(),
+        ));
+    }
+
+    let (channel, server_id, owner_id) =
+        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
+            .await?;
+
+    let message = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    if message.channel_id != channel_id {
+        return Err(ApiError::NotFound("Message"));
+    }
+
+    // Only the author can edit their own message
+    if message.author_id != Some(user.user_id) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let automod =
+        automod_service::check_message(&state, &channel, user.user_id, false, &body.content)
+            .await?;
+    if automod.delete_after_send
+        && let Some(sid) = channel.server_id
+    {
+        automod_service::remove_message(&state, sid, channel_id, message_id).await?;
+        return Err(ApiError::InvalidInput(AUTOMOD_REMOVED_MESSAGE.into()));
+    }
+
+    queries::update_message_content(&state.db, message_id, &body.content).await?;
+    // `message` was read before the update, so it still has the original content
+    mod_log::message_edit(&state, &channel, &message, &body.content);
+
+    // Re-parse mentions so the stored ones match the new content. Edits don't
+    // notify anyone, so mention counts are left alone, and they can only drop
+    // mentions: anything not pinged when sent (including what allowed_mentions
+    // suppressed) stays unpinged.
+    let can_mention_everyone =
+        can_mention_everyone(&state, server_id, owner_id, channel_id, user.user_id).await;
+    let mut mentions = parse_mentions(
+        &state.db,
+        &state.gateway,
+        &body.content,
+        user.user_id,
+        server_id,
+        can_mention_everyone,
+        None,
+    )
+    .await;
+    mentions.users.retain(|uid| message.mentions.contains(uid));
+    mentions.roles.retain(|rid| message.mention_roles.contains(rid));
+    mentions.everyone &= message.mention_everyone;
+    // Keep the reply ping the message was sent with
+    if let Some(reply_to_id) = message.reply_to_id {
+        let reply_author_id = queries::get_message_by_id(&state.db, reply_to_id)
+            .await?
+            .and_then(|m| m.author_id);
+        if let Some(uid) = reply_author_id.filter(|uid| message.mentions.contains(uid)) {
+            mentions.add_user(uid);
+        }
+    }
+    let updated = queries::set_message_mentions(
+        &state.db,
+        message_id,
+        &mentions.users,
+        &mentions.roles,
+        mentions.everyone,
+    )
+    .await?;
+    let attachments = queries::get_message_attachments(&state.db, message_id).await?;
+    let reactions = build_reaction_groups(&state, message_id, user.user_id).await?;
+
+    let event = MessageUpdateEvent {
+        message: updated,
+        attachments,
+        reactions,
+    };
+
+    if let Some(sid) = channel.server_id {
+        state
+            .gateway
+            .broadcast_to_server(sid, "MESSAGE_UPDATE", &event, None);
+    } else {
+        let members = queries::get_dm_members(&state.db, channel_id).await?;
+        for member in &members {
+            state
+                .gateway
+                .dispatch_to_user(member.id, "MESSAGE_UPDATE", &event);
+        }
+    }
+
+    Ok(Json(event))
+}
+
+async fn delete_message(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (channel, server_id, owner_id) =
+        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
+            .await?;
+
+    let message = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    if message.channel_id != channel_id {
+        return Err(ApiError::NotFound("Message"));
+    }
+
+    // Author can delete own messages; MANAGE_MESSAGES or global admin can delete any
+    if message.author_id != Some(user.user_id) {
+        let caller = queries::get_user_by_id(&state.db, user.user_id).await?;
+        let is_global_admin = caller.map(|u| u.is_admin).unwrap_or(false);
         if !is_global_admin {
             if let (Some(sid), Some(oid)) = (server_id, owner_id) {
                 if !perm_service::has_channel_permission(
@@ -549,11 +1320,24 @@ async fn delete_message(
         }
     }
 
+    remove_message(&state, &channel, message_id).await?;
+    mod_log::message_delete(&state, &channel, &message, user.user_id);
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Delete a message and tell everyone who can see the channel. Permission
+/// checks are the caller's job.
+pub(crate) async fn remove_message(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    message_id: Uuid,
+) -> Result<(), ApiError> {
     queries::delete_message(&state.db, message_id).await?;
 
     let event = MessageDeleteEvent {
         id: message_id,
-        channel_id,
+        channel_id: channel.id,
         server_id: channel.server_id,
     };
 
@@ -562,7 +1346,7 @@ async fn delete_message(
             .gateway
             .broadcast_to_server(sid, "MESSAGE_DELETE", &event, None);
     } else {
-        let members = queries::get_dm_members(&state.db, channel_id).await?;
+        let members = queries::get_dm_members(&state.db, channel.id).await?;
         for member in &members {
             state
                 .gateway
@@ -570,7 +1354,176 @@ async fn delete_message(
         }
     }
 
-    Ok(axum::http::StatusCode::NO_CONTENT)
+    Ok(())
+}
+
+/// Earlier versions of an edited message, oldest first. Visible to the
+/// author and to members who can manage messages in the channel.
+async fn get_message_history(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (_, server_id, owner_id) =
+        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
+            .await?;
+
+    let message = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .filter(|m| m.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    if message.author_id != Some(user.user_id) {
+        let (Some(sid), Some(oid)) = (server_id, owner_id) else {
+            return Err(ApiError::Forbidden);
+        };
+        if !perm_service::has_channel_permission(
+            &state.db,
+            sid,
+            channel_id,
+            user.user_id,
+            oid,
+            Permissions::MANAGE_MESSAGES,
+        )
+        .await?
+        {
+            return Err(ApiError::Forbidden);
+        }
+    }
+
+    let revisions = queries::get_message_revisions(&state.db, message_id).await?;
+    Ok(Json(revisions))
+}
+
+// ── Bulk delete ───────────────────────────────────────
+
+/// Most messages a single bulk delete may name
+const MAX_BULK_DELETE: usize = 100;
+/// Most messages a single purge may remove
+const MAX_PURGE: i64 = 1000;
+
+async fn bulk_delete_messages(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+    Json(body): Json<BulkDeleteMessagesRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (channel, server_id, _) = resolve_channel_with_perm(
+        &state,
+        channel_id,
+        user.user_id,
+        Permissions::VIEW_CHANNEL | Permissions::MANAGE_MESSAGES,
+    )
+    .await?;
+    let server_id = server_id.ok_or(ApiError::Forbidden)?;
+
+    let mut requested = body.messages;
+    requested.sort_unstable();
+    requested.dedup();
+    if requested.is_empty() || requested.len() > MAX_BULK_DELETE {
+        return Err(ApiError::InvalidInput(format!(
+            "Provide between 1 and {MAX_BULK_DELETE} message IDs"
+        )));
+    }
+
+    // Silently skip IDs from other channels or already deleted
+    let ids = queries::get_channel_message_ids(&state.db, channel_id, &requested).await?;
+    let deleted = remove_messages(&state, &channel, server_id, ids).await?;
+
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::MessageBulkDelete,
+        Some(channel_id),
+        body.reason.as_deref(),
+        Some(serde_json::json!({ "count": deleted })),
+    )
+    .await;
+
+    Ok(Json(BulkDeleteResponse { deleted }))
+}
+
+async fn purge_messages(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+    Json(body): Json<PurgeMessagesRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (channel, server_id, _) = resolve_channel_with_perm(
+        &state,
+        channel_id,
+        user.user_id,
+        Permissions::VIEW_CHANNEL | Permissions::MANAGE_MESSAGES,
+    )
+    .await?;
+    let server_id = server_id.ok_or(ApiError::Forbidden)?;
+
+    let limit = body.limit.unwrap_or(100);
+    if !(1..=MAX_PURGE).contains(&limit) {
+        return Err(ApiError::InvalidInput(format!(
+            "Purge limit must be between 1 and {MAX_PURGE}"
+        )));
+    }
+
+    let ids = queries::get_purgeable_message_ids(
+        &state.db,
+        channel_id,
+        body.author_id,
+        body.has_links,
+        body.has_attachments,
+        body.after,
+        limit,
+    )
+    .await?;
+    let deleted = remove_messages(&state, &channel, server_id, ids).await?;
+
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::MessageBulkDelete,
+        Some(channel_id),
+        body.reason.as_deref(),
+        Some(serde_json::json!({
+            "count": deleted,
+            "filters": {
+                "limit": limit,
+                "author_id": body.author_id,
+                "has_links": body.has_links,
+                "has_attachments": body.has_attachments,
+                "after": body.after,
+            },
+        })),
+    )
+    .await;
+
+    Ok(Json(BulkDeleteResponse { deleted }))
+}
+
+/// Delete messages from a server channel and send one MESSAGE_DELETE_BULK.
+async fn remove_messages(
+    state: &AppState,
+    channel: &crate::types::entities::Channel,
+    server_id: Uuid,
+    ids: Vec<Uuid>,
+) -> Result<u64, ApiError> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+
+    let deleted = crate::services::uploads::delete_messages_and_attachments(state, &ids).await?;
+
+    let event = MessageDeleteBulkEvent {
+        ids,
+        channel_id: channel.id,
+        server_id: Some(server_id),
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "MESSAGE_DELETE_BULK", &event, None);
+
+    Ok(deleted)
 }
 
 // ── Reactions ─────────────────────────────────────────
@@ -705,6 +1658,19 @@ async fn pin_message(
 
     queries::set_message_pinned(&state.db, message_id, true).await?;
 
+    if let Some(sid) = server_id {
+        audit_service::record(
+            &state,
+            sid,
+            user.user_id,
+            AuditAction::MessagePin,
+            Some(message_id),
+            None,
+            Some(serde_json::json!({ "channel_id": channel_id, "author_id": message.author_id })),
+        )
+        .await;
+    }
+
     let event = MessagePinEvent {
         channel_id,
         message_id,
@@ -765,6 +1731,19 @@ async fn unpin_message(
 
     queries::set_message_pinned(&state.db, message_id, false).await?;
 
+    if let Some(sid) = server_id {
+        audit_service::record(
+            &state,
+            sid,
+            user.user_id,
+            AuditAction::MessageUnpin,
+            Some(message_id),
+            None,
+            Some(serde_json::json!({ "channel_id": channel_id, "author_id": message.author_id })),
+        )
+        .await;
+    }
+
     let event = MessagePinEvent {
         channel_id,
         message_id,
@@ -927,6 +1906,11 @@ async fn set_override(
         return Err(ApiError::Forbidden);
     }
 
+    let previous = queries::get_channel_overrides(&state.db, channel_id)
+        .await?
+        .into_iter()
+        .find(|o| o.target_type == target_type && o.target_id == target_id);
+
     let override_id = Uuid::now_v7();
     let channel_override = queries::set_channel_override(
         &state.db,
@@ -939,6 +1923,22 @@ async fn set_override(
     )
     .await?;
 
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ChannelOverrideUpdate,
+        Some(channel_id),
+        None,
+        Some(serde_json::json!({
+            "target_type": target_type,
+            "target_id": target_id,
+            "allow": { "old": previous.as_ref().map(|o| o.allow), "new": channel_override.allow },
+            "deny": { "old": previous.as_ref().map(|o| o.deny), "new": channel_override.deny },
+        })),
+    )
+    .await;
+
     // Broadcast all overrides for this channel
     let all_overrides = queries::get_channel_overrides(&state.db, channel_id).await?;
     let event = ChannelOverrideUpdateEvent {
@@ -988,36 +1988,172 @@ async fn delete_override(
         return Err(ApiError::Forbidden);
     }
 
-    queries::delete_channel_override(&state.db, channel_id, &target_type, target_id).await?;
+    let previous = queries::get_channel_overrides(&state.db, channel_id)
+        .await?
+        .into_iter()
+        .find(|o| o.target_type == target_type && o.target_id == target_id);
+    queries::delete_channel_override(&state.db, channel_id, &target_type, target_id).await?;
+
+    if let Some(previous) = previous {
+        audit_service::record(
+            &state,
+            server_id,
+            user.user_id,
+            AuditAction::ChannelOverrideDelete,
+            Some(channel_id),
+            None,
+            Some(serde_json::json!({
+                "target_type": target_type,
+                "target_id": target_id,
+                "allow": { "old": previous.allow, "new": null },
+                "deny": { "old": previous.deny, "new": null },
+            })),
+        )
+        .await;
+    }
+
+    let all_overrides = queries::get_channel_overrides(&state.db, channel_id).await?;
+    let event = ChannelOverrideUpdateEvent {
+        server_id,
+        channel_id,
+        overrides: all_overrides,
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "CHANNEL_OVERRIDE_UPDATE", &event, None);
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+// ── Threads ──────────────────────────────────────────
+
+/// Inactivity periods (in minutes) a thread can auto-archive after
+const AUTO_ARCHIVE_OPTIONS: [i32; 4] = [60, 1440, 4320, 10080];
+const DEFAULT_AUTO_ARCHIVE_MINUTES: i32 = 1440;
+
+fn validate_thread_name(name: &str) -> Result<(), ApiError> {
+    if name.is_empty() || name.len() > 100 {
+        return Err(ApiError::InvalidInput(
+            "Thread name must be 1-100 characters".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_auto_archive(minutes: i32) -> Result<(), ApiError> {
+    if !AUTO_ARCHIVE_OPTIONS.contains(&minutes) {
+        return Err(ApiError::InvalidInput(format!(
+            "Auto-archive period must be one of {AUTO_ARCHIVE_OPTIONS:?} minutes"
+        )));
+    }
+    Ok(())
+}
+
+/// Whether the user may lock threads and post in locked ones: MANAGE_THREADS
+/// in servers, the thread's creator in DMs.
+async fn can_manage_thread(
+    state: &AppState,
+    server_id: Option<Uuid>,
+    owner_id: Option<Uuid>,
+    metadata: &ThreadMetadata,
+    user_id: Uuid,
+) -> Result<bool, ApiError> {
+    match (server_id, owner_id) {
+        (Some(sid), Some(oid)) => Ok(perm_service::has_channel_permission(
+            &state.db,
+            sid,
+            metadata.channel_id,
+            user_id,
+            oid,
+            Permissions::MANAGE_THREADS,
+        )
+        .await?),
+        _ => Ok(metadata.owner_id == Some(user_id)),
+    }
+}
 
-    let all_overrides = queries::get_channel_overrides(&state.db, channel_id).await?;
-    let event = ChannelOverrideUpdateEvent {
+/// Send a thread event to the server, or to the parent DM's members.
+pub(crate) async fn dispatch_thread_event<T: serde::Serialize>(
+    state: &AppState,
+    server_id: Option<Uuid>,
+    parent_channel_id: Uuid,
+    event_type: &str,
+    event: &T,
+) -> Result<(), sqlx::Error> {
+    if let Some(sid) = server_id {
+        state
+            .gateway
+            .broadcast_to_server(sid, event_type, event, None);
+    } else {
+        let members = queries::get_dm_members(&state.db, parent_channel_id).await?;
+        for member in &members {
+            state.gateway.dispatch_to_user(member.id, event_type, event);
+        }
+    }
+    Ok(())
+}
+
+async fn dispatch_thread_members_update(
+    state: &AppState,
+    server_id: Option<Uuid>,
+    metadata: &ThreadMetadata,
+    added_user_ids: Vec<Uuid>,
+    removed_user_ids: Vec<Uuid>,
+) -> Result<(), sqlx::Error> {
+    let event = ThreadMembersUpdateEvent {
+        channel_id: metadata.channel_id,
         server_id,
-        channel_id,
-        overrides: all_overrides,
+        added_user_ids,
+        removed_user_ids,
     };
-    state
-        .gateway
-        .broadcast_to_server(server_id, "CHANNEL_OVERRIDE_UPDATE", &event, None);
-
-    Ok(axum::http::StatusCode::NO_CONTENT)
+    dispatch_thread_event(
+        state,
+        server_id,
+        metadata.parent_channel_id,
+        "THREAD_MEMBERS_UPDATE",
+        &event,
+    )
+    .await
 }
 
-// ── Threads ──────────────────────────────────────────
+/// Resolve a thread channel the user can see, along with its metadata.
+async fn resolve_thread(
+    state: &AppState,
+    channel_id: Uuid,
+    user_id: Uuid,
+) -> Result<
+    (
+        crate::types::entities::Channel,
+        ThreadMetadata,
+        Option<Uuid>,
+        Option<Uuid>,
+    ),
+    ApiError,
+> {
+    let (channel, server_id, owner_id) =
+        resolve_channel_with_perm(state, channel_id, user_id, Permissions::VIEW_CHANNEL).await?;
+    let metadata = queries::get_thread_metadata(&state.db, channel_id)
+        .await?
+        .ok_or(ApiError::NotFound("Thread"))?;
+    Ok((channel, metadata, server_id, owner_id))
+}
 
+/// Start a thread off a channel, or post to a forum. A forum post is a
+/// thread whose starter message is created inside it from `content`, and
+/// may carry tags from the forum's tag set.
 async fn create_thread(
     State(state): State<AppState>,
     user: AuthUser,
     Path(channel_id): Path<Uuid>,
     Json(body): Json<CreateThreadRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
-    if body.name.is_empty() || body.name.len() > 100 {
-        return Err(ApiError::InvalidInput(
-            "Thread name must be 1-100 characters".into(),
-        ));
-    }
+    validate_thread_name(&body.name)?;
+    let auto_archive_minutes = body
+        .auto_archive_minutes
+        .unwrap_or(DEFAULT_AUTO_ARCHIVE_MINUTES);
+    validate_auto_archive(auto_archive_minutes)?;
 
-    let (parent_channel, server_id, _) = resolve_channel_with_perm(
+    let (parent_channel, server_id, owner_id) = resolve_channel_with_perm(
         &state,
         channel_id,
         user.user_id,
@@ -1025,6 +2161,55 @@ async fn create_thread(
     )
     .await?;
 
+    if queries::get_thread_metadata(&state.db, channel_id).await?.is_some() {
+        return Err(ApiError::InvalidInput(
+            "Threads cannot be created inside threads".into(),
+        ));
+    }
+
+    let is_forum = parent_channel.channel_type == ChannelType::Forum;
+    let tag_ids = if is_forum {
+        let content = body.content.as_deref().unwrap_or_default();
+        if content.is_empty() || content.len() > 4000 {
+            return Err(ApiError::InvalidInput(
+                "Forum posts need a starter message of 1-4000 characters".into(),
+            ));
+        }
+        if body.message_id.is_some() {
+            return Err(ApiError::InvalidInput(
+                "Forum posts cannot start from an existing message".into(),
+            ));
+        }
+        crate::services::auth::ensure_can_post(&state.db, &state.config, user.user_id).await?;
+        if let Some(sid) = server_id {
+            automod_service::ensure_not_timed_out(&state, sid, user.user_id).await?;
+            crate::services::safety::ensure_can_post(&state, sid, user.user_id).await?;
+        }
+        // The forum's slowmode paces new posts
+        enforce_slowmode(&state, &parent_channel, user.user_id).await?;
+        let automod =
+            automod_service::check_message(&state, &parent_channel, user.user_id, false, content)
+                .await?;
+        if automod.delete_after_send {
+            return Err(ApiError::InvalidInput(
+                "This post was blocked by AutoMod".into(),
+            ));
+        }
+        crate::api::forums::validate_post_tags(
+            &state,
+            &parent_channel,
+            body.tag_ids.as_deref().unwrap_or_default(),
+        )
+        .await?
+    } else {
+        if body.tag_ids.as_ref().is_some_and(|t| !t.is_empty()) {
+            return Err(ApiError::InvalidInput(
+                "Only forum posts can have tags".into(),
+            ));
+        }
+        Vec::new()
+    };
+
     let instance_id =
         queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
 
@@ -1042,41 +2227,132 @@ async fn create_thread(
     )
     .await?;
 
-    let metadata =
-        queries::create_thread_metadata(&state.db, thread_id, channel_id, body.message_id).await?;
+    let starter_message = match body.content {
+        Some(ref content) if is_forum => {
+            let message_id = Uuid::now_v7();
+            let message = queries::create_message(
+                &state.db,
+                message_id,
+                instance_id,
+                thread_id,
+                user.user_id,
+                content,
+                None,
+                false,
+            )
+            .await?;
+            queries::update_channel_last_message(&state.db, thread_id, message_id).await?;
+            Some(message)
+        }
+        _ => None,
+    };
+
+    let metadata = queries::create_thread_metadata(
+        &state.db,
+        thread_id,
+        channel_id,
+        starter_message
+            .as_ref()
+            .map(|m| m.id)
+            .or(body.message_id),
+        user.user_id,
+        auto_archive_minutes,
+    )
+    .await?;
+    queries::add_thread_member(&state.db, thread_id, user.user_id).await?;
+    if !tag_ids.is_empty() {
+        queries::set_forum_post_tags(&state.db, thread_id, &tag_ids).await?;
+    }
+    if starter_message.is_some() {
+        queries::record_thread_activity(&state.db, thread_id).await?;
+    }
+
+    // Starter messages ping like any other message. Overrides live on the
+    // forum, so MENTION_EVERYONE is checked there.
+    let mut mentioned = Vec::new();
+    let starter_message = match (starter_message, body.content.as_deref()) {
+        (Some(message), Some(content)) => {
+            let can_mention_everyone =
+                can_mention_everyone(&state, server_id, owner_id, channel_id, user.user_id).await;
+            let mentions = parse_mentions(
+                &state.db,
+                &state.gateway,
+                content,
+                user.user_id,
+                server_id,
+                can_mention_everyone,
+                None,
+            )
+            .await;
+            let message = if mentions.is_empty() {
+                message
+            } else {
+                queries::set_message_mentions(
+                    &state.db,
+                    message.id,
+                    &mentions.users,
+                    &mentions.roles,
+                    mentions.everyone,
+                )
+                .await?
+            };
+            if !mentions.notified.is_empty() {
+                let _ =
+                    queries::increment_mention_counts(&state.db, thread_id, &mentions.notified)
+                        .await;
+            }
+            mentioned = mentions.notified;
+            Some(message)
+        }
+        (message, _) => message,
+    };
 
     let event = ThreadCreateEvent {
         channel: thread_channel.clone(),
         metadata: metadata.clone(),
         parent_channel_id: channel_id,
         server_id: parent_channel.server_id,
+        tag_ids: tag_ids.clone(),
     };
+    dispatch_thread_event(&state, server_id, channel_id, "THREAD_CREATE", &event).await?;
 
-    if let Some(sid) = server_id {
+    if let (Some(message), Some(sid)) = (starter_message.as_ref(), server_id) {
+        let author = queries::get_user_by_id(&state.db, user.user_id)
+            .await?
+            .ok_or(ApiError::NotFound("User"))?;
+        let message_event = MessageCreateEvent {
+            message: message.clone(),
+            author: PublicUser::from(author),
+        };
         state
             .gateway
-            .broadcast_to_server(sid, "THREAD_CREATE", &event, None);
-    } else {
-        let members = queries::get_dm_members(&state.db, channel_id).await?;
-        for member in &members {
-            state
-                .gateway
-                .dispatch_to_user(member.id, "THREAD_CREATE", &event);
-        }
+            .broadcast_to_server(sid, "MESSAGE_CREATE", &message_event, None);
+        spawn_message_push(
+            &state,
+            &thread_channel,
+            &message_event.author,
+            message.content.as_deref().unwrap_or_default(),
+            mentioned,
+        );
     }
 
     Ok(Json(serde_json::json!({
         "channel": thread_channel,
         "metadata": metadata,
+        "tag_ids": tag_ids,
+        "message": starter_message,
     })))
 }
 
+/// List a channel's threads. Forums list their posts instead, with tags
+/// and reaction counts, sorted and filtered by the query.
 async fn list_threads(
     State(state): State<AppState>,
     user: AuthUser,
     Path(channel_id): Path<Uuid>,
-) -> Result<impl IntoResponse, ApiError> {
-    resolve_channel_with_perm(
+    Query(query): Query<ThreadListQuery>,
+) -> Result<axum::response::Response, ApiError> {
+    let (channel, _, _) = resolve_channel_with_perm(
         &state,
         channel_id,
         user.user_id,
@@ -1084,8 +2360,194 @@ async fn list_threads(
     )
     .await?;
 
-    let threads = queries::get_channel_threads(&state.db, channel_id).await?;
-    Ok(Json(threads))
+    if channel.channel_type == ChannelType::Forum {
+        let limit = query.limit.unwrap_or(50).clamp(1, 100);
+        let posts = queries::get_forum_posts(
+            &state.db,
+            channel_id,
+            query.archived,
+            query.tag_id,
+            query.sort,
+            query.before,
+            limit,
+        )
+        .await?;
+        return Ok(Json(posts).into_response());
+    }
+
+    let threads = queries::get_channel_threads(&state.db, channel_id, query.archived).await?;
+    Ok(Json(threads).into_response())
+}
+
+/// Rename, archive/unarchive, lock/unlock a thread or change its
+/// auto-archive period. The thread's creator may do everything except
+/// lock; locking and any change to a locked thread need MANAGE_THREADS.
+async fn update_thread(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+    Json(body): Json<UpdateThreadRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (channel, metadata, server_id, owner_id) =
+        resolve_thread(&state, channel_id, user.user_id).await?;
+
+    if let Some(ref name) = body.name {
+        validate_thread_name(name)?;
+    }
+    if let Some(minutes) = body.auto_archive_minutes {
+        validate_auto_archive(minutes)?;
+    }
+
+    let can_manage = can_manage_thread(&state, server_id, owner_id, &metadata, user.user_id).await?;
+    let is_thread_owner = metadata.owner_id == Some(user.user_id);
+    let locking = body.locked.is_some_and(|l| l != metadata.locked);
+    if !can_manage && (locking || metadata.locked || !is_thread_owner) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let new_tag_ids = match body.tag_ids {
+        Some(ref tag_ids) => {
+            let parent = queries::get_channel_by_id(&state.db, metadata.parent_channel_id)
+                .await?
+                .ok_or(ApiError::NotFound("Channel"))?;
+            if parent.channel_type != ChannelType::Forum {
+                return Err(ApiError::InvalidInput(
+                    "Only forum posts can have tags".into(),
+                ));
+            }
+            Some(crate::api::forums::validate_post_tags(&state, &parent, tag_ids).await?)
+        }
+        None => None,
+    };
+
+    let updated_channel = match body.name {
+        Some(ref name) if channel.name.as_deref() != Some(name) => {
+            queries::update_channel(
+                &state.db,
+                channel_id,
+                Some(name),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?
+        }
+        _ => channel.clone(),
+    };
+    let updated = queries::update_thread_metadata(
+        &state.db,
+        channel_id,
+        body.archived.unwrap_or(metadata.archived),
+        body.locked.unwrap_or(metadata.locked),
+        body.auto_archive_minutes
+            .unwrap_or(metadata.auto_archive_minutes),
+    )
+    .await?;
+
+    let mut tag_changes = None;
+    if let Some(tag_ids) = new_tag_ids {
+        let old_tag_ids = queries::get_forum_post_tag_ids(&state.db, channel_id).await?;
+        queries::set_forum_post_tags(&state.db, channel_id, &tag_ids).await?;
+        tag_changes = Some(serde_json::json!({ "old": old_tag_ids, "new": tag_ids }));
+
+        let event = ForumPostTagsUpdateEvent {
+            channel_id,
+            parent_channel_id: metadata.parent_channel_id,
+            server_id,
+            tag_ids,
+        };
+        dispatch_thread_event(
+            &state,
+            server_id,
+            metadata.parent_channel_id,
+            "FORUM_POST_TAGS_UPDATE",
+            &event,
+        )
+        .await?;
+    }
+
+    if let Some(sid) = server_id {
+        let mut changes = audit_service::diff(&metadata, &updated);
+        if updated_channel.name != channel.name {
+            changes["name"] = serde_json::json!({
+                "old": channel.name,
+                "new": updated_channel.name,
+            });
+        }
+        if let Some(tag_changes) = tag_changes {
+            changes["tag_ids"] = tag_changes;
+        }
+        audit_service::record(
+            &state,
+            sid,
+            user.user_id,
+            AuditAction::ThreadUpdate,
+            Some(channel_id),
+            None,
+            Some(changes),
+        )
+        .await;
+    }
+
+    let event = ThreadUpdateEvent {
+        channel: updated_channel,
+        metadata: updated,
+        server_id,
+    };
+    dispatch_thread_event(
+        &state,
+        server_id,
+        event.metadata.parent_channel_id,
+        "THREAD_UPDATE",
+        &event,
+    )
+    .await?;
+
+    Ok(Json(event))
+}
+
+// ── Thread Members ───────────────────────────────────
+
+async fn list_thread_members(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_thread(&state, channel_id, user.user_id).await?;
+    let members = queries::get_thread_members(&state.db, channel_id).await?;
+    Ok(Json(members))
+}
+
+async fn join_thread(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (_, metadata, server_id, _) = resolve_thread(&state, channel_id, user.user_id).await?;
+
+    if queries::add_thread_member(&state.db, channel_id, user.user_id).await? {
+        dispatch_thread_members_update(&state, server_id, &metadata, vec![user.user_id], vec![])
+            .await?;
+    }
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+async fn leave_thread(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (_, metadata, server_id, _) = resolve_thread(&state, channel_id, user.user_id).await?;
+
+    if queries::remove_thread_member(&state.db, channel_id, user.user_id).await? {
+        dispatch_thread_members_update(&state, server_id, &metadata, vec![], vec![user.user_id])
+            .await?;
+    }
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
 // ── Typing Indicators ────────────────────────────────
@@ -1159,12 +2621,71 @@ async fn ack_message(
 
 static RE_MENTION_ID: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"<@([0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})>").expect("RE_MENTION_ID is a valid regex"));
+static RE_MENTION_ROLE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<@&([0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12})>").expect("RE_MENTION_ROLE is a valid regex"));
 static RE_MENTION_NAME: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"@(\w{2,32})").expect("RE_MENTION_NAME is a valid regex"));
 
-/// Parse `<@uuid>`, `@username`, `@everyone`, and `@here` mentions from message content.
-/// Returns a deduplicated list of mentioned user IDs (excluding the author).
-/// `can_mention_everyone` gates whether @everyone/@here actually trigger mention counts.
+/// Mentions resolved from a message's content.
+#[derive(Debug, Default)]
+pub(crate) struct ParsedMentions {
+    /// Users mentioned directly by ID or username
+    pub users: Vec<Uuid>,
+    /// Roles that were mentioned and the author was allowed to ping
+    pub roles: Vec<Uuid>,
+    /// Whether @everyone or @here went through
+    pub everyone: bool,
+    /// Everyone pinged by the message (excluding the author): direct
+    /// mentions, role members and @everyone/@here recipients. Drives mention
+    /// counts and push eligibility.
+    pub notified: Vec<Uuid>,
+}
+
+impl ParsedMentions {
+    pub fn is_empty(&self) -> bool {
+        self.users.is_empty() && self.roles.is_empty() && !self.everyone
+    }
+
+    /// Ping a user who wasn't named in the content, e.g. a reply's target.
+    pub fn add_user(&mut self, user_id: Uuid) {
+        if !self.users.contains(&user_id) {
+            self.users.push(user_id);
+        }
+        if !self.notified.contains(&user_id) {
+            self.notified.push(user_id);
+        }
+    }
+}
+
+const MAX_ALLOWED_MENTION_IDS: usize = 100;
+
+/// Reject `allowed_mentions` that both parse a kind and list IDs for it.
+pub(crate) fn validate_allowed_mentions(allowed: &AllowedMentions) -> Result<(), ApiError> {
+    if allowed.parse.contains(&AllowedMentionType::Users) && !allowed.users.is_empty() {
+        return Err(ApiError::InvalidInput(
+            "allowed_mentions can't parse users and list users at the same time".into(),
+        ));
+    }
+    if allowed.parse.contains(&AllowedMentionType::Roles) && !allowed.roles.is_empty() {
+        return Err(ApiError::InvalidInput(
+            "allowed_mentions can't parse roles and list roles at the same time".into(),
+        ));
+    }
+    if allowed.users.len() > MAX_ALLOWED_MENTION_IDS
+        || allowed.roles.len() > MAX_ALLOWED_MENTION_IDS
+    {
+        return Err(ApiError::InvalidInput(format!(
+            "allowed_mentions can list at most {MAX_ALLOWED_MENTION_IDS} users and roles each"
+        )));
+    }
+    Ok(())
+}
+
+/// Parse `<@uuid>`, `<@&role_id>`, `@username`, `@everyone`, and `@here` mentions from
+/// message content.
+/// `can_mention_everyone` gates whether @everyone/@here actually trigger mention counts,
+/// and whether roles that aren't mentionable can be pinged. `allowed` narrows which of
+/// the mentions in the content ping anyone; `None` allows them all.
 pub(crate) async fn parse_mentions(
     pool: &sqlx::PgPool,
     gateway: &crate::gateway::GatewayState,
@@ -1172,39 +2693,18 @@ pub(crate) async fn parse_mentions(
     author_id: Uuid,
     server_id: Option<Uuid>,
     can_mention_everyone: bool,
-) -> Vec<Uuid> {
-    let mut mentioned: std::collections::HashSet<Uuid> = std::collections::HashSet::new();
-
-    // @everyone and @here only apply in server channels when the author has permission
-    if let Some(sid) = server_id {
-        if can_mention_everyone {
-            if content.contains("@everyone") {
-                if let Ok(members) = queries::get_server_members(pool, sid).await {
-                    for m in &members {
-                        if m.user_id != author_id {
-                            mentioned.insert(m.user_id);
-                        }
-                    }
-                }
-                return mentioned.into_iter().collect();
-            }
+    allowed: Option<&AllowedMentions>,
+) -> ParsedMentions {
+    let parses = |kind| allowed.is_none_or(|a| a.parse.contains(&kind));
 
-            if content.contains("@here") {
-                for uid in gateway.get_online_server_user_ids(sid) {
-                    if uid != author_id {
-                        mentioned.insert(uid);
-                    }
-                }
-            }
-        }
-    }
+    let mut users: std::collections::HashSet<Uuid> = std::collections::HashSet::new();
 
     // Direct ID mentions: <@uuid>
     for cap in RE_MENTION_ID.captures_iter(content) {
-        if let Ok(uid) = cap[1].parse::<Uuid>() {
-            if uid != author_id {
-                mentioned.insert(uid);
-            }
+        if let Ok(uid) = cap[1].parse::<Uuid>()
+            && uid != author_id
+        {
+            users.insert(uid);
         }
     }
 
@@ -1214,23 +2714,86 @@ pub(crate) async fn parse_mentions(
         if username == "everyone" || username == "here" {
             continue;
         }
-        if let Ok(Some(user)) = queries::get_user_by_username(pool, username).await {
-            if user.id != author_id {
-                mentioned.insert(user.id);
-            }
+        if let Ok(Some(user)) = queries::get_user_by_username(pool, username).await
+            && user.id != author_id
+        {
+            users.insert(user.id);
         }
     }
 
+    if !parses(AllowedMentionType::Users) {
+        users.retain(|uid| allowed.is_some_and(|a| a.users.contains(uid)));
+    }
+
+    let Some(sid) = server_id else {
+        let users: Vec<Uuid> = users.into_iter().collect();
+        return ParsedMentions {
+            notified: users.clone(),
+            users,
+            ..Default::default()
+        };
+    };
+
     // For server channels, filter to actual server members
-    if let Some(sid) = server_id {
-        if let Ok(members) = queries::get_server_members(pool, sid).await {
-            let member_ids: std::collections::HashSet<Uuid> =
-                members.iter().map(|m| m.user_id).collect();
-            mentioned.retain(|uid| member_ids.contains(uid));
+    let member_ids: std::collections::HashSet<Uuid> = queries::get_server_members(pool, sid)
+        .await
+        .map(|members| members.into_iter().map(|m| m.user_id).collect())
+        .unwrap_or_default();
+    users.retain(|uid| member_ids.contains(uid));
+
+    // Role mentions: <@&role_id>. Roles that aren't mentionable need MENTION_EVERYONE.
+    let mut role_ids: Vec<Uuid> = RE_MENTION_ROLE
+        .captures_iter(content)
+        .filter_map(|cap| cap[1].parse::<Uuid>().ok())
+        .filter(|id| {
+            parses(AllowedMentionType::Roles) || allowed.is_some_and(|a| a.roles.contains(id))
+        })
+        .collect();
+    role_ids.sort();
+    role_ids.dedup();
+    let mut roles = Vec::new();
+    if !role_ids.is_empty()
+        && let Ok(server_roles) = queries::get_server_roles(pool, sid).await
+    {
+        roles = server_roles
+            .into_iter()
+            .filter(|r| role_ids.contains(&r.id) && !r.is_default)
+            .filter(|r| r.mentionable || can_mention_everyone)
+            .map(|r| r.id)
+            .collect();
+    }
+
+    let mut notified = users.clone();
+    if !roles.is_empty()
+        && let Ok(role_members) = queries::get_role_member_ids(pool, sid, &roles).await
+    {
+        notified.extend(role_members);
+    }
+
+    // @everyone and @here only apply when the author has permission
+    let mut everyone = false;
+    if can_mention_everyone && parses(AllowedMentionType::Everyone) {
+        if content.contains("@everyone") {
+            everyone = true;
+            notified.extend(member_ids.iter().copied());
+        } else if content.contains("@here") {
+            everyone = true;
+            notified.extend(
+                gateway
+                    .get_online_server_user_ids(sid)
+                    .into_iter()
+                    .filter(|uid| member_ids.contains(uid)),
+            );
         }
     }
+    notified.remove(&author_id);
 
-    mentioned.into_iter().collect()
+    ParsedMentions {
+        users: users.into_iter().collect(),
+        roles,
+        everyone,
+        notified: notified.into_iter().collect(),
+    }
 }
 
 async fn build_reaction_groups(
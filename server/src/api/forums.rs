@@ -0,0 +1,240 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use uuid::Uuid;
+
+use crate::api::auth::AuthUser;
+use crate::api::channels::resolve_channel_with_perm;
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::state::AppState;
+use crate::types::entities::{
+    AuditAction, Channel, ChannelType, CreateForumTagRequest, UpdateForumTagRequest,
+};
+use crate::types::events::ForumTagsUpdateEvent;
+use crate::types::permissions::Permissions;
+
+const MAX_TAGS_PER_FORUM: usize = 20;
+/// Most tags a single forum post can carry
+const MAX_TAGS_PER_POST: usize = 5;
+const MAX_TAG_NAME_LENGTH: usize = 32;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{channel_id}/tags", get(list_tags).post(create_tag))
+        .route(
+            "/{channel_id}/tags/{tag_id}",
+            axum::routing::patch(update_tag).delete(delete_tag),
+        )
+}
+
+fn validate_tag_name(name: &str) -> Result<String, ApiError> {
+    let name = name.trim();
+    if name.is_empty() || name.len() > MAX_TAG_NAME_LENGTH {
+        return Err(ApiError::InvalidInput(format!(
+            "Tag name must be 1-{MAX_TAG_NAME_LENGTH} characters"
+        )));
+    }
+    Ok(name.to_string())
+}
+
+/// Check the tags picked for a post against its forum's tag set and the
+/// forum's required-tag setting. Returns them deduplicated.
+pub(crate) async fn validate_post_tags(
+    state: &AppState,
+    forum: &Channel,
+    tag_ids: &[Uuid],
+) -> Result<Vec<Uuid>, ApiError> {
+    let mut tag_ids = tag_ids.to_vec();
+    tag_ids.sort();
+    tag_ids.dedup();
+
+    if tag_ids.len() > MAX_TAGS_PER_POST {
+        return Err(ApiError::InvalidInput(format!(
+            "A post can have at most {MAX_TAGS_PER_POST} tags"
+        )));
+    }
+    if forum.require_tag && tag_ids.is_empty() {
+        return Err(ApiError::InvalidInput(
+            "This forum requires posts to have a tag".into(),
+        ));
+    }
+
+    let available = queries::get_forum_tags(&state.db, forum.id).await?;
+    if !tag_ids.iter().all(|id| available.iter().any(|t| t.id == *id)) {
+        return Err(ApiError::InvalidInput("Unknown forum tag".into()));
+    }
+    Ok(tag_ids)
+}
+
+/// Resolve a forum channel the user holds `required` in.
+async fn resolve_forum(
+    state: &AppState,
+    channel_id: Uuid,
+    user_id: Uuid,
+    required: Permissions,
+) -> Result<(Channel, Uuid), ApiError> {
+    let (channel, server_id, _) =
+        resolve_channel_with_perm(state, channel_id, user_id, required).await?;
+    match (channel.channel_type, server_id) {
+        (ChannelType::Forum, Some(sid)) => Ok((channel, sid)),
+        _ => Err(ApiError::InvalidInput("Channel is not a forum".into())),
+    }
+}
+
+async fn broadcast_tags(
+    state: &AppState,
+    server_id: Uuid,
+    channel_id: Uuid,
+) -> Result<(), ApiError> {
+    let tags = queries::get_forum_tags(&state.db, channel_id).await?;
+    let event = ForumTagsUpdateEvent {
+        channel_id,
+        server_id,
+        tags,
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "FORUM_TAGS_UPDATE", &event, None);
+    Ok(())
+}
+
+/// GET /channels/{channel_id}/tags
+async fn list_tags(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_forum(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL).await?;
+    let tags = queries::get_forum_tags(&state.db, channel_id).await?;
+    Ok(Json(tags))
+}
+
+/// POST /channels/{channel_id}/tags
+async fn create_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+    Json(body): Json<CreateForumTagRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let name = validate_tag_name(&body.name)?;
+    let (_, server_id) =
+        resolve_forum(&state, channel_id, user.user_id, Permissions::MANAGE_CHANNELS).await?;
+
+    let existing = queries::get_forum_tags(&state.db, channel_id).await?;
+    if existing.len() >= MAX_TAGS_PER_FORUM {
+        return Err(ApiError::InvalidInput(format!(
+            "A forum can have at most {MAX_TAGS_PER_FORUM} tags"
+        )));
+    }
+    if existing.iter().any(|t| t.name.eq_ignore_ascii_case(&name)) {
+        return Err(ApiError::InvalidInput("A tag with that name already exists".into()));
+    }
+
+    let tag = queries::create_forum_tag(
+        &state.db,
+        Uuid::now_v7(),
+        channel_id,
+        &name,
+        body.emoji.as_deref(),
+        existing.len() as i32,
+    )
+    .await?;
+
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ChannelUpdate,
+        Some(channel_id),
+        None,
+        Some(serde_json::json!({ "tags": { "added": tag.name } })),
+    )
+    .await;
+    broadcast_tags(&state, server_id, channel_id).await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(tag)))
+}
+
+/// PATCH /channels/{channel_id}/tags/{tag_id}
+async fn update_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, tag_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<UpdateForumTagRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let name = body.name.as_deref().map(validate_tag_name).transpose()?;
+    let (_, server_id) =
+        resolve_forum(&state, channel_id, user.user_id, Permissions::MANAGE_CHANNELS).await?;
+
+    let tag = queries::get_forum_tag(&state.db, tag_id)
+        .await?
+        .filter(|t| t.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Tag"))?;
+
+    if let Some(ref name) = name {
+        let existing = queries::get_forum_tags(&state.db, channel_id).await?;
+        if existing
+            .iter()
+            .any(|t| t.id != tag_id && t.name.eq_ignore_ascii_case(name))
+        {
+            return Err(ApiError::InvalidInput("A tag with that name already exists".into()));
+        }
+    }
+
+    let updated = queries::update_forum_tag(
+        &state.db,
+        tag_id,
+        name.as_deref(),
+        body.emoji.as_deref(),
+        body.position,
+    )
+    .await?;
+
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ChannelUpdate,
+        Some(channel_id),
+        None,
+        Some(serde_json::json!({ "tags": audit_service::diff(&tag, &updated) })),
+    )
+    .await;
+    broadcast_tags(&state, server_id, channel_id).await?;
+
+    Ok(Json(updated))
+}
+
+/// DELETE /channels/{channel_id}/tags/{tag_id}
+async fn delete_tag(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, tag_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (_, server_id) =
+        resolve_forum(&state, channel_id, user.user_id, Permissions::MANAGE_CHANNELS).await?;
+
+    let tag = queries::get_forum_tag(&state.db, tag_id)
+        .await?
+        .filter(|t| t.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Tag"))?;
+
+    queries::delete_forum_tag(&state.db, tag_id).await?;
+
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ChannelUpdate,
+        Some(channel_id),
+        None,
+        Some(serde_json::json!({ "tags": { "removed": tag.name } })),
+    )
+    .await;
+    broadcast_tags(&state, server_id, channel_id).await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
@@ -10,7 +10,9 @@ use crate::api::auth::AuthUser;
 use crate::api::servers::resolve_server_member;
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
 use crate::services::permissions as perm_service;
+use crate::services::safety as safety_service;
 use crate::state::AppState;
 use crate::types::entities::{AuditAction, CreateInviteRequest};
 use crate::types::events::{InviteCreateEvent, InviteDeleteEvent};
@@ -83,8 +85,8 @@ async fn create_invite(
     .await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::InviteCreate,
@@ -208,8 +210,12 @@ async fn use_invite(
         return Err(ApiError::InvalidInput("Already a member".into()));
     }
 
+    safety_service::ensure_invites_open(&state.db, server_id).await?;
+
     let member = queries::add_server_member(&state.db, server_id, user.user_id).await?;
     queries::increment_invite_uses(&state.db, &code).await?;
+    safety_service::record_join(&state, server_id, user.user_id, Some(&code)).await;
+    crate::services::mod_log::member_join(&state, server_id, user.user_id);
 
     // Subscribe gateway sessions and update presence cache
     state
@@ -276,8 +282,8 @@ async fn delete_invite(
     queries::delete_invite(&state.db, &code).await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::InviteDelete,
@@ -1,19 +1,25 @@
 pub mod admin;
 pub mod admin_dashboard;
 pub mod auth;
+pub mod automod;
 pub mod bans;
 pub mod bookmarks;
 pub mod bug_reports;
 pub mod channels;
 pub mod dms;
+pub mod forums;
 pub mod gif;
 pub mod invites;
 pub mod links;
+pub mod mod_log;
 pub mod polls;
 pub mod push;
 pub mod relationships;
+pub mod reports;
 pub mod roles;
+pub mod safety;
 pub mod scheduled;
+pub mod screening;
 pub mod search;
 pub mod servers;
 pub mod soundboard;
@@ -53,6 +59,11 @@ fn api_routes() -> Router<AppState> {
                 .merge(roles::routes())
                 .merge(invites::routes())
                 .merge(bans::routes())
+                .merge(automod::routes())
+                .merge(reports::routes())
+                .merge(mod_log::routes())
+                .merge(safety::routes())
+                .merge(screening::routes())
                 .merge(soundboard::routes())
                 .merge(webhooks::server_routes()),
         )
@@ -63,7 +74,9 @@ fn api_routes() -> Router<AppState> {
                 .merge(webhooks::routes())
                 .merge(scheduled::channel_routes())
                 .merge(links::routes())
-                .merge(polls::routes()),
+                .merge(forums::routes())
+                .merge(polls::routes())
+                .merge(reports::channel_routes()),
         )
         .nest("/admin", admin::routes())
         .nest("/admin/dashboard", admin_dashboard::routes())
@@ -72,6 +85,7 @@ fn api_routes() -> Router<AppState> {
         .nest("/search", search::routes())
         .merge(gif::routes())
         .merge(invites::resolve_routes())
+        .merge(channels::message_link_routes())
         .merge(webhooks::execute_routes())
         .merge(bug_reports::routes())
         .merge(unfurl::routes())
@@ -82,14 +96,24 @@ fn user_routes() -> Router<AppState> {
     Router::new()
         .route("/@me", get(get_me).patch(update_me).delete(delete_me))
         .route("/@me/avatar", post(request_avatar_upload))
+        .route("/@me/email", post(change_email))
         .route(
             "/@me/notification-preferences",
             get(get_notification_prefs).put(set_notification_pref),
         )
+        .route(
+            "/@me/digest-settings",
+            get(get_digest_settings).put(set_digest_settings),
+        )
+        .route(
+            "/digest/unsubscribe",
+            get(digest_unsubscribe_page).post(digest_unsubscribe),
+        )
         .nest("/@me/themes", themes::routes())
         .nest("/@me/bookmarks", bookmarks::routes())
         .nest("/@me/scheduled-messages", scheduled::routes())
         .route("/search", get(search_users))
+        .merge(reports::user_routes())
 }
 
 async fn get_me(
@@ -226,17 +250,7 @@ async fn delete_me(
     let user_data = queries::get_user_by_id(&state.db, user.user_id)
         .await?
         .ok_or(crate::error::ApiError::NotFound("User"))?;
-    let password_hash = user_data
-        .password_hash
-        .as_ref()
-        .ok_or(crate::error::ApiError::Unauthorized)?;
-
-    use argon2::{Argon2, PasswordHash, PasswordVerifier};
-    let parsed_hash = PasswordHash::new(password_hash)
-        .map_err(|e| anyhow::anyhow!("Invalid hash: {}", e))?;
-    Argon2::default()
-        .verify_password(body.password.as_bytes(), &parsed_hash)
-        .map_err(|_| crate::error::ApiError::InvalidInput("Incorrect password".into()))?;
+    crate::services::auth::verify_password(&user_data, &body.password)?;
 
     // Delete all servers owned by this user
     let servers = queries::get_user_servers(&state.db, user.user_id).await?;
@@ -255,6 +269,36 @@ async fn delete_me(
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
+#[derive(serde::Deserialize)]
+struct ChangeEmailRequest {
+    new_email: String,
+    password: String,
+}
+
+async fn change_email(
+    State(state): State<AppState>,
+    user: crate::api::auth::AuthUser,
+    axum::Json(body): axum::Json<ChangeEmailRequest>,
+) -> Result<impl IntoResponse, crate::error::ApiError> {
+    let mut redis = state.redis.clone();
+    let rate_key = format!("email_change:{}", user.user_id);
+    crate::api::auth::check_rate_limit(&mut redis, &rate_key, 5, 900).await?;
+
+    crate::services::auth::request_email_change(
+        &state.db,
+        &state.config,
+        state.mailer.as_deref(),
+        user.user_id,
+        body.new_email.trim(),
+        &body.password,
+    )
+    .await?;
+
+    Ok(axum::Json(serde_json::json!({
+        "message": "Check your new address for a confirmation link."
+    })))
+}
+
 async fn request_avatar_upload(
     State(state): State<AppState>,
     user: crate::api::auth::AuthUser,
@@ -360,6 +404,86 @@ async fn set_notification_pref(
     Ok(axum::Json(pref))
 }
 
+async fn get_digest_settings(
+    State(state): State<AppState>,
+    user: crate::api::auth::AuthUser,
+) -> Result<impl IntoResponse, crate::error::ApiError> {
+    use crate::services::digest;
+
+    let default_frequency = state
+        .config
+        .digest
+        .as_ref()
+        .map(digest::default_frequency)
+        .unwrap_or("daily");
+    let settings = crate::db::queries::ensure_digest_settings(
+        &state.db,
+        user.user_id,
+        default_frequency,
+        &digest::generate_unsubscribe_token(),
+    )
+    .await?;
+    Ok(axum::Json(settings))
+}
+
+#[derive(serde::Deserialize)]
+struct SetDigestSettingsRequest {
+    frequency: String,
+}
+
+async fn set_digest_settings(
+    State(state): State<AppState>,
+    user: crate::api::auth::AuthUser,
+    axum::Json(body): axum::Json<SetDigestSettingsRequest>,
+) -> Result<impl IntoResponse, crate::error::ApiError> {
+    use crate::services::digest;
+
+    if !digest::FREQUENCIES.contains(&body.frequency.as_str()) {
+        return Err(crate::error::ApiError::InvalidInput(
+            "frequency must be 'daily', 'weekly', or 'never'".into(),
+        ));
+    }
+
+    let settings = crate::db::queries::set_digest_frequency(
+        &state.db,
+        user.user_id,
+        &body.frequency,
+        &digest::generate_unsubscribe_token(),
+    )
+    .await?;
+    Ok(axum::Json(settings))
+}
+
+#[derive(serde::Deserialize)]
+struct DigestUnsubscribeQuery {
+    token: String,
+}
+
+/// Unsubscribe link from digest emails (no auth). Only shows a confirmation
+/// form, so link scanners and prefetchers can't unsubscribe anyone.
+async fn digest_unsubscribe_page() -> impl IntoResponse {
+    // An empty action posts back to this URL, token included
+    axum::response::Html(
+        "<p>Stop receiving email digests?</p>\
+         <form method=\"post\" action=\"\"><button type=\"submit\">Unsubscribe</button></form>",
+    )
+}
+
+/// Carries out the unsubscribe, from the confirmation form or a mail
+/// client's RFC 8058 one-click POST.
+async fn digest_unsubscribe(
+    State(state): State<AppState>,
+    axum::extract::Query(params): axum::extract::Query<DigestUnsubscribeQuery>,
+) -> Result<impl IntoResponse, crate::error::ApiError> {
+    if !crate::db::queries::unsubscribe_digest_by_token(&state.db, &params.token).await? {
+        return Err(crate::error::ApiError::NotFound("Subscription"));
+    }
+    Ok(axum::response::Html(
+        "<p>You've been unsubscribed from email digests. \
+         You can turn them back on in your notification settings.</p>",
+    ))
+}
+
 async fn health_check() -> impl IntoResponse {
     axum::Json(serde_json::json!({ "status": "ok" }))
 }
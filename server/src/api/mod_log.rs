@@ -0,0 +1,102 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use uuid::Uuid;
+
+use crate::api::auth::AuthUser;
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::permissions as perm_service;
+use crate::state::AppState;
+use crate::types::entities::{AuditAction, ChannelType, SetLogSettingsRequest};
+use crate::types::permissions::Permissions;
+
+pub fn routes() -> Router<AppState> {
+    Router::new().route(
+        "/{server_id}/log-settings",
+        get(get_log_settings).put(set_log_settings),
+    )
+}
+
+/// The mod log is configured by members with MANAGE_SERVER.
+async fn require_manage_server(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    if !perm_service::has_server_permission(
+        &state.db,
+        server_id,
+        user_id,
+        server.owner_id,
+        Permissions::MANAGE_SERVER,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+    Ok(())
+}
+
+async fn get_log_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+    let settings = queries::get_server_log_settings(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Log settings"))?;
+    Ok(Json(settings))
+}
+
+async fn set_log_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<SetLogSettingsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+
+    if let Some(channel_id) = body.channel_id {
+        let channel = queries::get_channel_by_id(&state.db, channel_id).await?;
+        if channel.is_none_or(|c| {
+            c.server_id != Some(server_id) || c.channel_type != ChannelType::Text
+        }) {
+            return Err(ApiError::InvalidInput(format!("Unknown text channel {channel_id}")));
+        }
+    }
+
+    let old = queries::get_server_log_settings(&state.db, server_id).await?;
+    let settings = queries::upsert_server_log_settings(
+        &state.db,
+        server_id,
+        body.channel_id,
+        body.log_moderation.unwrap_or(true),
+        body.log_member_joins.unwrap_or(true),
+        body.log_member_leaves.unwrap_or(true),
+        body.log_message_edits.unwrap_or(true),
+        body.log_message_deletes.unwrap_or(true),
+    )
+    .await?;
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::LogSettingsUpdate,
+        None,
+        None,
+        Some(audit_service::diff(&old, &settings)),
+    )
+    .await;
+
+    Ok(Json(settings))
+}
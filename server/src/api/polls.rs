@@ -11,10 +11,11 @@ use crate::api::auth::AuthUser;
 use crate::api::channels::resolve_channel_with_perm;
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
 use crate::state::AppState;
 use crate::types::entities::{
-    CastVoteRequest, CreatePollRequest, MyVote, Poll, PollOption, PollOptionResult, PollType,
-    PollVote, PollWithResults, RankedResult,
+    AuditAction, CastVoteRequest, CreatePollRequest, MyVote, Poll, PollOption, PollOptionResult,
+    PollType, PollVote, PollWithResults, RankedResult,
 };
 use crate::types::events::{MessageCreateEvent, PollCloseEvent, PollCreateEvent, PollVoteEvent};
 use crate::types::permissions::Permissions;
@@ -77,6 +78,13 @@ async fn create_poll(
         }
     }
 
+    crate::services::auth::ensure_can_post(&state.db, &state.config, user.user_id).await?;
+    if let Some(sid) = channel.server_id {
+        crate::services::automod::ensure_not_timed_out(&state, sid, user.user_id).await?;
+        crate::services::safety::ensure_can_post(&state, sid, user.user_id).await?;
+    }
+    crate::api::channels::enforce_slowmode(&state, &channel, user.user_id).await?;
+
     // Create the message
     let instance_id =
         queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
@@ -89,6 +97,7 @@ async fn create_poll(
         user.user_id,
         question,
         None,
+        false,
     )
     .await?;
     let _ = queries::update_channel_last_message(&state.db, channel_id, message_id).await;
@@ -409,7 +418,7 @@ async fn close_poll(
     user: AuthUser,
     Path((channel_id, poll_id)): Path<(Uuid, Uuid)>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let (_channel, _instance_id, server_id) = resolve_channel_with_perm(
+    let (_channel, server_id, _) = resolve_channel_with_perm(
         &state,
         channel_id,
         user.user_id,
@@ -450,6 +459,25 @@ async fn close_poll(
         return Err(ApiError::InvalidInput("Poll is already closed".into()));
     }
 
+    // Closing someone else's poll is a moderator action
+    if let Some(sid) = server_id.filter(|_| poll.creator_id != user.user_id) {
+        audit_service::record(
+            &state,
+            sid,
+            user.user_id,
+            AuditAction::PollClose,
+            Some(poll_id),
+            None,
+            Some(serde_json::json!({
+                "channel_id": channel_id,
+                "message_id": poll.message_id,
+                "creator_id": poll.creator_id,
+                "closed": { "old": false, "new": true },
+            })),
+        )
+        .await;
+    }
+
     let options = queries::get_poll_options(&state.db, poll_id).await?;
     let votes = queries::get_poll_votes(&state.db, poll_id).await?;
     let mut closed_poll = poll.clone();
@@ -0,0 +1,441 @@
+use axum::extract::{Path, Query, State};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use uuid::Uuid;
+
+use crate::api::auth::{check_rate_limit, AuthUser};
+use crate::api::channels::resolve_channel_with_perm;
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::permissions as perm_service;
+use crate::state::AppState;
+use crate::types::entities::{
+    AuditAction, CloseReportRequest, CreateReportRequest, Report, ReportAction, ReportQuery,
+    ReportStatus, Server,
+};
+use crate::types::permissions::Permissions;
+
+/// Channel-level routes (nested under /channels)
+pub fn channel_routes() -> Router<AppState> {
+    Router::new().route(
+        "/{channel_id}/messages/{message_id}/report",
+        post(report_message),
+    )
+}
+
+/// User-level routes (nested under /users)
+pub fn user_routes() -> Router<AppState> {
+    Router::new().route("/{user_id}/report", post(report_user))
+}
+
+/// Moderation queue (nested under /servers)
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/{server_id}/reports", get(list_reports))
+        .route("/{server_id}/reports/{report_id}", get(get_report))
+        .route("/{server_id}/reports/{report_id}/claim", post(claim_report))
+        .route("/{server_id}/reports/{report_id}/resolve", post(resolve_report))
+        .route("/{server_id}/reports/{report_id}/dismiss", post(dismiss_report))
+        .route("/{server_id}/reports/{report_id}/escalate", post(escalate_report))
+}
+
+const MAX_REASON_LEN: usize = 1000;
+
+fn clean_text(text: Option<&str>, what: &str) -> Result<Option<String>, ApiError> {
+    let text = text.map(str::trim).filter(|t| !t.is_empty());
+    if text.is_some_and(|t| t.len() > MAX_REASON_LEN) {
+        return Err(ApiError::InvalidInput(format!(
+            "{what} must be at most {MAX_REASON_LEN} characters"
+        )));
+    }
+    Ok(text.map(str::to_string))
+}
+
+// ── Filing reports ────────────────────────────────────
+
+async fn report_message(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, message_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CreateReportRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut redis = state.redis.clone();
+    check_rate_limit(&mut redis, &format!("report:{}", user.user_id), 10, 3600).await?;
+
+    let reason = clean_text(body.reason.as_deref(), "Reason")?;
+
+    let (channel, server_id, _) =
+        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
+            .await?;
+
+    let message = queries::get_message_by_id(&state.db, message_id)
+        .await?
+        .filter(|m| m.channel_id == channel.id)
+        .ok_or(ApiError::NotFound("Message"))?;
+
+    if message.author_id == Some(user.user_id) {
+        return Err(ApiError::InvalidInput("You can't report your own message".into()));
+    }
+    if queries::has_open_report(&state.db, user.user_id, Some(message_id), message.author_id).await? {
+        return Err(ApiError::InvalidInput("You've already reported this message".into()));
+    }
+
+    // DM reports have no server, so they go straight to the instance admins
+    let report = queries::create_report(
+        &state.db,
+        Uuid::now_v7(),
+        server_id,
+        user.user_id,
+        message.author_id,
+        Some(channel_id),
+        Some(message_id),
+        message.content.as_deref(),
+        body.category,
+        reason.as_deref(),
+    )
+    .await?;
+
+    tracing::info!(report_id = %report.id, server_id = ?server_id, "Message reported");
+
+    Ok(Json(report))
+}
+
+async fn report_user(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(target_id): Path<Uuid>,
+    Json(body): Json<CreateReportRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut redis = state.redis.clone();
+    check_rate_limit(&mut redis, &format!("report:{}", user.user_id), 10, 3600).await?;
+
+    let reason = clean_text(body.reason.as_deref(), "Reason")?;
+
+    if target_id == user.user_id {
+        return Err(ApiError::InvalidInput("You can't report yourself".into()));
+    }
+    queries::get_user_by_id(&state.db, target_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+
+    if let Some(server_id) = body.server_id {
+        queries::get_server_member(&state.db, server_id, user.user_id)
+            .await?
+            .ok_or(ApiError::NotFound("Server"))?;
+        // Only members can be reported to a server's moderators
+        queries::get_server_member(&state.db, server_id, target_id)
+            .await?
+            .ok_or(ApiError::NotFound("Member"))?;
+    }
+    if queries::has_open_report(&state.db, user.user_id, None, Some(target_id)).await? {
+        return Err(ApiError::InvalidInput("You've already reported this user".into()));
+    }
+
+    let report = queries::create_report(
+        &state.db,
+        Uuid::now_v7(),
+        body.server_id,
+        user.user_id,
+        Some(target_id),
+        None,
+        None,
+        None,
+        body.category,
+        reason.as_deref(),
+    )
+    .await?;
+
+    tracing::info!(report_id = %report.id, server_id = ?body.server_id, "User reported");
+
+    Ok(Json(report))
+}
+
+// ── Moderation queue ──────────────────────────────────
+
+/// The queue is open to members with MANAGE_MESSAGES or MODERATE_MEMBERS.
+async fn require_moderator(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(Server, Permissions), ApiError> {
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    let perms =
+        perm_service::compute_server_permissions(&state.db, server_id, user_id, server.owner_id)
+            .await?;
+    if !perms.intersects(Permissions::MANAGE_MESSAGES | Permissions::MODERATE_MEMBERS) {
+        return Err(ApiError::Forbidden);
+    }
+    Ok((server, perms))
+}
+
+async fn get_server_report(
+    state: &AppState,
+    server_id: Uuid,
+    report_id: Uuid,
+) -> Result<Report, ApiError> {
+    queries::get_report(&state.db, report_id)
+        .await?
+        .filter(|r| r.server_id == Some(server_id))
+        .ok_or(ApiError::NotFound("Report"))
+}
+
+async fn list_reports(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Query(params): Query<ReportQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_moderator(&state, server_id, user.user_id).await?;
+    let limit = params.limit.unwrap_or(50).clamp(1, 100);
+    let reports = queries::list_server_reports(&state.db, server_id, params.status, limit).await?;
+    Ok(Json(reports))
+}
+
+async fn get_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, report_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_moderator(&state, server_id, user.user_id).await?;
+    let report = get_server_report(&state, server_id, report_id).await?;
+    Ok(Json(report))
+}
+
+async fn claim_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, report_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_moderator(&state, server_id, user.user_id).await?;
+    get_server_report(&state, server_id, report_id).await?;
+
+    let report = queries::claim_report(&state.db, report_id, user.user_id)
+        .await?
+        .ok_or(ApiError::InvalidInput("Report is already closed".into()))?;
+    Ok(Json(report))
+}
+
+async fn resolve_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, report_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CloseReportRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (_, perms) = require_moderator(&state, server_id, user.user_id).await?;
+    let report = get_server_report(&state, server_id, report_id).await?;
+
+    // Each linked action needs the permission its own endpoint would
+    for action in &body.actions {
+        let required = match action {
+            ReportAction::DeleteMessage => Permissions::MANAGE_MESSAGES,
+            ReportAction::Kick => Permissions::KICK_MEMBERS,
+            ReportAction::Ban => Permissions::BAN_MEMBERS,
+        };
+        if !perms.contains(required) {
+            return Err(ApiError::Forbidden);
+        }
+    }
+
+    let report = close_report(&state, report, user.user_id, ReportStatus::Resolved, &body).await?;
+    Ok(Json(report))
+}
+
+async fn dismiss_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, report_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CloseReportRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_moderator(&state, server_id, user.user_id).await?;
+    let report = get_server_report(&state, server_id, report_id).await?;
+    let report = close_report(&state, report, user.user_id, ReportStatus::Dismissed, &body).await?;
+    Ok(Json(report))
+}
+
+/// Hand a report up to the instance admins (e.g. illegal content, or abuse
+/// spanning several servers). It stays in the server queue as well.
+async fn escalate_report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((server_id, report_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_moderator(&state, server_id, user.user_id).await?;
+    get_server_report(&state, server_id, report_id).await?;
+
+    let report = queries::escalate_report(&state.db, report_id)
+        .await?
+        .ok_or(ApiError::InvalidInput("Report is already closed".into()))?;
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ReportEscalate,
+        Some(report_id),
+        None,
+        None,
+    )
+    .await;
+
+    Ok(Json(report))
+}
+
+/// Resolve or dismiss `report`, then carry out any linked actions. The report
+/// is closed first so two moderators can't both act on it.
+/// Permission checks are the caller's job; shared with the admin queue.
+pub(crate) async fn close_report(
+    state: &AppState,
+    report: Report,
+    moderator_id: Uuid,
+    status: ReportStatus,
+    body: &CloseReportRequest,
+) -> Result<Report, ApiError> {
+    let note = clean_text(body.note.as_deref(), "Note")?;
+    if status == ReportStatus::Dismissed && !body.actions.is_empty() {
+        return Err(ApiError::InvalidInput("Dismissed reports can't take actions".into()));
+    }
+    if !matches!(report.status, ReportStatus::Open | ReportStatus::Claimed) {
+        return Err(ApiError::InvalidInput("Report is already closed".into()));
+    }
+
+    let audit_reason = match &note {
+        Some(note) => format!("Report {}: {}", report.id, note),
+        None => format!("Report {}", report.id),
+    };
+
+    let server = match report.server_id {
+        Some(server_id) => queries::get_server_by_id(&state.db, server_id).await?,
+        None => None,
+    };
+
+    for action in &body.actions {
+        match action {
+            ReportAction::DeleteMessage
+                if report.message_id.is_none() || report.channel_id.is_none() =>
+            {
+                return Err(ApiError::InvalidInput("This report has no message".into()));
+            }
+            ReportAction::Kick | ReportAction::Ban
+                if server.is_none() || report.target_user_id.is_none() =>
+            {
+                return Err(ApiError::InvalidInput(
+                    "Kicks and bans need a report from a server".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let action_taken = (!body.actions.is_empty()).then(|| {
+        body.actions
+            .iter()
+            .map(|a| a.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+
+    let closed = queries::close_report(
+        &state.db,
+        report.id,
+        status,
+        moderator_id,
+        note.as_deref(),
+        action_taken.as_deref(),
+    )
+    .await?
+    .ok_or(ApiError::InvalidInput("Report is already closed".into()))?;
+
+    for action in &body.actions {
+        match action {
+            ReportAction::DeleteMessage => {
+                let (Some(message_id), Some(channel_id)) = (report.message_id, report.channel_id)
+                else {
+                    continue;
+                };
+                // Already gone (deleted by its author or another moderator) is fine
+                let message = queries::get_message_by_id(&state.db, message_id).await?;
+                let channel = queries::get_channel_by_id(&state.db, channel_id).await?;
+                if let (Some(_), Some(channel)) = (message, channel) {
+                    crate::api::channels::remove_message(state, &channel, message_id).await?;
+                    if let Some(server_id) = channel.server_id {
+                        audit_service::record(
+                            state,
+                            server_id,
+                            moderator_id,
+                            AuditAction::MessageDelete,
+                            report.target_user_id,
+                            Some(&audit_reason),
+                            Some(serde_json::json!({
+                                "message_id": message_id,
+                                "channel_id": channel_id,
+                            })),
+                        )
+                        .await;
+                    }
+                }
+            }
+            ReportAction::Kick | ReportAction::Ban => {
+                let (Some(server), Some(target_id)) = (&server, report.target_user_id) else {
+                    continue;
+                };
+                if *action == ReportAction::Kick {
+                    crate::api::bans::apply_kick(state, server, target_id, moderator_id, Some(&audit_reason))
+                        .await?;
+                } else {
+                    crate::api::bans::apply_ban(
+                        state,
+                        server,
+                        target_id,
+                        moderator_id,
+                        Some(&audit_reason),
+                        None,
+                    )
+                    .await?;
+                }
+            }
+        }
+    }
+
+    if let (Some(server), Some(reporter_id)) = (&server, report.reporter_id) {
+        let outcome = if status == ReportStatus::Resolved {
+            "took action"
+        } else {
+            "closed it without action"
+        };
+        let notice = format!(
+            "the moderators of {} reviewed your report and {outcome}.",
+            server.name
+        );
+        // The resolution note is written for other moderators, so it isn't shared
+        crate::api::bans::send_moderation_notice(state, reporter_id, server, notice, None);
+    }
+
+    if let Some(server_id) = report.server_id {
+        audit_service::record(
+            state,
+            server_id,
+            moderator_id,
+            if status == ReportStatus::Resolved {
+                AuditAction::ReportResolve
+            } else {
+                AuditAction::ReportDismiss
+            },
+            Some(report.id),
+            note.as_deref(),
+            Some(serde_json::json!({
+                "category": report.category,
+                "target_user_id": report.target_user_id,
+                "actions": body.actions,
+            })),
+        )
+        .await;
+    }
+
+    Ok(closed)
+}
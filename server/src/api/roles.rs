@@ -8,9 +8,10 @@ use crate::api::auth::AuthUser;
 use crate::api::servers::resolve_server_member;
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
-use crate::types::entities::{CreateRoleRequest, RoleAssignment, UpdateRoleRequest};
+use crate::types::entities::{AuditAction, CreateRoleRequest, RoleAssignment, UpdateRoleRequest};
 use crate::types::events::{MemberRoleUpdateEvent, RoleCreateEvent, RoleDeleteEvent, RoleUpdateEvent};
 use crate::types::permissions::Permissions;
 
@@ -115,6 +116,18 @@ async fn create_role(
     )
     .await?;
 
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::RoleCreate,
+        Some(role_id),
+        None,
+        Some(serde_json::json!({ "name": role.name, "permissions": role.permissions })),
+    )
+    .await;
+
     let event = RoleCreateEvent {
         server_id,
         role: role.clone(),
@@ -176,6 +189,18 @@ async fn update_role(
     )
     .await?;
 
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::RoleUpdate,
+        Some(role_id),
+        None,
+        Some(audit_service::diff(&existing, &role)),
+    )
+    .await;
+
     let event = RoleUpdateEvent {
         server_id,
         role: role.clone(),
@@ -224,6 +249,18 @@ async fn delete_role(
 
     queries::delete_role(&state.db, role_id).await?;
 
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::RoleDelete,
+        Some(role_id),
+        None,
+        Some(serde_json::json!({ "name": role.name, "permissions": role.permissions })),
+    )
+    .await;
+
     let event = RoleDeleteEvent {
         server_id,
         role_id,
@@ -306,10 +343,24 @@ async fn assign_role_inner(
         ));
     }
 
+    let old_role_ids = queries::get_member_role_ids(&state.db, server_id, target_user_id).await?;
     queries::assign_member_role(&state.db, server_id, target_user_id, role_id).await?;
 
     // Broadcast updated role list
     let role_ids = queries::get_member_role_ids(&state.db, server_id, target_user_id).await?;
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user_id,
+        AuditAction::MemberRoleAdd,
+        Some(target_user_id),
+        None,
+        Some(audit_service::change("role_ids", &old_role_ids, &role_ids)),
+    )
+    .await;
+
     let event = MemberRoleUpdateEvent {
         server_id,
         user_id: target_user_id,
@@ -343,9 +394,22 @@ async fn remove_role(
         return Err(ApiError::Forbidden);
     }
 
+    let old_role_ids = queries::get_member_role_ids(&state.db, server_id, target_user_id).await?;
     queries::remove_member_role(&state.db, server_id, target_user_id, role_id).await?;
 
     let role_ids = queries::get_member_role_ids(&state.db, server_id, target_user_id).await?;
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::MemberRoleRemove,
+        Some(target_user_id),
+        None,
+        Some(audit_service::change("role_ids", &old_role_ids, &role_ids)),
+    )
+    .await;
     let event = MemberRoleUpdateEvent {
         server_id,
         user_id: target_user_id,
@@ -0,0 +1,178 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::routing::{get, put};
+use axum::{Json, Router};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::api::auth::AuthUser;
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::permissions as perm_service;
+use crate::services::safety as safety_service;
+use crate::state::AppState;
+use crate::types::entities::{AuditAction, SetLockdownRequest, UpdateServerSafetyRequest};
+use crate::types::permissions::Permissions;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{server_id}/safety",
+            get(get_safety_settings).patch(update_safety_settings),
+        )
+        .route("/{server_id}/lockdown", put(set_lockdown))
+}
+
+/// Longest lockdown, automatic or manual, that can be scheduled to lift itself
+const MAX_LOCKDOWN_SECS: i64 = 7 * 86400;
+
+/// Safety settings and lockdowns require MANAGE_SERVER.
+async fn require_manage_server(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    if !perm_service::has_server_permission(
+        &state.db,
+        server_id,
+        user_id,
+        server.owner_id,
+        Permissions::MANAGE_SERVER,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+    Ok(())
+}
+
+fn check_range(
+    value: Option<i32>,
+    range: std::ops::RangeInclusive<i32>,
+    what: &str,
+) -> Result<(), ApiError> {
+    if value.is_some_and(|v| !range.contains(&v)) {
+        return Err(ApiError::InvalidInput(format!(
+            "{what} must be between {} and {}",
+            range.start(),
+            range.end()
+        )));
+    }
+    Ok(())
+}
+
+async fn get_safety_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+    let settings = queries::ensure_server_safety_settings(&state.db, server_id).await?;
+    Ok(Json(settings))
+}
+
+async fn update_safety_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<UpdateServerSafetyRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+
+    check_range(body.raid_join_threshold, 2..=1000, "Raid join threshold")?;
+    check_range(body.raid_invite_threshold, 2..=1000, "Raid invite threshold")?;
+    check_range(body.raid_window_secs, 10..=3600, "Raid window")?;
+    check_range(
+        body.raid_lockdown_secs,
+        60..=MAX_LOCKDOWN_SECS as i32,
+        "Raid lockdown duration",
+    )?;
+
+    let old = queries::ensure_server_safety_settings(&state.db, server_id).await?;
+    let updated = queries::update_server_safety_settings(
+        &state.db,
+        server_id,
+        body.verification_level,
+        body.raid_protection,
+        body.raid_join_threshold,
+        body.raid_invite_threshold,
+        body.raid_window_secs,
+        body.raid_pause_invites,
+        body.raid_quarantine_joins,
+        body.raid_lockdown_secs,
+    )
+    .await?;
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ServerSafetyUpdate,
+        None,
+        None,
+        Some(audit_service::diff(&old, &updated)),
+    )
+    .await;
+
+    Ok(Json(updated))
+}
+
+async fn set_lockdown(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<SetLockdownRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_manage_server(&state, server_id, user.user_id).await?;
+
+    let settings = if body.enabled {
+        let now = Utc::now();
+        let until = match body.duration_secs {
+            None => None,
+            Some(secs @ 60..=MAX_LOCKDOWN_SECS) => Some(now + chrono::Duration::seconds(secs)),
+            Some(_) => {
+                return Err(ApiError::InvalidInput(
+                    "Lockdown duration must be between 1 minute and 7 days".into(),
+                ));
+            }
+        };
+        queries::set_server_lockdown(
+            &state.db,
+            server_id,
+            Some(now),
+            until,
+            body.pause_invites.unwrap_or(true),
+            body.quarantine_joins.unwrap_or(true),
+        )
+        .await?
+    } else {
+        queries::set_server_lockdown(&state.db, server_id, None, None, false, false).await?
+    };
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ServerLockdown,
+        None,
+        None,
+        Some(serde_json::json!({
+            "enabled": body.enabled,
+            "lockdown_until": settings.lockdown_until,
+            "pause_invites": settings.lockdown_pause_invites,
+            "quarantine_joins": settings.lockdown_quarantine,
+        })),
+    )
+    .await;
+
+    safety_service::broadcast_lockdown(&state, &settings);
+
+    Ok(Json(settings))
+}
@@ -63,14 +63,20 @@ async fn create_scheduled(
         ));
     }
 
+    crate::services::auth::ensure_can_post(&state.db, &state.config, user.user_id).await?;
+
     // Permission check
-    let _ = resolve_channel_with_perm(
+    let (channel, server_id, owner_id) = resolve_channel_with_perm(
         &state,
         channel_id,
         user.user_id,
         Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES,
     )
     .await?;
+    crate::api::channels::ensure_can_announce(&state, &channel, owner_id, user.user_id).await?;
+    if let Some(sid) = server_id {
+        crate::services::automod::ensure_not_timed_out(&state, sid, user.user_id).await?;
+    }
 
     // Rate limit per user
     let existing = queries::get_scheduled_messages_for_user(&state.db, user.user_id).await?;
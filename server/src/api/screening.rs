@@ -0,0 +1,227 @@
+use axum::extract::{Path, Query, State};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use uuid::Uuid;
+
+use crate::api::auth::AuthUser;
+use crate::api::servers::resolve_server_member;
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::auth as auth_service;
+use crate::services::permissions as perm_service;
+use crate::state::AppState;
+use crate::types::entities::{AcceptScreeningRequest, AuditAction, UpdateScreeningRequest};
+use crate::types::events::{MemberRoleUpdateEvent, ServerMemberUpdateEvent};
+use crate::types::permissions::Permissions;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{server_id}/screening",
+            get(get_screening).put(update_screening),
+        )
+        .route("/{server_id}/screening/accept", post(accept_screening))
+        .route("/{server_id}/screening/responses", get(list_responses))
+}
+
+const MAX_RULES: usize = 20;
+const MAX_RULE_LEN: usize = 500;
+const MAX_QUESTIONS: usize = 5;
+const MAX_QUESTION_LEN: usize = 300;
+
+fn clean_list(
+    items: &[String],
+    max: usize,
+    max_len: usize,
+    what: &str,
+) -> Result<Vec<String>, ApiError> {
+    if items.len() > max {
+        return Err(ApiError::InvalidInput(format!("At most {max} {what} allowed")));
+    }
+    items
+        .iter()
+        .map(|item| {
+            let item = item.trim();
+            if item.is_empty() || item.len() > max_len {
+                return Err(ApiError::InvalidInput(format!(
+                    "Each of the {what} must be 1-{max_len} characters"
+                )));
+            }
+            Ok(item.to_string())
+        })
+        .collect()
+}
+
+/// Pending members need to see the screen, so any member can read it.
+async fn get_screening(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_server_member(&state.db, server_id, user.user_id).await?;
+    let screening = queries::get_server_screening(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Screening"))?;
+    Ok(Json(screening))
+}
+
+async fn update_screening(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<UpdateScreeningRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let server = resolve_server_member(&state.db, server_id, user.user_id).await?;
+    if !perm_service::has_server_permission(
+        &state.db,
+        server_id,
+        user.user_id,
+        server.owner_id,
+        Permissions::MANAGE_SERVER,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+
+    let rules = clean_list(&body.rules, MAX_RULES, MAX_RULE_LEN, "rules")?;
+    let questions = clean_list(&body.questions, MAX_QUESTIONS, MAX_QUESTION_LEN, "questions")?;
+    if body.enabled && rules.is_empty() {
+        return Err(ApiError::InvalidInput(
+            "Add at least one rule before turning screening on".into(),
+        ));
+    }
+    let description = body.description.as_deref().map(str::trim).filter(|d| !d.is_empty());
+    if description.is_some_and(|d| d.len() > 1000) {
+        return Err(ApiError::InvalidInput(
+            "Description must be at most 1000 characters".into(),
+        ));
+    }
+
+    if let Some(channel_id) = body.rules_channel_id {
+        let channel = queries::get_channel_by_id(&state.db, channel_id).await?;
+        if channel.is_none_or(|c| c.server_id != Some(server_id)) {
+            return Err(ApiError::InvalidInput(format!("Unknown channel {channel_id}")));
+        }
+    }
+    if let Some(role_id) = body.accept_role_id {
+        let role = queries::get_role_by_id(&state.db, role_id).await?;
+        if role.is_none_or(|r| r.server_id != server_id || r.is_default) {
+            return Err(ApiError::InvalidInput(format!("Unknown role {role_id}")));
+        }
+    }
+
+    let old = queries::get_server_screening(&state.db, server_id).await?;
+    let screening = queries::upsert_server_screening(
+        &state.db,
+        server_id,
+        body.enabled,
+        description,
+        &rules,
+        &questions,
+        body.rules_channel_id,
+        body.accept_role_id,
+    )
+    .await?;
+
+    // Turning screening off lets everyone still waiting in
+    if !screening.enabled {
+        for member in queries::clear_pending_members(&state.db, server_id).await? {
+            let event = ServerMemberUpdateEvent { server_id, member };
+            state
+                .gateway
+                .broadcast_to_server(server_id, "SERVER_MEMBER_UPDATE", &event, None);
+        }
+    }
+
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ScreeningUpdate,
+        None,
+        None,
+        Some(audit_service::diff(&old, &screening)),
+    )
+    .await;
+
+    Ok(Json(screening))
+}
+
+async fn accept_screening(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<AcceptScreeningRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_server_member(&state.db, server_id, user.user_id).await?;
+
+    let screening = queries::get_server_screening(&state.db, server_id)
+        .await?
+        .filter(|s| s.enabled)
+        .ok_or(ApiError::NotFound("Screening"))?;
+    let answers = auth_service::collect_answers(&screening.questions, &body.answers)?;
+
+    let member = queries::accept_member_screening(&state.db, server_id, user.user_id)
+        .await?
+        .ok_or(ApiError::InvalidInput(
+            "You've already accepted this server's rules".into(),
+        ))?;
+    queries::create_screening_response(&state.db, server_id, user.user_id, &answers).await?;
+
+    let event = ServerMemberUpdateEvent {
+        server_id,
+        member: member.clone(),
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "SERVER_MEMBER_UPDATE", &event, None);
+
+    if let Some(role_id) = screening.accept_role_id {
+        queries::assign_member_role(&state.db, server_id, user.user_id, role_id).await?;
+
+        let role_ids = queries::get_member_role_ids(&state.db, server_id, user.user_id).await?;
+        let event = MemberRoleUpdateEvent {
+            server_id,
+            user_id: user.user_id,
+            role_ids,
+        };
+        state
+            .gateway
+            .broadcast_to_server(server_id, "MEMBER_ROLE_UPDATE", &event, None);
+    }
+
+    Ok(Json(member))
+}
+
+#[derive(serde::Deserialize)]
+struct ResponsesQuery {
+    limit: Option<i64>,
+}
+
+/// Screening answers are visible to MANAGE_SERVER or MODERATE_MEMBERS.
+async fn list_responses(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Query(params): Query<ResponsesQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let server = resolve_server_member(&state.db, server_id, user.user_id).await?;
+    let perms = perm_service::compute_server_permissions(
+        &state.db,
+        server_id,
+        user.user_id,
+        server.owner_id,
+    )
+    .await?;
+    if !perms.intersects(Permissions::MANAGE_SERVER | Permissions::MODERATE_MEMBERS) {
+        return Err(ApiError::Forbidden);
+    }
+
+    let limit = params.limit.unwrap_or(50).clamp(1, 100);
+    let responses = queries::get_screening_responses(&state.db, server_id, limit).await?;
+    Ok(Json(responses))
+}
@@ -7,11 +7,14 @@ use uuid::Uuid;
 use crate::api::auth::AuthUser;
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
 use crate::types::entities::{
-    ChannelType, CreateChannelRequest, CreateServerRequest, PublicUser, ServerMemberWithUser,
+    AuditAction, ChannelPositionUpdate, ChannelType, CreateChannelRequest, CreateServerRequest,
+    PublicUser, ServerMemberWithUser,
 };
+use crate::types::events::{ChannelOverrideUpdateEvent, ChannelUpdateBatchEvent};
 use crate::types::permissions::Permissions;
 
 /// Helper: verify server exists and user is a member.
@@ -54,13 +57,16 @@ pub fn routes() -> Router<AppState> {
         .route("/{server_id}/banner", post(request_banner_upload))
         .route(
             "/{server_id}/channels",
-            get(get_channels).post(create_channel),
+            get(get_channels)
+                .post(create_channel)
+                .patch(reorder_channels),
         )
         .route("/{server_id}/members", get(get_members))
         .route(
             "/{server_id}/members/@me",
             post(join_server).delete(leave_server),
         )
+        .route("/{server_id}/transfer", post(transfer_ownership))
 }
 
 async fn create_server(
@@ -262,6 +268,17 @@ async fn create_channel(
     )
     .await?;
 
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ChannelCreate,
+        Some(channel_id),
+        None,
+        Some(serde_json::json!({ "name": channel.name, "channel_type": channel.channel_type })),
+    )
+    .await;
+
     state
         .gateway
         .broadcast_to_server(server_id, "CHANNEL_CREATE", &channel, None);
@@ -269,6 +286,144 @@ async fn create_channel(
     Ok(Json(channel))
 }
 
+/// PATCH /servers/{server_id}/channels
+///
+/// Reorder channels and move them between categories in one go. Parents
+/// must be categories; `lock_permissions` syncs a channel's overrides with
+/// its category. Clients get a single CHANNEL_UPDATE_BATCH event, plus a
+/// CHANNEL_OVERRIDE_UPDATE for each synced channel.
+async fn reorder_channels(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<Vec<ChannelPositionUpdate>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    if !perm_service::has_server_permission(
+        &state.db,
+        server_id,
+        user.user_id,
+        server.owner_id,
+        Permissions::MANAGE_CHANNELS,
+    )
+    .await?
+    {
+        return Err(ApiError::Forbidden);
+    }
+
+    if body.is_empty() || body.len() > 100 {
+        return Err(ApiError::InvalidInput(
+            "Provide between 1 and 100 channel positions".into(),
+        ));
+    }
+
+    let existing = queries::get_server_channels(&state.db, server_id).await?;
+    let find = |id: Uuid| existing.iter().find(|c| c.id == id);
+
+    let mut ids = Vec::with_capacity(body.len());
+    let mut positions = Vec::with_capacity(body.len());
+    let mut parent_ids = Vec::with_capacity(body.len());
+    let mut sync_ids = Vec::new();
+    for update in &body {
+        if ids.contains(&update.id) {
+            return Err(ApiError::InvalidInput(
+                "Each channel can only appear once".into(),
+            ));
+        }
+        let channel = find(update.id).ok_or(ApiError::NotFound("Channel"))?;
+        let is_thread = channel
+            .parent_id
+            .and_then(find)
+            .is_some_and(|p| p.channel_type != ChannelType::Category);
+        if is_thread {
+            return Err(ApiError::InvalidInput("Threads cannot be reordered".into()));
+        }
+
+        let parent_id = update.parent_id.unwrap_or(channel.parent_id);
+        if let Some(pid) = parent_id {
+            if channel.channel_type == ChannelType::Category {
+                return Err(ApiError::InvalidInput(
+                    "Categories cannot be nested".into(),
+                ));
+            }
+            if !find(pid).is_some_and(|p| p.channel_type == ChannelType::Category) {
+                return Err(ApiError::InvalidInput(
+                    "Channels can only be moved into a category".into(),
+                ));
+            }
+        }
+        if update.lock_permissions {
+            if parent_id.is_none() {
+                return Err(ApiError::InvalidInput(
+                    "Only channels in a category can sync its permissions".into(),
+                ));
+            }
+            sync_ids.push(update.id);
+        }
+
+        ids.push(update.id);
+        positions.push(update.position.unwrap_or(channel.position));
+        parent_ids.push(parent_id);
+    }
+
+    let updated = queries::reorder_channels(
+        &state.db,
+        server_id,
+        &ids,
+        &positions,
+        &parent_ids,
+        &sync_ids,
+    )
+    .await?;
+
+    for channel in &updated {
+        let Some(before) = find(channel.id) else {
+            continue;
+        };
+        let mut changes = audit_service::diff(before, channel);
+        if sync_ids.contains(&channel.id) {
+            changes["permissions_synced"] = serde_json::json!(true);
+        }
+        if changes.as_object().is_some_and(|c| !c.is_empty()) {
+            audit_service::record(
+                &state,
+                server_id,
+                user.user_id,
+                AuditAction::ChannelUpdate,
+                Some(channel.id),
+                None,
+                Some(changes),
+            )
+            .await;
+        }
+    }
+
+    let event = ChannelUpdateBatchEvent {
+        server_id,
+        channels: updated.clone(),
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "CHANNEL_UPDATE_BATCH", &event, None);
+
+    // Synced channels now carry their category's overrides
+    for &channel_id in &sync_ids {
+        let event = ChannelOverrideUpdateEvent {
+            server_id,
+            channel_id,
+            overrides: queries::get_channel_overrides(&state.db, channel_id).await?,
+        };
+        state
+            .gateway
+            .broadcast_to_server(server_id, "CHANNEL_OVERRIDE_UPDATE", &event, None);
+    }
+
+    Ok(Json(updated))
+}
+
 async fn get_members(
     State(state): State<AppState>,
     user: AuthUser,
@@ -291,6 +446,8 @@ async fn get_members(
                 user_id: member.user_id,
                 nickname: member.nickname,
                 joined_at: member.joined_at,
+                communication_disabled_until: member.communication_disabled_until,
+                pending: member.pending,
                 user: PublicUser::from(user_data),
                 status,
                 role_ids,
@@ -317,7 +474,11 @@ async fn join_server(
         return Err(ApiError::InvalidInput("Already a member".into()));
     }
 
+    crate::services::safety::ensure_invites_open(&state.db, server_id).await?;
+
     let member = queries::add_server_member(&state.db, server_id, user.user_id).await?;
+    crate::services::safety::record_join(&state, server_id, user.user_id, None).await;
+    crate::services::mod_log::member_join(&state, server_id, user.user_id);
 
     // Subscribe gateway sessions and update presence cache
     state
@@ -373,6 +534,7 @@ async fn leave_server(
 
     queries::remove_server_member(&state.db, server_id, user.user_id).await?;
     state.gateway.remove_user_server(user.user_id, server_id);
+    crate::services::mod_log::member_leave(&state, server_id, user.user_id);
 
     let event = crate::types::events::ServerMemberRemoveEvent {
         server_id,
@@ -394,8 +556,13 @@ struct UpdateServerRequest {
     icon_url: Option<String>,
     banner_url: Option<String>,
     banner_position: Option<i16>,
+    /// Days to keep message edit history; 0 keeps it for the message's lifetime
+    edit_history_retention_days: Option<i32>,
 }
 
+/// Longest edit history retention a server can pick (10 years)
+const MAX_EDIT_HISTORY_RETENTION_DAYS: i32 = 3650;
+
 async fn update_server_handler(
     State(state): State<AppState>,
     user: AuthUser,
@@ -428,6 +595,15 @@ async fn update_server_handler(
         }
     }
 
+    if body
+        .edit_history_retention_days
+        .is_some_and(|days| !(0..=MAX_EDIT_HISTORY_RETENTION_DAYS).contains(&days))
+    {
+        return Err(ApiError::InvalidInput(format!(
+            "Edit history retention must be between 0 and {MAX_EDIT_HISTORY_RETENTION_DAYS} days"
+        )));
+    }
+
     let updated = queries::update_server(
         &state.db,
         server_id,
@@ -436,9 +612,21 @@ async fn update_server_handler(
         body.icon_url.as_deref(),
         body.banner_url.as_deref(),
         body.banner_position,
+        body.edit_history_retention_days,
     )
     .await?;
 
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::ServerUpdate,
+        Some(server_id),
+        None,
+        Some(audit_service::diff(&server, &updated)),
+    )
+    .await;
+
     state
         .gateway
         .broadcast_to_server(server_id, "SERVER_UPDATE", &updated, None);
@@ -446,6 +634,92 @@ async fn update_server_handler(
     Ok(Json(updated))
 }
 
+#[derive(serde::Deserialize)]
+struct TransferOwnershipRequest {
+    new_owner_id: Uuid,
+    password: String,
+    reason: Option<String>,
+}
+
+async fn transfer_ownership(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(server_id): Path<Uuid>,
+    Json(body): Json<TransferOwnershipRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let server = resolve_server_owner(&state.db, server_id, user.user_id).await?;
+
+    // Re-confirm the owner's password before handing the server away
+    let user_data = queries::get_user_by_id(&state.db, user.user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    crate::services::auth::verify_password(&user_data, &body.password)?;
+
+    let updated = apply_ownership_transfer(
+        &state,
+        server,
+        body.new_owner_id,
+        user.user_id,
+        body.reason.as_deref(),
+    )
+    .await?;
+
+    Ok(Json(updated))
+}
+
+/// Helper: move ownership of `server` to `new_owner_id`, who must already be a
+/// member. Writes the audit entry and broadcasts `SERVER_UPDATE`. Shared by the
+/// owner-initiated transfer and the instance-admin override.
+pub(crate) async fn apply_ownership_transfer(
+    state: &AppState,
+    server: crate::types::entities::Server,
+    new_owner_id: Uuid,
+    actor_id: Uuid,
+    reason: Option<&str>,
+) -> Result<crate::types::entities::Server, ApiError> {
+    if new_owner_id == server.owner_id {
+        return Err(ApiError::InvalidInput(
+            "User already owns this server".into(),
+        ));
+    }
+
+    queries::get_server_member(&state.db, server.id, new_owner_id)
+        .await?
+        .ok_or(ApiError::InvalidInput(
+            "New owner must be a member of the server".into(),
+        ))?;
+
+    let new_owner = queries::get_user_by_id(&state.db, new_owner_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    if new_owner.bot {
+        return Err(ApiError::InvalidInput(
+            "Ownership cannot be transferred to a bot".into(),
+        ));
+    }
+
+    let updated = queries::update_server_owner(&state.db, server.id, new_owner_id).await?;
+
+    audit_service::record(
+        state,
+        server.id,
+        actor_id,
+        AuditAction::ServerOwnerTransfer,
+        Some(new_owner_id),
+        reason,
+        Some(serde_json::json!({
+            "owner_id": { "old": server.owner_id, "new": new_owner_id }
+        })),
+    )
+    .await;
+
+    state
+        .gateway
+        .broadcast_to_server(server.id, "SERVER_UPDATE", &updated, None);
+
+    Ok(updated)
+}
+
 async fn request_icon_upload(
     State(state): State<AppState>,
     user: AuthUser,
@@ -7,9 +7,10 @@ use uuid::Uuid;
 use crate::api::auth::AuthUser;
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
-use crate::types::entities::SetJoinSoundRequest;
+use crate::types::entities::{AuditAction, SetJoinSoundRequest};
 use crate::types::events::{SoundboardPlayEvent, SoundboardSoundCreateEvent, SoundboardSoundDeleteEvent};
 use crate::types::permissions::Permissions;
 
@@ -139,6 +140,18 @@ async fn upload_sound(
     .await
     .map_err(|e| ApiError::Internal(e.into()))?;
 
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::SoundboardSoundCreate,
+        Some(sound_id),
+        None,
+        Some(serde_json::json!({ "name": sound.name, "emoji_name": sound.emoji_name })),
+    )
+    .await;
+
     // Broadcast to server
     let event = SoundboardSoundCreateEvent {
         server_id,
@@ -187,6 +200,18 @@ async fn delete_sound(
 
     queries::delete_soundboard_sound(&state.db, sound_id).await?;
 
+    // Audit log
+    audit_service::record(
+        &state,
+        server_id,
+        user.user_id,
+        AuditAction::SoundboardSoundDelete,
+        Some(sound_id),
+        None,
+        Some(serde_json::json!({ "name": sound.name, "uploader_id": sound.uploader_id })),
+    )
+    .await;
+
     // Broadcast deletion
     let event = SoundboardSoundDeleteEvent {
         server_id,
@@ -1,18 +1,22 @@
 use axum::extract::{Path, State};
 use axum::response::IntoResponse;
-use axum::routing::{get, post};
+use axum::routing::{get, post, put};
 use axum::{Json, Router};
 use uuid::Uuid;
 
 use livekit_api::access_token;
+use livekit_api::services::room::{RoomClient, UpdateParticipantOptions};
+use livekit_protocol::ParticipantPermission;
 
 use crate::api::auth::{AuthUser, BeaconAuthUser};
 use crate::db::queries;
 use crate::error::ApiError;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
-use crate::types::entities::{ChannelType, VoiceJoinRequest, VoiceStateUpdate};
-use crate::types::events::{SoundboardPlayEvent, VoiceTokenResponse};
+use crate::types::entities::{
+    ChannelType, SetStageInstanceRequest, VoiceJoinRequest, VoiceStateUpdate,
+};
+use crate::types::events::{SoundboardPlayEvent, StageInstanceDeleteEvent, VoiceTokenResponse};
 use crate::types::permissions::Permissions;
 
 pub fn routes() -> Router<AppState> {
@@ -21,8 +25,23 @@ pub fn routes() -> Router<AppState> {
         .route("/{channel_id}/voice/leave", post(voice_leave))
         .route("/{channel_id}/voice/state", axum::routing::patch(voice_update_state))
         .route("/{channel_id}/voice/states", get(voice_get_states))
+        .route(
+            "/{channel_id}/stage",
+            get(get_stage).put(set_stage).delete(end_stage),
+        )
+        .route(
+            "/{channel_id}/stage/request-to-speak",
+            put(request_to_speak).delete(withdraw_request),
+        )
+        .route(
+            "/{channel_id}/stage/speakers/{user_id}",
+            put(add_speaker).delete(remove_speaker),
+        )
 }
 
+/// Stage moderators can start stages and approve or remove speakers
+const STAGE_MODERATOR: Permissions = Permissions::MUTE_MEMBERS;
+
 /// POST /channels/:channel_id/voice/join
 /// Join a voice channel and get a LiveKit token.
 /// Works for both server voice channels and DM/group-DM channels.
@@ -41,9 +60,9 @@ async fn voice_join(
     let self_deaf = body.self_deaf.unwrap_or(false);
 
     // Branch: server voice channel vs DM voice call
-    let (server_id, can_speak, dm_member_ids) = if let Some(sid) = channel.server_id {
+    let (server_id, can_speak, suppress, dm_member_ids) = if let Some(sid) = channel.server_id {
         // ── Server voice channel ──
-        if channel.channel_type != ChannelType::Voice {
+        if !matches!(channel.channel_type, ChannelType::Voice | ChannelType::Stage) {
             return Err(ApiError::InvalidInput("Not a voice channel".into()));
         }
 
@@ -82,7 +101,20 @@ async fn voice_join(
         .await
         .unwrap_or(false);
 
-        (Some(sid), speak, None)
+        // Stage audiences listen until a moderator brings them up to speak
+        let suppress = channel.channel_type == ChannelType::Stage
+            && !perm_service::has_channel_permission(
+                &state.db,
+                sid,
+                channel_id,
+                user.user_id,
+                server.owner_id,
+                STAGE_MODERATOR,
+            )
+            .await
+            .unwrap_or(false);
+
+        (Some(sid), speak && !suppress, suppress, None)
     } else {
         // ── DM / Group DM voice call ──
         if !matches!(channel.channel_type, ChannelType::Dm | ChannelType::GroupDm) {
@@ -98,7 +130,7 @@ async fn voice_join(
 
         let member_ids: Vec<Uuid> = members.iter().map(|m| m.id).collect();
         // All DM participants can speak
-        (None, true, Some(member_ids))
+        (None, true, false, Some(member_ids))
     };
 
     // Get LiveKit config
@@ -141,6 +173,7 @@ async fn voice_join(
         self_mute,
         self_deaf,
         dm_member_ids,
+        suppress,
     );
 
     // Play entrance sound (server voice channels only)
@@ -250,6 +283,8 @@ async fn voice_get_states(
             self_mute: vs.self_mute,
             self_deaf: vs.self_deaf,
             audio_sharing: vs.audio_sharing,
+            suppress: vs.suppress,
+            request_to_speak_at: vs.request_to_speak_at,
         })
         .collect();
 
@@ -263,4 +298,276 @@ struct VoiceStateResponse {
     self_mute: bool,
     self_deaf: bool,
     audio_sharing: bool,
+    suppress: bool,
+    request_to_speak_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+// ── Stage Channels ──────────────────────────────────────
+
+/// Resolve a stage channel and check the caller can see it. Returns the
+/// server id and whether the caller is a stage moderator.
+async fn resolve_stage(
+    state: &AppState,
+    channel_id: Uuid,
+    user_id: Uuid,
+) -> Result<(Uuid, bool), ApiError> {
+    let (channel, server_id, owner_id) = crate::api::channels::resolve_channel_with_perm(
+        state,
+        channel_id,
+        user_id,
+        Permissions::VIEW_CHANNEL,
+    )
+    .await?;
+    let (Some(sid), Some(oid), ChannelType::Stage) = (server_id, owner_id, channel.channel_type)
+    else {
+        return Err(ApiError::InvalidInput("Not a stage channel".into()));
+    };
+    let moderator =
+        perm_service::has_channel_permission(&state.db, sid, channel_id, user_id, oid, STAGE_MODERATOR)
+            .await?;
+    Ok((sid, moderator))
+}
+
+/// Swap a participant's LiveKit publish permission in the stage's room.
+async fn set_can_publish(
+    state: &AppState,
+    channel_id: Uuid,
+    user_id: Uuid,
+    can_publish: bool,
+) -> Result<(), ApiError> {
+    let lk = state
+        .config
+        .livekit
+        .as_ref()
+        .ok_or(ApiError::Internal(anyhow::anyhow!("LiveKit not configured")))?;
+    let http_url = lk
+        .url
+        .replace("ws://", "http://")
+        .replace("wss://", "https://");
+    let room_client = RoomClient::with_api_key(&http_url, &lk.api_key, &lk.api_secret);
+
+    room_client
+        .update_participant(
+            &channel_id.to_string(),
+            &user_id.to_string(),
+            UpdateParticipantOptions {
+                permission: Some(ParticipantPermission {
+                    can_subscribe: true,
+                    can_publish,
+                    can_publish_data: true,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!("LiveKit API error: {e}")))?;
+    Ok(())
+}
+
+/// GET /channels/:channel_id/stage
+async fn get_stage(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_stage(&state, channel_id, user.user_id).await?;
+    let instance = queries::get_stage_instance(&state.db, channel_id)
+        .await?
+        .ok_or(ApiError::NotFound("Stage instance"))?;
+    Ok(Json(instance))
+}
+
+/// PUT /channels/:channel_id/stage
+/// Start the stage with a topic, or change the topic of a live one.
+async fn set_stage(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+    Json(body): Json<SetStageInstanceRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let topic = body.topic.trim();
+    if topic.is_empty() || topic.len() > 120 {
+        return Err(ApiError::InvalidInput(
+            "Stage topic must be 1-120 characters".into(),
+        ));
+    }
+
+    let (server_id, moderator) = resolve_stage(&state, channel_id, user.user_id).await?;
+    if !moderator {
+        return Err(ApiError::Forbidden);
+    }
+
+    let instance =
+        queries::upsert_stage_instance(&state.db, channel_id, server_id, topic, user.user_id)
+            .await?;
+    state
+        .gateway
+        .broadcast_to_server(server_id, "STAGE_INSTANCE_UPDATE", &instance, None);
+
+    Ok(Json(instance))
+}
+
+/// DELETE /channels/:channel_id/stage
+/// End the stage. Everyone on it returns to the audience.
+async fn end_stage(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (server_id, moderator) = resolve_stage(&state, channel_id, user.user_id).await?;
+    if !moderator {
+        return Err(ApiError::Forbidden);
+    }
+
+    if !queries::delete_stage_instance(&state.db, channel_id).await? {
+        return Err(ApiError::NotFound("Stage instance"));
+    }
+    // Everyone returns to the audience: revoke publishing in LiveKit, then
+    // suppress them (voice_update_stage broadcasts the new voice state)
+    for vs in state.gateway.voice_channel_users(channel_id) {
+        if let Err(e) = set_can_publish(&state, channel_id, vs.user_id, false).await {
+            tracing::warn!(
+                user_id = %vs.user_id,
+                error = ?e,
+                "Failed to revoke publishing when ending stage"
+            );
+        }
+        state
+            .gateway
+            .voice_update_stage(vs.user_id, channel_id, true, None);
+    }
+
+    let event = StageInstanceDeleteEvent {
+        server_id,
+        channel_id,
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "STAGE_INSTANCE_DELETE", &event, None);
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// PUT /channels/:channel_id/stage/request-to-speak
+async fn request_to_speak(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_stage(&state, channel_id, user.user_id).await?;
+
+    let current = state
+        .gateway
+        .voice_state(user.user_id)
+        .filter(|vs| vs.channel_id == channel_id)
+        .ok_or(ApiError::InvalidInput("Not in this stage channel".into()))?;
+    if !current.suppress {
+        return Err(ApiError::InvalidInput("Already a speaker".into()));
+    }
+    if current.request_to_speak_at.is_none() {
+        state.gateway.voice_update_stage(
+            user.user_id,
+            channel_id,
+            true,
+            Some(chrono::Utc::now()),
+        );
+    }
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// DELETE /channels/:channel_id/stage/request-to-speak
+async fn withdraw_request(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_stage(&state, channel_id, user.user_id).await?;
+
+    let current = state
+        .gateway
+        .voice_state(user.user_id)
+        .filter(|vs| vs.channel_id == channel_id)
+        .ok_or(ApiError::InvalidInput("Not in this stage channel".into()))?;
+    if current.request_to_speak_at.is_some() {
+        state
+            .gateway
+            .voice_update_stage(user.user_id, channel_id, current.suppress, None);
+    }
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// PUT /channels/:channel_id/stage/speakers/:user_id
+/// Bring a listener up to speak. Moderators only; the listener still needs SPEAK.
+async fn add_speaker(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, target_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (server_id, moderator) = resolve_stage(&state, channel_id, user.user_id).await?;
+    if !moderator {
+        return Err(ApiError::Forbidden);
+    }
+
+    state
+        .gateway
+        .voice_state(target_id)
+        .filter(|vs| vs.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Stage participant"))?;
+
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+    if !perm_service::has_channel_permission(
+        &state.db,
+        server_id,
+        channel_id,
+        target_id,
+        server.owner_id,
+        Permissions::SPEAK,
+    )
+    .await?
+    {
+        return Err(ApiError::InvalidInput(
+            "That member does not have permission to speak here".into(),
+        ));
+    }
+
+    set_can_publish(&state, channel_id, target_id, true).await?;
+    state
+        .gateway
+        .voice_update_stage(target_id, channel_id, false, None);
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// DELETE /channels/:channel_id/stage/speakers/:user_id
+/// Move a speaker back to the audience. Moderators can move anyone;
+/// speakers can step down themselves. Also declines a pending request.
+async fn remove_speaker(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((channel_id, target_id)): Path<(Uuid, Uuid)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (_, moderator) = resolve_stage(&state, channel_id, user.user_id).await?;
+    if !moderator && target_id != user.user_id {
+        return Err(ApiError::Forbidden);
+    }
+
+    let current = state
+        .gateway
+        .voice_state(target_id)
+        .filter(|vs| vs.channel_id == channel_id)
+        .ok_or(ApiError::NotFound("Stage participant"))?;
+
+    if !current.suppress {
+        set_can_publish(&state, channel_id, target_id, false).await?;
+    }
+    state
+        .gateway
+        .voice_update_stage(target_id, channel_id, true, None);
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
 }
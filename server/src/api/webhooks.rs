@@ -6,12 +6,18 @@ use rand::Rng;
 use uuid::Uuid;
 
 use crate::api::auth::AuthUser;
+use crate::api::channels::{
+    ParsedMentions, parse_mentions, resolve_channel_with_perm, validate_allowed_mentions,
+};
 use crate::db::queries;
 use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::automod as automod_service;
 use crate::services::permissions as perm_service;
 use crate::state::AppState;
 use crate::types::entities::{
-    AuditAction, CreateWebhookRequest, ExecuteWebhookRequest, PublicUser, UpdateWebhookRequest,
+    AllowedMentions, Attachment, AuditAction, Channel, ChannelType, CreateWebhookRequest,
+    ExecuteWebhookRequest, FollowChannelRequest, PublicUser, Server, UpdateWebhookRequest, Webhook,
 };
 use crate::types::events::MessageCreateWithExtrasEvent;
 use crate::types::permissions::Permissions;
@@ -26,6 +32,10 @@ pub fn routes() -> Router<AppState> {
             "/{channel_id}/webhooks/{webhook_id}",
             axum::routing::patch(update_webhook).delete(delete_webhook),
         )
+        .route(
+            "/{channel_id}/followers",
+            get(get_followers).post(follow_channel),
+        )
 }
 
 /// Server-level webhook routes (nested under /servers)
@@ -102,8 +112,8 @@ async fn create_webhook(
     .await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::WebhookCreate,
@@ -206,6 +216,11 @@ async fn update_webhook(
         return Err(ApiError::Forbidden);
     }
 
+    let existing = queries::get_webhook_by_id(&state.db, webhook_id)
+        .await?
+        .filter(|w| w.server_id == server_id)
+        .ok_or(ApiError::NotFound("Webhook"))?;
+
     let webhook = queries::update_webhook(
         &state.db,
         webhook_id,
@@ -215,14 +230,14 @@ async fn update_webhook(
     .await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::WebhookUpdate,
         Some(webhook_id),
         None,
-        None,
+        Some(audit_service::diff(&existing, &webhook)),
     )
     .await;
 
@@ -262,8 +277,8 @@ async fn delete_webhook(
     queries::delete_webhook(&state.db, webhook_id).await?;
 
     // Audit log
-    let _ = queries::create_audit_log(
-        &state.db,
+    audit_service::record(
+        &state,
         server_id,
         user.user_id,
         AuditAction::WebhookDelete,
@@ -316,6 +331,52 @@ async fn execute_webhook(
             "Message content must be 1-4000 characters".into(),
         ));
     }
+    if let Some(ref allowed) = body.allowed_mentions {
+        validate_allowed_mentions(allowed)?;
+    }
+
+    if webhook.source_channel_id.is_some() {
+        return Err(ApiError::InvalidInput(
+            "Follower webhooks only relay published announcements".into(),
+        ));
+    }
+
+    post_as_webhook(
+        &state,
+        webhook,
+        &body.content,
+        body.username,
+        body.avatar_url,
+        body.allowed_mentions.as_ref(),
+        body.silent,
+        &[],
+    )
+    .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Post a message into a webhook's channel under the webhook's identity,
+/// with optional per-message name and avatar overrides. Mentions are only
+/// parsed when `allowed` is given, and ping with the creator's permissions.
+/// `attachments` are re-attached to the new message, sharing their files.
+#[allow(clippy::too_many_arguments)]
+async fn post_as_webhook(
+    state: &AppState,
+    webhook: Webhook,
+    content: &str,
+    username: Option<String>,
+    avatar_url: Option<String>,
+    allowed: Option<&AllowedMentions>,
+    silent: bool,
+    attachments: &[Attachment],
+) -> Result<(), ApiError> {
+    let channel = queries::get_channel_by_id(&state.db, webhook.channel_id)
+        .await?
+        .ok_or(ApiError::NotFound("Channel"))?;
+    let automod =
+        automod_service::check_message(state, &channel, webhook.creator_id, true, content)
+            .await?;
 
     let instance_id =
         queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
@@ -328,17 +389,86 @@ async fn execute_webhook(
         instance_id,
         webhook.channel_id,
         webhook.creator_id,
-        &body.content,
+        content,
         None,
+        silent,
     )
     .await?;
 
+    let mut copies = Vec::with_capacity(attachments.len());
+    for a in attachments {
+        copies.push(
+            queries::create_attachment(
+                &state.db,
+                Uuid::now_v7(),
+                message_id,
+                &a.filename,
+                &a.content_type,
+                a.size_bytes,
+                &a.url,
+                a.width,
+                a.height,
+            )
+            .await?,
+        );
+    }
+
+    // Webhook messages only ping anyone when the caller opts in
+    let mentions = match allowed {
+        Some(allowed) => {
+            let server = queries::get_server_by_id(&state.db, webhook.server_id)
+                .await?
+                .ok_or(ApiError::NotFound("Server"))?;
+            let can_mention_everyone = perm_service::has_channel_permission(
+                &state.db,
+                webhook.server_id,
+                webhook.channel_id,
+                webhook.creator_id,
+                server.owner_id,
+                Permissions::MENTION_EVERYONE,
+            )
+            .await
+            .unwrap_or(false);
+            parse_mentions(
+                &state.db,
+                &state.gateway,
+                content,
+                webhook.creator_id,
+                Some(webhook.server_id),
+                can_mention_everyone,
+                Some(allowed),
+            )
+            .await
+        }
+        None => ParsedMentions::default(),
+    };
+    let message = if mentions.is_empty() {
+        message
+    } else {
+        queries::set_message_mentions(
+            &state.db,
+            message_id,
+            &mentions.users,
+            &mentions.roles,
+            mentions.everyone,
+        )
+        .await?
+    };
+    if !silent && !mentions.notified.is_empty() {
+        let _ = queries::increment_mention_counts(
+            &state.db,
+            webhook.channel_id,
+            &mentions.notified,
+        )
+        .await;
+    }
+
     // Build the author info — use webhook name/avatar overrides
     let webhook_user = PublicUser {
         id: webhook.creator_id,
-        username: body.username.unwrap_or(webhook.name),
+        username: username.unwrap_or(webhook.name),
         display_name: None,
-        avatar_url: body.avatar_url.or(webhook.avatar_url),
+        avatar_url: avatar_url.or(webhook.avatar_url),
         bio: None,
         status: "online".to_string(),
         custom_status: None,
@@ -350,7 +480,7 @@ async fn execute_webhook(
     let event = MessageCreateWithExtrasEvent {
         message,
         author: webhook_user,
-        attachments: vec![],
+        attachments: copies,
     };
 
     state.gateway.broadcast_to_server(
@@ -360,5 +490,160 @@ async fn execute_webhook(
         None,
     );
 
-    Ok(axum::http::StatusCode::NO_CONTENT)
+    if automod.delete_after_send {
+        automod_service::remove_message(state, webhook.server_id, webhook.channel_id, message_id)
+            .await?;
+    }
+
+    Ok(())
+}
+
+// ── Channel Following ────────────────────────────────
+
+/// The identity follower copies are posted under, e.g. "Drocsid #news"
+fn follower_name(server: &Server, channel: &Channel) -> String {
+    let name = format!("{} #{}", server.name, channel.name.as_deref().unwrap_or_default());
+    name.chars().take(80).collect()
+}
+
+/// POST /channels/{channel_id}/followers
+///
+/// Follow an announcement channel into a channel on the caller's server by
+/// creating a follower webhook there.
+async fn follow_channel(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+    Json(body): Json<FollowChannelRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (source, _, _) =
+        resolve_channel_with_perm(&state, channel_id, user.user_id, Permissions::VIEW_CHANNEL)
+            .await?;
+    let source_server_id = match (source.channel_type, source.server_id) {
+        (ChannelType::Announcement, Some(sid)) => sid,
+        _ => {
+            return Err(ApiError::InvalidInput(
+                "Only announcement channels can be followed".into(),
+            ))
+        }
+    };
+
+    let (target, target_server_id, _) = resolve_channel_with_perm(
+        &state,
+        body.webhook_channel_id,
+        user.user_id,
+        Permissions::VIEW_CHANNEL | Permissions::MANAGE_WEBHOOKS,
+    )
+    .await?;
+    let target_server_id = target_server_id.ok_or(ApiError::InvalidInput(
+        "Webhooks are only for server channels".into(),
+    ))?;
+    if target.id == source.id
+        || !matches!(target.channel_type, ChannelType::Text | ChannelType::Announcement)
+    {
+        return Err(ApiError::InvalidInput(
+            "Announcements can only be followed into another text channel".into(),
+        ));
+    }
+
+    let followers = queries::get_follower_webhooks(&state.db, channel_id).await?;
+    if followers.iter().any(|w| w.channel_id == target.id) {
+        return Err(ApiError::InvalidInput(
+            "That channel already follows this announcement channel".into(),
+        ));
+    }
+
+    let source_server = queries::get_server_by_id(&state.db, source_server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+
+    let webhook_id = Uuid::now_v7();
+    let webhook = queries::create_follower_webhook(
+        &state.db,
+        webhook_id,
+        target_server_id,
+        target.id,
+        user.user_id,
+        &follower_name(&source_server, &source),
+        source_server.icon_url.as_deref(),
+        &generate_webhook_token(),
+        channel_id,
+    )
+    .await?;
+
+    audit_service::record(
+        &state,
+        target_server_id,
+        user.user_id,
+        AuditAction::WebhookCreate,
+        Some(webhook_id),
+        None,
+        Some(serde_json::json!({
+            "name": webhook.name,
+            "source_channel_id": channel_id,
+        })),
+    )
+    .await;
+
+    Ok(Json(webhook))
+}
+
+/// GET /channels/{channel_id}/followers
+async fn get_followers(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(channel_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    resolve_channel_with_perm(
+        &state,
+        channel_id,
+        user.user_id,
+        Permissions::VIEW_CHANNEL | Permissions::MANAGE_WEBHOOKS,
+    )
+    .await?;
+
+    let followers = queries::get_channel_followers(&state.db, channel_id).await?;
+    Ok(Json(followers))
+}
+
+/// Copy a published announcement into every following channel, attributed
+/// to the source server. A follower that fails is logged and skipped so it
+/// can't hold up the rest. Returns how many copies were delivered.
+pub(crate) async fn publish_to_followers(
+    state: &AppState,
+    source: &Channel,
+    content: &str,
+    attachments: &[Attachment],
+) -> Result<usize, ApiError> {
+    let server_id = source.server_id.ok_or(ApiError::NotFound("Server"))?;
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+    let username = follower_name(&server, source);
+
+    let mut delivered = 0;
+    for webhook in queries::get_follower_webhooks(&state.db, source.id).await? {
+        let webhook_id = webhook.id;
+        match post_as_webhook(
+            state,
+            webhook,
+            content,
+            Some(username.clone()),
+            server.icon_url.clone(),
+            // Follower copies never ping the following server
+            Some(&AllowedMentions::default()),
+            false,
+            attachments,
+        )
+        .await
+        {
+            Ok(()) => delivered += 1,
+            Err(e) => tracing::warn!(
+                webhook_id = %webhook_id,
+                error = ?e,
+                "Failed to deliver announcement to follower"
+            ),
+        }
+    }
+    Ok(delivered)
 }
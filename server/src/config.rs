@@ -11,6 +11,7 @@ pub struct AppConfig {
     pub livekit: Option<LiveKitConfig>,
     pub gif: Option<GifConfig>,
     pub email: Option<EmailConfig>,
+    pub digest: Option<DigestConfig>,
     pub github: Option<GitHubConfig>,
     pub web_push: Option<WebPushConfig>,
     pub admin_dashboard: Option<AdminDashboardConfig>,
@@ -44,6 +45,9 @@ pub struct AuthConfig {
 pub struct InstanceConfig {
     pub domain: String,
     pub name: String,
+    /// When set, users must confirm their email address before they can post.
+    #[serde(default)]
+    pub require_email_verification: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -73,9 +77,60 @@ pub struct GifConfig {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct EmailConfig {
-    pub resend_api_key: String,
+    #[serde(default)]
+    pub transport: EmailTransportKind,
+    /// Required when `transport = "resend"`.
+    pub resend_api_key: Option<String>,
     pub from_address: String,
     pub reset_token_ttl_secs: Option<i64>,
+    pub verification_token_ttl_secs: Option<i64>,
+    pub smtp: Option<SmtpConfig>,
+    /// Maildir root used by the `maildir` transport.
+    pub maildir_path: Option<String>,
+    /// Directory of template overrides (`<name>.subject.txt`, `<name>.txt`,
+    /// `<name>.html`). Missing files fall back to the built-in templates.
+    pub templates_dir: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailTransportKind {
+    #[default]
+    Resend,
+    Smtp,
+    Maildir,
+    Log,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: Option<u16>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    #[serde(default)]
+    pub tls: SmtpTls,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpTls {
+    /// Plain connection upgraded with STARTTLS (port 587)
+    #[default]
+    Starttls,
+    /// Implicit TLS (port 465)
+    Tls,
+    /// Unencrypted — only for local relays such as MailHog
+    None,
+}
+
+/// Unread-mention email digests. Requires `[email]` to be configured.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DigestConfig {
+    /// Users offline for longer than this are eligible (default 24h).
+    pub offline_threshold_secs: Option<i64>,
+    /// Frequency for users who haven't chosen one: "daily", "weekly" or "never".
+    pub default_frequency: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -3,11 +3,16 @@ use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::types::entities::{
-    Attachment, AuditAction, AuditLogEntry, Ban, Channel, ChannelLink, ChannelOverride, ChannelType,
-    DmMember, Invite, Message, MessageBookmark, Poll, PollOption, PollType, PollVote, Reaction,
-    ReadState, Relationship, RelationshipType, RegistrationCode, Role, ScheduledMessage,
-    SearchResult, Server, ServerMember, Session, SoundboardSound, ThreadMetadata, User,
-    UserCustomTheme, Webhook,
+    Attachment, AuditAction, AuditLogEntry, AutoModAction, AutoModRule, AutoModTriggerMetadata,
+    AutoModTriggerType, Ban, Channel, ChannelFollower, ChannelLink, ChannelOverride, ChannelType,
+    DmMember, ForumPost, ForumSortOrder, ForumTag, InstanceSettings, Invite, Message,
+    MessageBookmark, MessageForward, MessageRevision, Poll, PollOption, PollType, PollVote,
+    QuestionnaireAnswer, Reaction, ReadState, RegistrationCode, RegistrationMode,
+    RegistrationRequest, RegistrationRequestStatus, Relationship, RelationshipType, Report,
+    ReportCategory, ReportStatus, Role, ScheduledMessage, ScreeningResponse, SearchResult, Server,
+    ServerLogSettings, ServerMember, ServerSafetySettings, ServerScreening, Session,
+    SoundboardSound, StageInstance, ThreadMember, ThreadMetadata, User, UserCustomTheme,
+    VerificationLevel, Webhook,
 };
 use crate::types::entities::PublicUser;
 
@@ -43,7 +48,7 @@ pub async fn create_user(
         r#"
         INSERT INTO users (id, instance_id, username, email, password_hash)
         VALUES ($1, $2, $3, $4, $5)
-        RETURNING id, instance_id, username, display_name, email, password_hash,
+        RETURNING id, instance_id, username, display_name, email, email_verified, password_hash,
                   avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         "#,
     )
@@ -59,7 +64,7 @@ pub async fn create_user(
 pub async fn get_user_by_id(pool: &PgPool, id: Uuid) -> Result<Option<User>, sqlx::Error> {
     sqlx::query_as::<_, User>(
         r#"
-        SELECT id, instance_id, username, display_name, email, password_hash,
+        SELECT id, instance_id, username, display_name, email, email_verified, password_hash,
                avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         FROM users WHERE id = $1
         "#,
@@ -72,7 +77,7 @@ pub async fn get_user_by_id(pool: &PgPool, id: Uuid) -> Result<Option<User>, sql
 pub async fn get_user_by_email(pool: &PgPool, email: &str) -> Result<Option<User>, sqlx::Error> {
     sqlx::query_as::<_, User>(
         r#"
-        SELECT id, instance_id, username, display_name, email, password_hash,
+        SELECT id, instance_id, username, display_name, email, email_verified, password_hash,
                avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         FROM users WHERE email = $1
         "#,
@@ -108,7 +113,7 @@ pub async fn get_users_by_ids(
 ) -> Result<Vec<User>, sqlx::Error> {
     sqlx::query_as::<_, User>(
         r#"
-        SELECT id, instance_id, username, display_name, email, password_hash,
+        SELECT id, instance_id, username, display_name, email, email_verified, password_hash,
                avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         FROM users WHERE id = ANY($1)
         "#,
@@ -195,7 +200,8 @@ pub async fn create_server(
         INSERT INTO servers (id, instance_id, name, description, owner_id)
         VALUES ($1, $2, $3, $4, $5)
         RETURNING id, instance_id, name, description, icon_url, banner_url, banner_position,
-                  owner_id, default_channel_id, created_at, updated_at
+                  owner_id, default_channel_id, edit_history_retention_days, created_at,
+                  updated_at
         "#,
     )
     .bind(id)
@@ -211,7 +217,8 @@ pub async fn get_server_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Server>,
     sqlx::query_as::<_, Server>(
         r#"
         SELECT id, instance_id, name, description, icon_url, banner_url, banner_position,
-               owner_id, default_channel_id, created_at, updated_at
+               owner_id, default_channel_id, edit_history_retention_days, created_at,
+               updated_at
         FROM servers WHERE id = $1
         "#,
     )
@@ -224,7 +231,8 @@ pub async fn get_user_servers(pool: &PgPool, user_id: Uuid) -> Result<Vec<Server
     sqlx::query_as::<_, Server>(
         r#"
         SELECT s.id, s.instance_id, s.name, s.description, s.icon_url, s.banner_url,
-               s.banner_position, s.owner_id, s.default_channel_id, s.created_at, s.updated_at
+               s.banner_position, s.owner_id, s.default_channel_id,
+               s.edit_history_retention_days, s.created_at, s.updated_at
         FROM servers s
         INNER JOIN server_members sm ON s.id = sm.server_id
         WHERE sm.user_id = $1
@@ -270,9 +278,13 @@ pub async fn add_server_member(
 ) -> Result<ServerMember, sqlx::Error> {
     sqlx::query_as::<_, ServerMember>(
         r#"
-        INSERT INTO server_members (server_id, user_id)
-        VALUES ($1, $2)
-        RETURNING server_id, user_id, nickname, joined_at
+        INSERT INTO server_members (server_id, user_id, pending)
+        VALUES ($1, $2, EXISTS (
+            SELECT 1 FROM server_screening sc
+            INNER JOIN servers s ON s.id = sc.server_id
+            WHERE sc.server_id = $1 AND sc.enabled AND s.owner_id <> $2
+        ))
+        RETURNING server_id, user_id, nickname, joined_at, communication_disabled_until, pending
         "#,
     )
     .bind(server_id)
@@ -288,7 +300,7 @@ pub async fn get_server_member(
 ) -> Result<Option<ServerMember>, sqlx::Error> {
     sqlx::query_as::<_, ServerMember>(
         r#"
-        SELECT server_id, user_id, nickname, joined_at
+        SELECT server_id, user_id, nickname, joined_at, communication_disabled_until, pending
         FROM server_members
         WHERE server_id = $1 AND user_id = $2
         "#,
@@ -305,7 +317,7 @@ pub async fn get_server_members(
 ) -> Result<Vec<ServerMember>, sqlx::Error> {
     sqlx::query_as::<_, ServerMember>(
         r#"
-        SELECT server_id, user_id, nickname, joined_at
+        SELECT server_id, user_id, nickname, joined_at, communication_disabled_until, pending
         FROM server_members
         WHERE server_id = $1
         ORDER BY joined_at
@@ -316,6 +328,26 @@ pub async fn get_server_members(
     .await
 }
 
+pub async fn set_member_timeout(
+    pool: &PgPool,
+    server_id: Uuid,
+    user_id: Uuid,
+    until: Option<DateTime<Utc>>,
+) -> Result<Option<ServerMember>, sqlx::Error> {
+    sqlx::query_as::<_, ServerMember>(
+        r#"
+        UPDATE server_members SET communication_disabled_until = $3
+        WHERE server_id = $1 AND user_id = $2
+        RETURNING server_id, user_id, nickname, joined_at, communication_disabled_until, pending
+        "#,
+    )
+    .bind(server_id)
+    .bind(user_id)
+    .bind(until)
+    .fetch_optional(pool)
+    .await
+}
+
 pub async fn remove_server_member(
     pool: &PgPool,
     server_id: Uuid,
@@ -347,7 +379,8 @@ pub async fn create_channel(
         INSERT INTO channels (id, instance_id, server_id, channel_type, name, topic, parent_id, position)
         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
         RETURNING id, instance_id, server_id, parent_id, channel_type, name, topic, position,
-                  created_at, updated_at, last_message_id
+                  created_at, updated_at, last_message_id, rate_limit_per_user, require_tag,
+                  retention_days, message_ttl_minutes
         "#,
     )
     .bind(id)
@@ -369,7 +402,8 @@ pub async fn get_server_channels(
     sqlx::query_as::<_, Channel>(
         r#"
         SELECT id, instance_id, server_id, parent_id, channel_type, name, topic, position,
-               created_at, updated_at, last_message_id
+               created_at, updated_at, last_message_id, rate_limit_per_user, require_tag,
+               retention_days, message_ttl_minutes
         FROM channels
         WHERE server_id = $1
         ORDER BY position
@@ -384,7 +418,8 @@ pub async fn get_channel_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Channel
     sqlx::query_as::<_, Channel>(
         r#"
         SELECT id, instance_id, server_id, parent_id, channel_type, name, topic, position,
-               created_at, updated_at, last_message_id
+               created_at, updated_at, last_message_id, rate_limit_per_user, require_tag,
+               retention_days, message_ttl_minutes
         FROM channels WHERE id = $1
         "#,
     )
@@ -393,30 +428,97 @@ pub async fn get_channel_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Channel
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn update_channel(
     pool: &PgPool,
     id: Uuid,
     name: Option<&str>,
     topic: Option<&str>,
+    rate_limit_per_user: Option<i32>,
+    require_tag: Option<bool>,
+    retention_days: Option<i32>,
+    message_ttl_minutes: Option<i32>,
 ) -> Result<Channel, sqlx::Error> {
     sqlx::query_as::<_, Channel>(
         r#"
         UPDATE channels
         SET name = COALESCE($2, name),
             topic = COALESCE($3, topic),
+            rate_limit_per_user = COALESCE($4, rate_limit_per_user),
+            require_tag = COALESCE($5, require_tag),
+            retention_days = COALESCE($6, retention_days),
+            message_ttl_minutes = COALESCE($7, message_ttl_minutes),
             updated_at = now()
         WHERE id = $1
         RETURNING id, instance_id, server_id, parent_id, channel_type, name, topic, position,
-                  created_at, updated_at, last_message_id
+                  created_at, updated_at, last_message_id, rate_limit_per_user, require_tag,
+                  retention_days, message_ttl_minutes
         "#,
     )
     .bind(id)
     .bind(name)
     .bind(topic)
+    .bind(rate_limit_per_user)
+    .bind(require_tag)
+    .bind(retention_days)
+    .bind(message_ttl_minutes)
     .fetch_one(pool)
     .await
 }
 
+/// Apply a bulk reorder in one transaction: set each channel's position and
+/// category, then copy each `sync_ids` channel's category overrides onto it.
+pub async fn reorder_channels(
+    pool: &PgPool,
+    server_id: Uuid,
+    ids: &[Uuid],
+    positions: &[i32],
+    parent_ids: &[Option<Uuid>],
+    sync_ids: &[Uuid],
+) -> Result<Vec<Channel>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let channels = sqlx::query_as::<_, Channel>(
+        r#"
+        UPDATE channels c
+        SET position = u.position, parent_id = u.parent_id, updated_at = now()
+        FROM UNNEST($2::UUID[], $3::INT[], $4::UUID[]) AS u(id, position, parent_id)
+        WHERE c.id = u.id AND c.server_id = $1
+        RETURNING c.id, c.instance_id, c.server_id, c.parent_id, c.channel_type, c.name, c.topic,
+                  c.position, c.created_at, c.updated_at, c.last_message_id,
+                  c.rate_limit_per_user, c.require_tag, c.retention_days, c.message_ttl_minutes
+        "#,
+    )
+    .bind(server_id)
+    .bind(ids)
+    .bind(positions)
+    .bind(parent_ids)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    if !sync_ids.is_empty() {
+        sqlx::query("DELETE FROM channel_overrides WHERE channel_id = ANY($1)")
+            .bind(sync_ids)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            INSERT INTO channel_overrides (id, channel_id, target_type, target_id, allow, deny)
+            SELECT gen_random_uuid(), c.id, o.target_type, o.target_id, o.allow, o.deny
+            FROM channels c
+            INNER JOIN channel_overrides o ON o.channel_id = c.parent_id
+            WHERE c.id = ANY($1)
+            "#,
+        )
+        .bind(sync_ids)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(channels)
+}
+
 pub async fn delete_channel(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
     sqlx::query("DELETE FROM channels WHERE id = $1")
         .bind(id)
@@ -427,6 +529,7 @@ pub async fn delete_channel(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error>
 
 // ── Messages ───────────────────────────────────────────
 
+#[allow(clippy::too_many_arguments)]
 pub async fn create_message(
     pool: &PgPool,
     id: Uuid,
@@ -435,13 +538,19 @@ pub async fn create_message(
     author_id: Uuid,
     content: &str,
     reply_to_id: Option<Uuid>,
+    silent: bool,
 ) -> Result<Message, sqlx::Error> {
     sqlx::query_as::<_, Message>(
         r#"
-        INSERT INTO messages (id, instance_id, channel_id, author_id, content, reply_to_id)
-        VALUES ($1, $2, $3, $4, $5, $6)
+        INSERT INTO messages (id, instance_id, channel_id, author_id, content, reply_to_id, silent,
+                              expires_at)
+        SELECT $1, $2, $3, $4, $5, $6, $7,
+               CASE WHEN c.message_ttl_minutes > 0
+                    THEN now() + make_interval(mins => c.message_ttl_minutes) END
+        FROM channels c WHERE c.id = $3
         RETURNING id, instance_id, channel_id, author_id, content, reply_to_id,
-                  edited_at, pinned, created_at
+                  edited_at, pinned, mentions, mention_roles, mention_everyone,
+                  silent, expires_at, created_at
         "#,
     )
     .bind(id)
@@ -450,6 +559,35 @@ pub async fn create_message(
     .bind(author_id)
     .bind(content)
     .bind(reply_to_id)
+    .bind(silent)
+    .fetch_one(pool)
+    .await
+}
+
+/// Message with no author, posted by the server itself (AutoMod alerts etc.).
+pub async fn create_system_message(
+    pool: &PgPool,
+    id: Uuid,
+    instance_id: Uuid,
+    channel_id: Uuid,
+    content: &str,
+) -> Result<Message, sqlx::Error> {
+    sqlx::query_as::<_, Message>(
+        r#"
+        INSERT INTO messages (id, instance_id, channel_id, author_id, content, expires_at)
+        SELECT $1, $2, $3, NULL, $4,
+               CASE WHEN c.message_ttl_minutes > 0
+                    THEN now() + make_interval(mins => c.message_ttl_minutes) END
+        FROM channels c WHERE c.id = $3
+        RETURNING id, instance_id, channel_id, author_id, content, reply_to_id,
+                  edited_at, pinned, mentions, mention_roles, mention_everyone,
+                  silent, expires_at, created_at
+        "#,
+    )
+    .bind(id)
+    .bind(instance_id)
+    .bind(channel_id)
+    .bind(content)
     .fetch_one(pool)
     .await
 }
@@ -459,13 +597,45 @@ pub async fn get_messages(
     channel_id: Uuid,
     before: Option<Uuid>,
     after: Option<Uuid>,
+    around: Option<Uuid>,
     limit: i64,
 ) -> Result<Vec<Message>, sqlx::Error> {
-    if let Some(before_id) = before {
+    if let Some(around_id) = around {
+        // The anchor and the older half, then the newer half, newest first
+        sqlx::query_as::<_, Message>(
+            r#"
+            SELECT * FROM (
+                (SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
+                        edited_at, pinned, mentions, mention_roles, mention_everyone,
+                        silent, expires_at, created_at
+                 FROM messages
+                 WHERE channel_id = $1 AND id <= $2
+                 ORDER BY id DESC
+                 LIMIT $3)
+                UNION ALL
+                (SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
+                        edited_at, pinned, mentions, mention_roles, mention_everyone,
+                        silent, expires_at, created_at
+                 FROM messages
+                 WHERE channel_id = $1 AND id > $2
+                 ORDER BY id ASC
+                 LIMIT $4)
+            ) window_messages
+            ORDER BY id DESC
+            "#,
+        )
+        .bind(channel_id)
+        .bind(around_id)
+        .bind(limit - limit / 2)
+        .bind(limit / 2)
+        .fetch_all(pool)
+        .await
+    } else if let Some(before_id) = before {
         sqlx::query_as::<_, Message>(
             r#"
             SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
-                   edited_at, pinned, created_at
+                   edited_at, pinned, mentions, mention_roles, mention_everyone,
+                   silent, expires_at, created_at
             FROM messages
             WHERE channel_id = $1 AND id < $2
             ORDER BY id DESC
@@ -481,7 +651,8 @@ pub async fn get_messages(
         sqlx::query_as::<_, Message>(
             r#"
             SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
-                   edited_at, pinned, created_at
+                   edited_at, pinned, mentions, mention_roles, mention_everyone,
+                   silent, expires_at, created_at
             FROM messages
             WHERE channel_id = $1 AND id > $2
             ORDER BY id ASC
@@ -497,7 +668,8 @@ pub async fn get_messages(
         sqlx::query_as::<_, Message>(
             r#"
             SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
-                   edited_at, pinned, created_at
+                   edited_at, pinned, mentions, mention_roles, mention_everyone,
+                   silent, expires_at, created_at
             FROM messages
             WHERE channel_id = $1
             ORDER BY id DESC
@@ -697,6 +869,25 @@ pub async fn get_member_roles(
     .await
 }
 
+/// Members of a server holding any of the given roles.
+pub async fn get_role_member_ids(
+    pool: &PgPool,
+    server_id: Uuid,
+    role_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT DISTINCT user_id FROM member_roles
+        WHERE server_id = $1 AND role_id = ANY($2)
+        "#,
+    )
+    .bind(server_id)
+    .bind(role_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|r| r.0).collect())
+}
+
 // ── Channel Overrides ─────────────────────────────────
 
 pub async fn get_channel_overrides(
@@ -782,6 +973,7 @@ pub async fn get_default_role(
 
 // ── Message Edit/Delete ───────────────────────────────
 
+/// Replace a message's content, keeping the old version as a revision.
 pub async fn update_message_content(
     pool: &PgPool,
     message_id: Uuid,
@@ -789,10 +981,16 @@ pub async fn update_message_content(
 ) -> Result<Message, sqlx::Error> {
     sqlx::query_as::<_, Message>(
         r#"
+        WITH previous AS (
+            INSERT INTO message_revisions (message_id, content, created_at)
+            SELECT id, content, COALESCE(edited_at, created_at)
+            FROM messages WHERE id = $1
+        )
         UPDATE messages SET content = $2, edited_at = now()
         WHERE id = $1
         RETURNING id, instance_id, channel_id, author_id, content, reply_to_id,
-                  edited_at, pinned, created_at
+                  edited_at, pinned, mentions, mention_roles, mention_everyone,
+                  silent, expires_at, created_at
         "#,
     )
     .bind(message_id)
@@ -801,6 +999,40 @@ pub async fn update_message_content(
     .await
 }
 
+/// Earlier versions of a message, oldest first.
+pub async fn get_message_revisions(
+    pool: &PgPool,
+    message_id: Uuid,
+) -> Result<Vec<MessageRevision>, sqlx::Error> {
+    sqlx::query_as::<_, MessageRevision>(
+        r#"
+        SELECT id, message_id, content, created_at, replaced_at FROM message_revisions
+        WHERE message_id = $1 ORDER BY replaced_at
+        "#,
+    )
+    .bind(message_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Drop revisions older than their server's edit history retention.
+pub async fn delete_expired_message_revisions(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM message_revisions r
+        USING messages m, channels c, servers s
+        WHERE r.message_id = m.id
+          AND m.channel_id = c.id
+          AND c.server_id = s.id
+          AND s.edit_history_retention_days > 0
+          AND r.replaced_at < now() - make_interval(days => s.edit_history_retention_days)
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
 pub async fn delete_message(pool: &PgPool, message_id: Uuid) -> Result<(), sqlx::Error> {
     sqlx::query("DELETE FROM messages WHERE id = $1")
         .bind(message_id)
@@ -809,6 +1041,164 @@ pub async fn delete_message(pool: &PgPool, message_id: Uuid) -> Result<(), sqlx:
     Ok(())
 }
 
+pub async fn delete_messages(pool: &PgPool, message_ids: &[Uuid]) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM messages WHERE id = ANY($1)")
+        .bind(message_ids)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Messages whose disappearing timer has run out, as
+/// (message_id, channel_id, server_id).
+pub async fn get_expired_messages(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<(Uuid, Uuid, Option<Uuid>)>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT m.id, m.channel_id, c.server_id
+        FROM messages m
+        JOIN channels c ON c.id = m.channel_id
+        WHERE m.expires_at <= now()
+        ORDER BY m.expires_at
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Whether any channel has its own retention period.
+pub async fn any_channel_retention(pool: &PgPool) -> Result<bool, sqlx::Error> {
+    let row: (bool,) =
+        sqlx::query_as("SELECT EXISTS (SELECT 1 FROM channels WHERE retention_days > 0)")
+            .fetch_one(pool)
+            .await?;
+    Ok(row.0)
+}
+
+/// Messages older than their channel's own retention period, as
+/// (message_id, channel_id, server_id). Only channels with a limit are read.
+pub async fn get_messages_past_channel_retention(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<(Uuid, Uuid, Option<Uuid>)>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT m.id, m.channel_id, c.server_id
+        FROM channels c
+        JOIN messages m ON m.channel_id = c.id
+        WHERE c.retention_days > 0
+          AND m.created_at < now() - make_interval(days => c.retention_days)
+        ORDER BY m.created_at
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Messages older than the instance-wide retention cap, as
+/// (message_id, channel_id, server_id).
+pub async fn get_messages_past_instance_retention(
+    pool: &PgPool,
+    retention_days: i32,
+    limit: i64,
+) -> Result<Vec<(Uuid, Uuid, Option<Uuid>)>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT m.id, m.channel_id, c.server_id
+        FROM messages m
+        JOIN channels c ON c.id = m.channel_id
+        WHERE m.created_at < now() - make_interval(days => $1)
+        ORDER BY m.created_at
+        LIMIT $2
+        "#,
+    )
+    .bind(retention_days)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// A member's messages across a server's channels since `since`, as
+/// (message_id, channel_id), oldest first.
+pub async fn get_member_message_ids_since(
+    pool: &PgPool,
+    server_id: Uuid,
+    author_id: Uuid,
+    since: DateTime<Utc>,
+    limit: i64,
+) -> Result<Vec<(Uuid, Uuid)>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT m.id, m.channel_id
+        FROM messages m
+        JOIN channels c ON c.id = m.channel_id
+        WHERE c.server_id = $1 AND m.author_id = $2 AND m.created_at >= $3
+        ORDER BY m.id
+        LIMIT $4
+        "#,
+    )
+    .bind(server_id)
+    .bind(author_id)
+    .bind(since)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Which of `message_ids` belong to `channel_id`.
+pub async fn get_channel_message_ids(
+    pool: &PgPool,
+    channel_id: Uuid,
+    message_ids: &[Uuid],
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(Uuid,)> =
+        sqlx::query_as("SELECT id FROM messages WHERE channel_id = $1 AND id = ANY($2)")
+            .bind(channel_id)
+            .bind(message_ids)
+            .fetch_all(pool)
+            .await?;
+    Ok(rows.into_iter().map(|r| r.0).collect())
+}
+
+/// The newest messages in a channel matching the purge filters.
+pub async fn get_purgeable_message_ids(
+    pool: &PgPool,
+    channel_id: Uuid,
+    author_id: Option<Uuid>,
+    has_links: bool,
+    has_attachments: bool,
+    after: Option<DateTime<Utc>>,
+    limit: i64,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(Uuid,)> = sqlx::query_as(
+        r#"
+        SELECT m.id FROM messages m
+        WHERE m.channel_id = $1
+          AND ($2::uuid IS NULL OR m.author_id = $2)
+          AND (NOT $3 OR m.content ~* 'https?://')
+          AND (NOT $4 OR EXISTS (SELECT 1 FROM attachments a WHERE a.message_id = m.id))
+          AND ($5::timestamptz IS NULL OR m.created_at > $5)
+        ORDER BY m.id DESC
+        LIMIT $6
+        "#,
+    )
+    .bind(channel_id)
+    .bind(author_id)
+    .bind(has_links)
+    .bind(has_attachments)
+    .bind(after)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|r| r.0).collect())
+}
+
 pub async fn delete_channel_messages(pool: &PgPool, channel_id: Uuid) -> Result<u64, sqlx::Error> {
     let result = sqlx::query("DELETE FROM messages WHERE channel_id = $1")
         .bind(channel_id)
@@ -824,7 +1214,8 @@ pub async fn get_message_by_id(
     sqlx::query_as::<_, Message>(
         r#"
         SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
-               edited_at, pinned, created_at
+               edited_at, pinned, mentions, mention_roles, mention_everyone,
+               silent, expires_at, created_at
         FROM messages WHERE id = $1
         "#,
     )
@@ -843,7 +1234,8 @@ pub async fn set_message_pinned(
         UPDATE messages SET pinned = $2
         WHERE id = $1
         RETURNING id, instance_id, channel_id, author_id, content, reply_to_id,
-                  edited_at, pinned, created_at
+                  edited_at, pinned, mentions, mention_roles, mention_everyone,
+                  silent, expires_at, created_at
         "#,
     )
     .bind(message_id)
@@ -859,7 +1251,8 @@ pub async fn get_pinned_messages(
     sqlx::query_as::<_, Message>(
         r#"
         SELECT id, instance_id, channel_id, author_id, content, reply_to_id,
-               edited_at, pinned, created_at
+               edited_at, pinned, mentions, mention_roles, mention_everyone,
+               silent, expires_at, created_at
         FROM messages
         WHERE channel_id = $1 AND pinned = true
         ORDER BY created_at DESC
@@ -870,8 +1263,82 @@ pub async fn get_pinned_messages(
     .await
 }
 
+// ── Forwards ──────────────────────────────────────────
+
+pub async fn create_message_forward(
+    pool: &PgPool,
+    message_id: Uuid,
+    source_message_id: Uuid,
+    source_channel_id: Uuid,
+    content: Option<&str>,
+    attachments: &[Attachment],
+    source_created_at: DateTime<Utc>,
+) -> Result<MessageForward, sqlx::Error> {
+    sqlx::query_as::<_, MessageForward>(
+        r#"
+        INSERT INTO message_forwards
+            (message_id, source_message_id, source_channel_id, content, attachments,
+             source_created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING message_id, source_message_id, source_channel_id, content, attachments,
+                  source_created_at, created_at
+        "#,
+    )
+    .bind(message_id)
+    .bind(source_message_id)
+    .bind(source_channel_id)
+    .bind(content)
+    .bind(sqlx::types::Json(attachments))
+    .bind(source_created_at)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_forwards_for_messages(
+    pool: &PgPool,
+    message_ids: &[Uuid],
+) -> Result<Vec<MessageForward>, sqlx::Error> {
+    sqlx::query_as::<_, MessageForward>(
+        r#"
+        SELECT message_id, source_message_id, source_channel_id, content, attachments,
+               source_created_at, created_at
+        FROM message_forwards WHERE message_id = ANY($1)
+        "#,
+    )
+    .bind(message_ids)
+    .fetch_all(pool)
+    .await
+}
+
 // ── Attachments ───────────────────────────────────────
 
+/// Files attached to these messages that no forward snapshot or other
+/// message still shows, i.e. the ones safe to delete along with the messages.
+pub async fn get_attachment_urls_for_messages(
+    pool: &PgPool,
+    message_ids: &[Uuid],
+) -> Result<Vec<String>, sqlx::Error> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        r#"
+        SELECT DISTINCT a.url FROM attachments a
+        WHERE a.message_id = ANY($1)
+          AND NOT EXISTS (
+              SELECT 1 FROM message_forwards f
+              WHERE f.attachments @> jsonb_build_array(jsonb_build_object('url', a.url))
+          )
+          -- Announcement follower copies share the source's files
+          AND NOT EXISTS (
+              SELECT 1 FROM attachments o
+              WHERE o.url = a.url AND NOT o.message_id = ANY($1)
+          )
+        "#,
+    )
+    .bind(message_ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().map(|r| r.0).collect())
+}
+
 pub async fn create_attachment(
     pool: &PgPool,
     id: Uuid,
@@ -1075,7 +1542,7 @@ pub async fn get_user_by_username(
 ) -> Result<Option<User>, sqlx::Error> {
     sqlx::query_as::<_, User>(
         r#"
-        SELECT id, instance_id, username, display_name, email, password_hash,
+        SELECT id, instance_id, username, display_name, email, email_verified, password_hash,
                avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         FROM users WHERE username = $1
         "#,
@@ -1093,7 +1560,7 @@ pub async fn search_users_by_username(
     let pattern = format!("{}%", query);
     sqlx::query_as::<_, User>(
         r#"
-        SELECT id, instance_id, username, display_name, email, password_hash,
+        SELECT id, instance_id, username, display_name, email, email_verified, password_hash,
                avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         FROM users WHERE username ILIKE $1
         ORDER BY username
@@ -1134,7 +1601,8 @@ pub async fn get_dm_channels(
     sqlx::query_as::<_, Channel>(
         r#"
         SELECT c.id, c.instance_id, c.server_id, c.parent_id, c.channel_type,
-               c.name, c.topic, c.position, c.created_at, c.updated_at, c.last_message_id
+               c.name, c.topic, c.position, c.created_at, c.updated_at, c.last_message_id,
+               c.rate_limit_per_user, c.require_tag, c.retention_days, c.message_ttl_minutes
         FROM channels c
         INNER JOIN dm_members dm ON c.id = dm.channel_id
         WHERE dm.user_id = $1 AND c.channel_type IN ('dm', 'groupdm') AND dm.closed = FALSE
@@ -1152,7 +1620,7 @@ pub async fn get_dm_members(
 ) -> Result<Vec<User>, sqlx::Error> {
     sqlx::query_as::<_, User>(
         r#"
-        SELECT u.id, u.instance_id, u.username, u.display_name, u.email, u.password_hash,
+        SELECT u.id, u.instance_id, u.username, u.display_name, u.email, u.email_verified, u.password_hash,
                u.avatar_url, u.bio, u.status, u.custom_status, u.timezone, u.theme_preference, u.is_admin, u.bot, u.created_at, u.updated_at
         FROM users u
         INNER JOIN dm_members dm ON u.id = dm.user_id
@@ -1172,7 +1640,8 @@ pub async fn find_existing_dm(
     sqlx::query_as::<_, Channel>(
         r#"
         SELECT c.id, c.instance_id, c.server_id, c.parent_id, c.channel_type,
-               c.name, c.topic, c.position, c.created_at, c.updated_at, c.last_message_id
+               c.name, c.topic, c.position, c.created_at, c.updated_at, c.last_message_id,
+               c.rate_limit_per_user, c.require_tag, c.retention_days, c.message_ttl_minutes
         FROM channels c
         WHERE c.channel_type = 'dm'
           AND c.id IN (
@@ -1213,17 +1682,24 @@ pub async fn create_thread_metadata(
     channel_id: Uuid,
     parent_channel_id: Uuid,
     starter_message_id: Option<Uuid>,
+    owner_id: Uuid,
+    auto_archive_minutes: i32,
 ) -> Result<ThreadMetadata, sqlx::Error> {
     sqlx::query_as::<_, ThreadMetadata>(
         r#"
-        INSERT INTO thread_metadata (channel_id, parent_channel_id, starter_message_id)
-        VALUES ($1, $2, $3)
-        RETURNING channel_id, parent_channel_id, starter_message_id, archived, locked, message_count, created_at
+        INSERT INTO thread_metadata
+            (channel_id, parent_channel_id, starter_message_id, owner_id, auto_archive_minutes)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING channel_id, parent_channel_id, starter_message_id, archived, locked,
+                  message_count, created_at, owner_id, auto_archive_minutes, archived_at,
+                  last_activity_at
         "#,
     )
     .bind(channel_id)
     .bind(parent_channel_id)
     .bind(starter_message_id)
+    .bind(owner_id)
+    .bind(auto_archive_minutes)
     .fetch_one(pool)
     .await
 }
@@ -1234,7 +1710,9 @@ pub async fn get_thread_metadata(
 ) -> Result<Option<ThreadMetadata>, sqlx::Error> {
     sqlx::query_as::<_, ThreadMetadata>(
         r#"
-        SELECT channel_id, parent_channel_id, starter_message_id, archived, locked, message_count, created_at
+        SELECT channel_id, parent_channel_id, starter_message_id, archived, locked,
+               message_count, created_at, owner_id, auto_archive_minutes, archived_at,
+               last_activity_at
         FROM thread_metadata WHERE channel_id = $1
         "#,
     )
@@ -1246,28 +1724,33 @@ pub async fn get_thread_metadata(
 pub async fn get_channel_threads(
     pool: &PgPool,
     parent_channel_id: Uuid,
+    archived: bool,
 ) -> Result<Vec<Channel>, sqlx::Error> {
     sqlx::query_as::<_, Channel>(
         r#"
         SELECT c.id, c.instance_id, c.server_id, c.parent_id, c.channel_type,
-               c.name, c.topic, c.position, c.created_at, c.updated_at, c.last_message_id
+               c.name, c.topic, c.position, c.created_at, c.updated_at, c.last_message_id,
+               c.rate_limit_per_user, c.require_tag, c.retention_days, c.message_ttl_minutes
         FROM channels c
         INNER JOIN thread_metadata tm ON c.id = tm.channel_id
-        WHERE tm.parent_channel_id = $1 AND tm.archived = false
-        ORDER BY c.created_at DESC
+        WHERE tm.parent_channel_id = $1 AND tm.archived = $2
+        ORDER BY tm.last_activity_at DESC
         "#,
     )
     .bind(parent_channel_id)
+    .bind(archived)
     .fetch_all(pool)
     .await
 }
 
-pub async fn increment_thread_message_count(
-    pool: &PgPool,
-    channel_id: Uuid,
-) -> Result<(), sqlx::Error> {
+/// Count a new message and push back the thread's auto-archive deadline.
+pub async fn record_thread_activity(pool: &PgPool, channel_id: Uuid) -> Result<(), sqlx::Error> {
     sqlx::query(
-        "UPDATE thread_metadata SET message_count = message_count + 1 WHERE channel_id = $1",
+        r#"
+        UPDATE thread_metadata
+        SET message_count = message_count + 1, last_activity_at = now()
+        WHERE channel_id = $1
+        "#,
     )
     .bind(channel_id)
     .execute(pool)
@@ -1275,25 +1758,293 @@ pub async fn increment_thread_message_count(
     Ok(())
 }
 
-// ── Search ───────────────────────────────────────────
-
-pub async fn search_messages(
+/// Set the archived/locked state and auto-archive period. Unarchiving
+/// counts as activity so the thread is not archived again straight away.
+pub async fn update_thread_metadata(
     pool: &PgPool,
-    query: &str,
-    channel_id: Option<Uuid>,
-    server_id: Option<Uuid>,
-    limit: i32,
-    offset: i32,
-) -> Result<Vec<SearchResult>, sqlx::Error> {
-    sqlx::query_as::<_, SearchResult>(
-        "SELECT * FROM search_messages($1, $2, $3, $4, $5)",
+    channel_id: Uuid,
+    archived: bool,
+    locked: bool,
+    auto_archive_minutes: i32,
+) -> Result<ThreadMetadata, sqlx::Error> {
+    sqlx::query_as::<_, ThreadMetadata>(
+        r#"
+        UPDATE thread_metadata SET
+            archived = $2,
+            locked = $3,
+            auto_archive_minutes = $4,
+            archived_at = CASE
+                WHEN NOT $2 THEN NULL
+                WHEN archived THEN archived_at
+                ELSE now()
+            END,
+            last_activity_at = CASE WHEN archived AND NOT $2 THEN now() ELSE last_activity_at END
+        WHERE channel_id = $1
+        RETURNING channel_id, parent_channel_id, starter_message_id, archived, locked,
+                  message_count, created_at, owner_id, auto_archive_minutes, archived_at,
+                  last_activity_at
+        "#,
     )
-    .bind(query)
     .bind(channel_id)
-    .bind(server_id)
-    .bind(limit)
-    .bind(offset)
-    .fetch_all(pool)
+    .bind(archived)
+    .bind(locked)
+    .bind(auto_archive_minutes)
+    .fetch_one(pool)
+    .await
+}
+
+/// Archive every open thread that has been idle for its auto-archive period.
+pub async fn archive_idle_threads(pool: &PgPool) -> Result<Vec<ThreadMetadata>, sqlx::Error> {
+    sqlx::query_as::<_, ThreadMetadata>(
+        r#"
+        UPDATE thread_metadata SET archived = true, archived_at = now()
+        WHERE archived = false
+          AND last_activity_at + make_interval(mins => auto_archive_minutes) <= now()
+        RETURNING channel_id, parent_channel_id, starter_message_id, archived, locked,
+                  message_count, created_at, owner_id, auto_archive_minutes, archived_at,
+                  last_activity_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+}
+
+// ── Thread Members ───────────────────────────────────
+
+/// Add a member to a thread. Returns false if they were already in it.
+pub async fn add_thread_member(
+    pool: &PgPool,
+    channel_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO thread_members (channel_id, user_id) VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(channel_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Remove a member from a thread. Returns false if they were not in it.
+pub async fn remove_thread_member(
+    pool: &PgPool,
+    channel_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM thread_members WHERE channel_id = $1 AND user_id = $2")
+        .bind(channel_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn get_thread_members(
+    pool: &PgPool,
+    channel_id: Uuid,
+) -> Result<Vec<ThreadMember>, sqlx::Error> {
+    sqlx::query_as::<_, ThreadMember>(
+        r#"
+        SELECT channel_id, user_id, joined_at FROM thread_members
+        WHERE channel_id = $1 ORDER BY joined_at
+        "#,
+    )
+    .bind(channel_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_thread_member_ids(
+    pool: &PgPool,
+    channel_id: Uuid,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(Uuid,)> =
+        sqlx::query_as("SELECT user_id FROM thread_members WHERE channel_id = $1")
+            .bind(channel_id)
+            .fetch_all(pool)
+            .await?;
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
+// ── Forums ───────────────────────────────────────────
+
+pub async fn get_forum_tags(pool: &PgPool, channel_id: Uuid) -> Result<Vec<ForumTag>, sqlx::Error> {
+    sqlx::query_as::<_, ForumTag>(
+        "SELECT * FROM forum_tags WHERE channel_id = $1 ORDER BY position, created_at",
+    )
+    .bind(channel_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_forum_tag(pool: &PgPool, id: Uuid) -> Result<Option<ForumTag>, sqlx::Error> {
+    sqlx::query_as::<_, ForumTag>("SELECT * FROM forum_tags WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn create_forum_tag(
+    pool: &PgPool,
+    id: Uuid,
+    channel_id: Uuid,
+    name: &str,
+    emoji: Option<&str>,
+    position: i32,
+) -> Result<ForumTag, sqlx::Error> {
+    sqlx::query_as::<_, ForumTag>(
+        r#"
+        INSERT INTO forum_tags (id, channel_id, name, emoji, position)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(channel_id)
+    .bind(name)
+    .bind(emoji)
+    .bind(position)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn update_forum_tag(
+    pool: &PgPool,
+    id: Uuid,
+    name: Option<&str>,
+    emoji: Option<&str>,
+    position: Option<i32>,
+) -> Result<ForumTag, sqlx::Error> {
+    sqlx::query_as::<_, ForumTag>(
+        r#"
+        UPDATE forum_tags
+        SET name = COALESCE($2, name),
+            emoji = COALESCE($3, emoji),
+            position = COALESCE($4, position)
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(name)
+    .bind(emoji)
+    .bind(position)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete_forum_tag(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM forum_tags WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn get_forum_post_tag_ids(
+    pool: &PgPool,
+    channel_id: Uuid,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    let rows: Vec<(Uuid,)> =
+        sqlx::query_as("SELECT tag_id FROM forum_post_tags WHERE channel_id = $1")
+            .bind(channel_id)
+            .fetch_all(pool)
+            .await?;
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
+/// Replace the tags applied to a forum post.
+pub async fn set_forum_post_tags(
+    pool: &PgPool,
+    channel_id: Uuid,
+    tag_ids: &[Uuid],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM forum_post_tags WHERE channel_id = $1")
+        .bind(channel_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        r#"
+        INSERT INTO forum_post_tags (channel_id, tag_id)
+        SELECT $1, UNNEST($2::UUID[])
+        "#,
+    )
+    .bind(channel_id)
+    .bind(tag_ids)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await
+}
+
+/// List a forum's posts, optionally only those carrying `tag_id`.
+pub async fn get_forum_posts(
+    pool: &PgPool,
+    forum_id: Uuid,
+    archived: bool,
+    tag_id: Option<Uuid>,
+    sort: ForumSortOrder,
+    before: Option<Uuid>,
+    limit: i64,
+) -> Result<Vec<ForumPost>, sqlx::Error> {
+    sqlx::query_as::<_, ForumPost>(
+        r#"
+        SELECT tm.*, c.name,
+               ARRAY(SELECT pt.tag_id FROM forum_post_tags pt WHERE pt.channel_id = c.id)
+                   AS tag_ids,
+               (SELECT COUNT(*) FROM reactions r WHERE r.message_id = tm.starter_message_id)
+                   AS reaction_count
+        FROM thread_metadata tm
+        INNER JOIN channels c ON c.id = tm.channel_id
+        WHERE tm.parent_channel_id = $1 AND tm.archived = $2
+          AND ($3::UUID IS NULL OR EXISTS (
+              SELECT 1 FROM forum_post_tags pt WHERE pt.channel_id = c.id AND pt.tag_id = $3
+          ))
+          AND ($5::UUID IS NULL OR (
+              CASE WHEN $4 THEN tm.created_at ELSE tm.last_activity_at END, tm.channel_id
+          ) < (
+              SELECT CASE WHEN $4 THEN b.created_at ELSE b.last_activity_at END, b.channel_id
+              FROM thread_metadata b WHERE b.channel_id = $5
+          ))
+        ORDER BY CASE WHEN $4 THEN tm.created_at ELSE tm.last_activity_at END DESC,
+                 tm.channel_id DESC
+        LIMIT $6
+        "#,
+    )
+    .bind(forum_id)
+    .bind(archived)
+    .bind(tag_id)
+    .bind(sort == ForumSortOrder::CreationDate)
+    .bind(before)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+// ── Search ───────────────────────────────────────────
+
+pub async fn search_messages(
+    pool: &PgPool,
+    query: &str,
+    channel_id: Option<Uuid>,
+    server_id: Option<Uuid>,
+    limit: i32,
+    offset: i32,
+) -> Result<Vec<SearchResult>, sqlx::Error> {
+    sqlx::query_as::<_, SearchResult>(
+        "SELECT * FROM search_messages($1, $2, $3, $4, $5)",
+    )
+    .bind(query)
+    .bind(channel_id)
+    .bind(server_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
     .await
 }
 
@@ -1379,19 +2130,22 @@ pub async fn create_ban(
     user_id: Uuid,
     moderator_id: Uuid,
     reason: Option<&str>,
+    expires_at: Option<DateTime<Utc>>,
 ) -> Result<Ban, sqlx::Error> {
     sqlx::query_as::<_, Ban>(
         r#"
-        INSERT INTO bans (server_id, user_id, moderator_id, reason)
-        VALUES ($1, $2, $3, $4)
-        ON CONFLICT (server_id, user_id) DO UPDATE SET reason = $4, moderator_id = $3
-        RETURNING server_id, user_id, moderator_id, reason, created_at
+        INSERT INTO bans (server_id, user_id, moderator_id, reason, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (server_id, user_id)
+            DO UPDATE SET reason = $4, moderator_id = $3, expires_at = $5
+        RETURNING server_id, user_id, moderator_id, reason, created_at, expires_at
         "#,
     )
     .bind(server_id)
     .bind(user_id)
     .bind(moderator_id)
     .bind(reason)
+    .bind(expires_at)
     .fetch_one(pool)
     .await
 }
@@ -1403,7 +2157,7 @@ pub async fn get_ban(
 ) -> Result<Option<Ban>, sqlx::Error> {
     sqlx::query_as::<_, Ban>(
         r#"
-        SELECT server_id, user_id, moderator_id, reason, created_at
+        SELECT server_id, user_id, moderator_id, reason, created_at, expires_at
         FROM bans WHERE server_id = $1 AND user_id = $2
         "#,
     )
@@ -1416,7 +2170,7 @@ pub async fn get_ban(
 pub async fn get_server_bans(pool: &PgPool, server_id: Uuid) -> Result<Vec<Ban>, sqlx::Error> {
     sqlx::query_as::<_, Ban>(
         r#"
-        SELECT server_id, user_id, moderator_id, reason, created_at
+        SELECT server_id, user_id, moderator_id, reason, created_at, expires_at
         FROM bans WHERE server_id = $1
         ORDER BY created_at DESC
         "#,
@@ -1435,6 +2189,37 @@ pub async fn delete_ban(pool: &PgPool, server_id: Uuid, user_id: Uuid) -> Result
     Ok(())
 }
 
+pub async fn get_expired_bans(pool: &PgPool, limit: i64) -> Result<Vec<Ban>, sqlx::Error> {
+    sqlx::query_as::<_, Ban>(
+        r#"
+        SELECT server_id, user_id, moderator_id, reason, created_at, expires_at
+        FROM bans WHERE expires_at <= now()
+        ORDER BY expires_at
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Lift a temporary ban if it is still expired. Returns false when the ban was
+/// already lifted or has since been replaced by a new one.
+pub async fn delete_expired_ban(
+    pool: &PgPool,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "DELETE FROM bans WHERE server_id = $1 AND user_id = $2 AND expires_at <= now()",
+    )
+    .bind(server_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
 // ── Audit Log ───────────────────────────────────────────
 
 pub async fn create_audit_log(
@@ -1463,11 +2248,15 @@ pub async fn create_audit_log(
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn get_audit_log(
     pool: &PgPool,
     server_id: Uuid,
     action: Option<AuditAction>,
     user_id: Option<Uuid>,
+    target_id: Option<Uuid>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
     before: Option<Uuid>,
     limit: i64,
 ) -> Result<Vec<AuditLogEntry>, sqlx::Error> {
@@ -1486,6 +2275,18 @@ pub async fn get_audit_log(
         sql.push_str(&format!(" AND user_id = ${param_idx}"));
         param_idx += 1;
     }
+    if target_id.is_some() {
+        sql.push_str(&format!(" AND target_id = ${param_idx}"));
+        param_idx += 1;
+    }
+    if since.is_some() {
+        sql.push_str(&format!(" AND created_at >= ${param_idx}"));
+        param_idx += 1;
+    }
+    if until.is_some() {
+        sql.push_str(&format!(" AND created_at < ${param_idx}"));
+        param_idx += 1;
+    }
     if before.is_some() {
         sql.push_str(&format!(
             " AND created_at < (SELECT created_at FROM audit_log WHERE id = ${param_idx})"
@@ -1501,6 +2302,15 @@ pub async fn get_audit_log(
     if let Some(uid) = user_id {
         query = query.bind(uid);
     }
+    if let Some(tid) = target_id {
+        query = query.bind(tid);
+    }
+    if let Some(s) = since {
+        query = query.bind(s);
+    }
+    if let Some(u) = until {
+        query = query.bind(u);
+    }
     if let Some(b) = before {
         query = query.bind(b);
     }
@@ -1524,7 +2334,8 @@ pub async fn create_webhook(
         r#"
         INSERT INTO webhooks (id, server_id, channel_id, creator_id, name, token)
         VALUES ($1, $2, $3, $4, $5, $6)
-        RETURNING id, server_id, channel_id, creator_id, name, avatar_url, token, created_at
+        RETURNING id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+                  source_channel_id
         "#,
     )
     .bind(id)
@@ -1543,7 +2354,8 @@ pub async fn get_webhook_by_id(
 ) -> Result<Option<Webhook>, sqlx::Error> {
     sqlx::query_as::<_, Webhook>(
         r#"
-        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at
+        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+               source_channel_id
         FROM webhooks WHERE id = $1
         "#,
     )
@@ -1558,7 +2370,8 @@ pub async fn get_webhook_by_token(
 ) -> Result<Option<Webhook>, sqlx::Error> {
     sqlx::query_as::<_, Webhook>(
         r#"
-        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at
+        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+               source_channel_id
         FROM webhooks WHERE token = $1
         "#,
     )
@@ -1573,7 +2386,8 @@ pub async fn get_channel_webhooks(
 ) -> Result<Vec<Webhook>, sqlx::Error> {
     sqlx::query_as::<_, Webhook>(
         r#"
-        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at
+        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+               source_channel_id
         FROM webhooks WHERE channel_id = $1
         ORDER BY created_at DESC
         "#,
@@ -1589,7 +2403,8 @@ pub async fn get_server_webhooks(
 ) -> Result<Vec<Webhook>, sqlx::Error> {
     sqlx::query_as::<_, Webhook>(
         r#"
-        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at
+        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+               source_channel_id
         FROM webhooks WHERE server_id = $1
         ORDER BY created_at DESC
         "#,
@@ -1611,7 +2426,8 @@ pub async fn update_webhook(
         SET name = COALESCE($2, name),
             channel_id = COALESCE($3, channel_id)
         WHERE id = $1
-        RETURNING id, server_id, channel_id, creator_id, name, avatar_url, token, created_at
+        RETURNING id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+                  source_channel_id
         "#,
     )
     .bind(id)
@@ -1621,30 +2437,117 @@ pub async fn update_webhook(
     .await
 }
 
-pub async fn delete_webhook(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
-    sqlx::query("DELETE FROM webhooks WHERE id = $1")
-        .bind(id)
-        .execute(pool)
-        .await?;
-    Ok(())
+/// Create a follower webhook relaying `source_channel_id` into `channel_id`.
+#[allow(clippy::too_many_arguments)]
+pub async fn create_follower_webhook(
+    pool: &PgPool,
+    id: Uuid,
+    server_id: Uuid,
+    channel_id: Uuid,
+    creator_id: Uuid,
+    name: &str,
+    avatar_url: Option<&str>,
+    token: &str,
+    source_channel_id: Uuid,
+) -> Result<Webhook, sqlx::Error> {
+    sqlx::query_as::<_, Webhook>(
+        r#"
+        INSERT INTO webhooks
+            (id, server_id, channel_id, creator_id, name, avatar_url, token, source_channel_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+                  source_channel_id
+        "#,
+    )
+    .bind(id)
+    .bind(server_id)
+    .bind(channel_id)
+    .bind(creator_id)
+    .bind(name)
+    .bind(avatar_url)
+    .bind(token)
+    .bind(source_channel_id)
+    .fetch_one(pool)
+    .await
 }
 
-// ── User Status ─────────────────────────────────────────
+/// Follower webhooks relaying the given announcement channel.
+pub async fn get_follower_webhooks(
+    pool: &PgPool,
+    source_channel_id: Uuid,
+) -> Result<Vec<Webhook>, sqlx::Error> {
+    sqlx::query_as::<_, Webhook>(
+        r#"
+        SELECT id, server_id, channel_id, creator_id, name, avatar_url, token, created_at,
+               source_channel_id
+        FROM webhooks WHERE source_channel_id = $1
+        ORDER BY created_at
+        "#,
+    )
+    .bind(source_channel_id)
+    .fetch_all(pool)
+    .await
+}
 
-pub async fn update_user_status(
+pub async fn get_channel_followers(
     pool: &PgPool,
-    user_id: Uuid,
-    status: &str,
-) -> Result<(), sqlx::Error> {
-    sqlx::query("UPDATE users SET status = $2, updated_at = now() WHERE id = $1")
-        .bind(user_id)
-        .bind(status)
-        .execute(pool)
-        .await?;
-    Ok(())
+    source_channel_id: Uuid,
+) -> Result<Vec<ChannelFollower>, sqlx::Error> {
+    sqlx::query_as::<_, ChannelFollower>(
+        r#"
+        SELECT id AS webhook_id, server_id, channel_id, created_at
+        FROM webhooks WHERE source_channel_id = $1
+        ORDER BY created_at
+        "#,
+    )
+    .bind(source_channel_id)
+    .fetch_all(pool)
+    .await
 }
 
-pub async fn update_user_custom_status(
+/// Mark a message as published. Returns false if it already was.
+pub async fn mark_message_published(
+    pool: &PgPool,
+    message_id: Uuid,
+    published_by: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        INSERT INTO published_messages (message_id, published_by) VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(message_id)
+    .bind(published_by)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn delete_webhook(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM webhooks WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// ── User Status ─────────────────────────────────────────
+
+pub async fn update_user_status(
+    pool: &PgPool,
+    user_id: Uuid,
+    status: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE users SET status = $2, updated_at = now() WHERE id = $1")
+        .bind(user_id)
+        .bind(status)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn update_user_custom_status(
     pool: &PgPool,
     user_id: Uuid,
     custom_status: Option<&str>,
@@ -1676,7 +2579,7 @@ pub async fn update_user_profile(
             timezone = COALESCE($6, timezone),
             updated_at = now()
         WHERE id = $1
-        RETURNING id, instance_id, username, display_name, email, password_hash,
+        RETURNING id, instance_id, username, display_name, email, email_verified, password_hash,
                   avatar_url, bio, status, custom_status, timezone, theme_preference, is_admin, bot, created_at, updated_at
         "#,
     )
@@ -1690,6 +2593,7 @@ pub async fn update_user_profile(
     .await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn update_server(
     pool: &PgPool,
     server_id: Uuid,
@@ -1698,6 +2602,7 @@ pub async fn update_server(
     icon_url: Option<&str>,
     banner_url: Option<&str>,
     banner_position: Option<i16>,
+    edit_history_retention_days: Option<i32>,
 ) -> Result<Server, sqlx::Error> {
     sqlx::query_as::<_, Server>(
         r#"
@@ -1707,10 +2612,12 @@ pub async fn update_server(
             icon_url = COALESCE($4, icon_url),
             banner_url = COALESCE($5, banner_url),
             banner_position = COALESCE($6, banner_position),
+            edit_history_retention_days = COALESCE($7, edit_history_retention_days),
             updated_at = now()
         WHERE id = $1
         RETURNING id, instance_id, name, description, icon_url, banner_url, banner_position,
-                  owner_id, default_channel_id, created_at, updated_at
+                  owner_id, default_channel_id, edit_history_retention_days, created_at,
+                  updated_at
         "#,
     )
     .bind(server_id)
@@ -1719,6 +2626,27 @@ pub async fn update_server(
     .bind(icon_url)
     .bind(banner_url)
     .bind(banner_position)
+    .bind(edit_history_retention_days)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn update_server_owner(
+    pool: &PgPool,
+    server_id: Uuid,
+    owner_id: Uuid,
+) -> Result<Server, sqlx::Error> {
+    sqlx::query_as::<_, Server>(
+        r#"
+        UPDATE servers SET owner_id = $2, updated_at = now()
+        WHERE id = $1
+        RETURNING id, instance_id, name, description, icon_url, banner_url, banner_position,
+                  owner_id, default_channel_id, edit_history_retention_days, created_at,
+                  updated_at
+        "#,
+    )
+    .bind(server_id)
+    .bind(owner_id)
     .fetch_one(pool)
     .await
 }
@@ -1824,6 +2752,212 @@ pub async fn delete_registration_code(pool: &PgPool, code: &str) -> Result<(), s
     Ok(())
 }
 
+// ── Instance Settings ─────────────────────────────────
+
+pub async fn get_instance_settings(pool: &PgPool) -> Result<InstanceSettings, sqlx::Error> {
+    sqlx::query_as::<_, InstanceSettings>(
+        r#"
+        SELECT registration_mode, registration_questions, message_retention_days, updated_at
+        FROM instance_settings
+        "#,
+    )
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn update_registration_settings(
+    pool: &PgPool,
+    mode: Option<RegistrationMode>,
+    questions: Option<&[String]>,
+) -> Result<InstanceSettings, sqlx::Error> {
+    sqlx::query_as::<_, InstanceSettings>(
+        r#"
+        UPDATE instance_settings SET
+            registration_mode = COALESCE($1, registration_mode),
+            registration_questions = COALESCE($2, registration_questions),
+            updated_at = now()
+        RETURNING registration_mode, registration_questions, message_retention_days, updated_at
+        "#,
+    )
+    .bind(mode)
+    .bind(questions.map(sqlx::types::Json))
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn update_retention_settings(
+    pool: &PgPool,
+    message_retention_days: i32,
+) -> Result<InstanceSettings, sqlx::Error> {
+    sqlx::query_as::<_, InstanceSettings>(
+        r#"
+        UPDATE instance_settings SET
+            message_retention_days = $1,
+            updated_at = now()
+        RETURNING registration_mode, registration_questions, message_retention_days, updated_at
+        "#,
+    )
+    .bind(message_retention_days)
+    .fetch_one(pool)
+    .await
+}
+
+// ── Registration Requests ─────────────────────────────
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_registration_request(
+    pool: &PgPool,
+    id: Uuid,
+    username: &str,
+    email: &str,
+    password_hash: Option<&str>,
+    answers: &[QuestionnaireAnswer],
+    status: RegistrationRequestStatus,
+    user_id: Option<Uuid>,
+) -> Result<RegistrationRequest, sqlx::Error> {
+    sqlx::query_as::<_, RegistrationRequest>(
+        r#"
+        INSERT INTO registration_requests (id, username, email, password_hash, answers, status, user_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(username)
+    .bind(email)
+    .bind(password_hash)
+    .bind(sqlx::types::Json(answers))
+    .bind(status)
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_registration_request(
+    pool: &PgPool,
+    id: Uuid,
+) -> Result<Option<RegistrationRequest>, sqlx::Error> {
+    sqlx::query_as::<_, RegistrationRequest>("SELECT * FROM registration_requests WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn list_registration_requests(
+    pool: &PgPool,
+    status: Option<RegistrationRequestStatus>,
+    limit: i64,
+) -> Result<Vec<RegistrationRequest>, sqlx::Error> {
+    sqlx::query_as::<_, RegistrationRequest>(
+        r#"
+        SELECT * FROM registration_requests
+        WHERE $1::registration_request_status IS NULL OR status = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(status)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// True if a pending request already holds this email or username.
+pub async fn registration_request_pending_for(
+    pool: &PgPool,
+    email: &str,
+    username: &str,
+) -> Result<bool, sqlx::Error> {
+    let row: (bool,) = sqlx::query_as(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM registration_requests
+            WHERE status = 'pending' AND (email = $1 OR username = $2)
+        )
+        "#,
+    )
+    .bind(email)
+    .bind(username)
+    .fetch_one(pool)
+    .await?;
+    Ok(row.0)
+}
+
+/// Record an approve/reject decision on a pending request. The stored password
+/// hash is dropped either way. Returns None if the request was not pending.
+pub async fn review_registration_request(
+    pool: &PgPool,
+    id: Uuid,
+    status: RegistrationRequestStatus,
+    reviewer_id: Uuid,
+    reason: Option<&str>,
+    user_id: Option<Uuid>,
+) -> Result<Option<RegistrationRequest>, sqlx::Error> {
+    sqlx::query_as::<_, RegistrationRequest>(
+        r#"
+        UPDATE registration_requests SET
+            status = $2,
+            reviewer_id = $3,
+            review_reason = $4,
+            user_id = $5,
+            password_hash = NULL,
+            reviewed_at = now()
+        WHERE id = $1 AND status = 'pending'
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(reviewer_id)
+    .bind(reason)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Record the account an approved request created.
+pub async fn set_registration_request_user(
+    pool: &PgPool,
+    id: Uuid,
+    user_id: Uuid,
+) -> Result<RegistrationRequest, sqlx::Error> {
+    sqlx::query_as::<_, RegistrationRequest>(
+        r#"
+        UPDATE registration_requests SET user_id = $2
+        WHERE id = $1
+        RETURNING id, username, email, password_hash, answers, status, user_id, reviewer_id,
+                  review_reason, created_at, reviewed_at
+        "#,
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+}
+
+/// Put a claimed request back in the queue after its approval failed.
+pub async fn reopen_registration_request(
+    pool: &PgPool,
+    id: Uuid,
+    password_hash: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE registration_requests SET
+            status = 'pending',
+            reviewer_id = NULL,
+            password_hash = $2,
+            reviewed_at = NULL
+        WHERE id = $1
+        "#,
+    )
+    .bind(id)
+    .bind(password_hash)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 // ── Read States ──────────────────────────────────────────
 
 pub async fn get_user_read_states(
@@ -1866,6 +3000,32 @@ pub async fn ack_channel(
     .await
 }
 
+/// Store the mentions resolved for a freshly sent message.
+pub async fn set_message_mentions(
+    pool: &PgPool,
+    message_id: Uuid,
+    mentions: &[Uuid],
+    mention_roles: &[Uuid],
+    mention_everyone: bool,
+) -> Result<Message, sqlx::Error> {
+    sqlx::query_as::<_, Message>(
+        r#"
+        UPDATE messages
+        SET mentions = $2, mention_roles = $3, mention_everyone = $4
+        WHERE id = $1
+        RETURNING id, instance_id, channel_id, author_id, content, reply_to_id,
+                  edited_at, pinned, mentions, mention_roles, mention_everyone,
+                  silent, expires_at, created_at
+        "#,
+    )
+    .bind(message_id)
+    .bind(mentions)
+    .bind(mention_roles)
+    .bind(mention_everyone)
+    .fetch_one(pool)
+    .await
+}
+
 pub async fn increment_mention_counts(
     pool: &PgPool,
     channel_id: Uuid,
@@ -2055,55 +3215,392 @@ pub async fn update_user_password_hash(
     Ok(())
 }
 
-// ── Soundboard ──────────────────────────────────────────
+// ── Email Verification ────────────────────────────────
 
-pub async fn create_soundboard_sound(
+/// The user and address a consumed verification token confirms.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct EmailVerificationToken {
+    pub user_id: Uuid,
+    pub email: String,
+}
+
+pub async fn create_email_verification_token(
     pool: &PgPool,
     id: Uuid,
-    server_id: Uuid,
-    uploader_id: Uuid,
-    name: &str,
-    audio_url: &str,
-    duration_ms: i32,
-    emoji_name: Option<&str>,
-) -> Result<SoundboardSound, sqlx::Error> {
-    sqlx::query_as::<_, SoundboardSound>(
-        "INSERT INTO soundboard_sounds (id, server_id, uploader_id, name, audio_url, duration_ms, emoji_name)
-         VALUES ($1, $2, $3, $4, $5, $6, $7)
-         RETURNING *",
+    user_id: Uuid,
+    email: &str,
+    token_hash: &str,
+    expires_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO email_verification_tokens (id, user_id, email, token_hash, expires_at)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
     )
     .bind(id)
-    .bind(server_id)
-    .bind(uploader_id)
-    .bind(name)
-    .bind(audio_url)
-    .bind(duration_ms)
-    .bind(emoji_name)
-    .fetch_one(pool)
-    .await
+    .bind(user_id)
+    .bind(email)
+    .bind(token_hash)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+    Ok(())
 }
 
-pub async fn get_soundboard_sounds(
+/// Atomically delete a verification token by hash (if valid and not expired)
+/// and return it.
+pub async fn consume_email_verification_token(
     pool: &PgPool,
-    server_id: Uuid,
-) -> Result<Vec<SoundboardSound>, sqlx::Error> {
-    sqlx::query_as::<_, SoundboardSound>(
-        "SELECT * FROM soundboard_sounds WHERE server_id = $1 ORDER BY created_at",
+    token_hash: &str,
+) -> Result<Option<EmailVerificationToken>, sqlx::Error> {
+    sqlx::query_as::<_, EmailVerificationToken>(
+        r#"
+        DELETE FROM email_verification_tokens
+        WHERE token_hash = $1 AND expires_at > now()
+        RETURNING user_id, email
+        "#,
     )
-    .bind(server_id)
-    .fetch_all(pool)
+    .bind(token_hash)
+    .fetch_optional(pool)
     .await
 }
 
-pub async fn get_soundboard_sound(
+pub async fn delete_user_email_verification_tokens(
     pool: &PgPool,
-    sound_id: Uuid,
-) -> Result<Option<SoundboardSound>, sqlx::Error> {
-    sqlx::query_as::<_, SoundboardSound>("SELECT * FROM soundboard_sounds WHERE id = $1")
-        .bind(sound_id)
-        .fetch_optional(pool)
-        .await
-}
+    user_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM email_verification_tokens WHERE user_id = $1")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn set_user_email_verified(
+    pool: &PgPool,
+    user_id: Uuid,
+    verified: bool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE users SET email_verified = $2, updated_at = now() WHERE id = $1")
+        .bind(user_id)
+        .bind(verified)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Replace a user's email with an address they have just confirmed.
+pub async fn update_user_email(
+    pool: &PgPool,
+    user_id: Uuid,
+    email: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE users SET email = $2, email_verified = true, updated_at = now() WHERE id = $1",
+    )
+    .bind(user_id)
+    .bind(email)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// ── Email Digests ─────────────────────────────────────
+
+pub async fn touch_user_last_seen(pool: &PgPool, user_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE users SET last_seen_at = now() WHERE id = $1")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, serde::Serialize, sqlx::FromRow)]
+pub struct DigestSettings {
+    pub user_id: Uuid,
+    pub frequency: String,
+    #[serde(skip_serializing)]
+    pub unsubscribe_token: String,
+    pub last_digest_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Fetch a user's digest settings, creating the row with `default_frequency`
+/// and a fresh unsubscribe token if it doesn't exist yet.
+pub async fn ensure_digest_settings(
+    pool: &PgPool,
+    user_id: Uuid,
+    default_frequency: &str,
+    unsubscribe_token: &str,
+) -> Result<DigestSettings, sqlx::Error> {
+    sqlx::query_as::<_, DigestSettings>(
+        r#"
+        INSERT INTO email_digest_settings (user_id, frequency, unsubscribe_token)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id) DO UPDATE SET user_id = EXCLUDED.user_id
+        RETURNING user_id, frequency, unsubscribe_token, last_digest_at, updated_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(default_frequency)
+    .bind(unsubscribe_token)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn set_digest_frequency(
+    pool: &PgPool,
+    user_id: Uuid,
+    frequency: &str,
+    unsubscribe_token: &str,
+) -> Result<DigestSettings, sqlx::Error> {
+    sqlx::query_as::<_, DigestSettings>(
+        r#"
+        INSERT INTO email_digest_settings (user_id, frequency, unsubscribe_token)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id) DO UPDATE SET frequency = $2, updated_at = now()
+        RETURNING user_id, frequency, unsubscribe_token, last_digest_at, updated_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(frequency)
+    .bind(unsubscribe_token)
+    .fetch_one(pool)
+    .await
+}
+
+/// Turn digests off for whoever owns `token`. Returns false for unknown tokens.
+pub async fn unsubscribe_digest_by_token(
+    pool: &PgPool,
+    token: &str,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE email_digest_settings SET frequency = 'never', updated_at = now() WHERE unsubscribe_token = $1",
+    )
+    .bind(token)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn mark_digest_sent(pool: &PgPool, user_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE email_digest_settings SET last_digest_at = now() WHERE user_id = $1")
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DigestCandidate {
+    pub user_id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub last_seen_at: DateTime<Utc>,
+    pub last_digest_at: Option<DateTime<Utc>>,
+}
+
+/// Users with a verified email who have been offline longer than
+/// `offline_threshold_secs` and whose digest interval has elapsed.
+pub async fn get_digest_candidates(
+    pool: &PgPool,
+    offline_threshold_secs: i64,
+    default_frequency: &str,
+    limit: i64,
+) -> Result<Vec<DigestCandidate>, sqlx::Error> {
+    sqlx::query_as::<_, DigestCandidate>(
+        r#"
+        SELECT u.id AS user_id, u.username, u.email,
+               COALESCE(u.last_seen_at, u.created_at) AS last_seen_at, ds.last_digest_at
+        FROM users u
+        LEFT JOIN email_digest_settings ds ON ds.user_id = u.id
+        WHERE u.email IS NOT NULL
+          AND u.email_verified
+          AND NOT u.bot
+          -- Users who never connected count as last seen at sign-up
+          AND COALESCE(u.last_seen_at, u.created_at)
+              < now() - make_interval(secs => $1::bigint::double precision)
+          AND COALESCE(ds.frequency, $2) <> 'never'
+          AND (
+              ds.last_digest_at IS NULL
+              OR ds.last_digest_at < now() - CASE COALESCE(ds.frequency, $2)
+                  WHEN 'weekly' THEN INTERVAL '7 days'
+                  ELSE INTERVAL '1 day'
+              END
+          )
+        ORDER BY ds.last_digest_at NULLS FIRST, COALESCE(u.last_seen_at, u.created_at)
+        LIMIT $3
+        "#,
+    )
+    .bind(offline_threshold_secs)
+    .bind(default_frequency)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DigestMentionRow {
+    pub channel_id: Uuid,
+    pub channel_name: Option<String>,
+    pub server_id: Option<Uuid>,
+    pub server_name: Option<String>,
+    pub mention_count: i32,
+}
+
+/// Server channels where the user has unread mentions that arrived after `since`.
+pub async fn get_unread_mentions_since(
+    pool: &PgPool,
+    user_id: Uuid,
+    since: DateTime<Utc>,
+) -> Result<Vec<DigestMentionRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestMentionRow>(
+        r#"
+        SELECT c.id AS channel_id, c.name AS channel_name, c.server_id, s.name AS server_name,
+               rs.mention_count
+        FROM read_states rs
+        INNER JOIN channels c ON c.id = rs.channel_id
+        INNER JOIN servers s ON s.id = c.server_id
+        WHERE rs.user_id = $1 AND rs.mention_count > 0 AND rs.updated_at > $2
+        ORDER BY s.name, s.id, c.position
+        "#,
+    )
+    .bind(user_id)
+    .bind(since)
+    .fetch_all(pool)
+    .await
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DigestDmRow {
+    pub channel_id: Uuid,
+    pub senders: String,
+    pub message_count: i64,
+}
+
+/// DM channels with unread messages from other users sent after `since`.
+pub async fn get_unread_dms_since(
+    pool: &PgPool,
+    user_id: Uuid,
+    since: DateTime<Utc>,
+) -> Result<Vec<DigestDmRow>, sqlx::Error> {
+    sqlx::query_as::<_, DigestDmRow>(
+        r#"
+        SELECT dm.channel_id,
+               string_agg(DISTINCT COALESCE(u.display_name, u.username), ', ') AS senders,
+               COUNT(*) AS message_count
+        FROM dm_members dm
+        INNER JOIN messages m ON m.channel_id = dm.channel_id
+        INNER JOIN users u ON u.id = m.author_id
+        LEFT JOIN read_states rs ON rs.channel_id = dm.channel_id AND rs.user_id = dm.user_id
+        WHERE dm.user_id = $1
+          AND m.author_id <> $1
+          AND m.created_at > $2
+          AND (rs.last_read_message_id IS NULL OR m.id > rs.last_read_message_id)
+        GROUP BY dm.channel_id
+        ORDER BY MAX(m.created_at) DESC
+        "#,
+    )
+    .bind(user_id)
+    .bind(since)
+    .fetch_all(pool)
+    .await
+}
+
+// ── Stage Instances ─────────────────────────────────────
+
+pub async fn get_stage_instance(
+    pool: &PgPool,
+    channel_id: Uuid,
+) -> Result<Option<StageInstance>, sqlx::Error> {
+    sqlx::query_as::<_, StageInstance>("SELECT * FROM stage_instances WHERE channel_id = $1")
+        .bind(channel_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Start a stage, or change the topic of one that is already live.
+pub async fn upsert_stage_instance(
+    pool: &PgPool,
+    channel_id: Uuid,
+    server_id: Uuid,
+    topic: &str,
+    started_by: Uuid,
+) -> Result<StageInstance, sqlx::Error> {
+    sqlx::query_as::<_, StageInstance>(
+        r#"
+        INSERT INTO stage_instances (channel_id, server_id, topic, started_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (channel_id) DO UPDATE SET topic = $3, updated_at = now()
+        RETURNING *
+        "#,
+    )
+    .bind(channel_id)
+    .bind(server_id)
+    .bind(topic)
+    .bind(started_by)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete_stage_instance(pool: &PgPool, channel_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM stage_instances WHERE channel_id = $1")
+        .bind(channel_id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+// ── Soundboard ──────────────────────────────────────────
+
+pub async fn create_soundboard_sound(
+    pool: &PgPool,
+    id: Uuid,
+    server_id: Uuid,
+    uploader_id: Uuid,
+    name: &str,
+    audio_url: &str,
+    duration_ms: i32,
+    emoji_name: Option<&str>,
+) -> Result<SoundboardSound, sqlx::Error> {
+    sqlx::query_as::<_, SoundboardSound>(
+        "INSERT INTO soundboard_sounds (id, server_id, uploader_id, name, audio_url, duration_ms, emoji_name)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         RETURNING *",
+    )
+    .bind(id)
+    .bind(server_id)
+    .bind(uploader_id)
+    .bind(name)
+    .bind(audio_url)
+    .bind(duration_ms)
+    .bind(emoji_name)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_soundboard_sounds(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Vec<SoundboardSound>, sqlx::Error> {
+    sqlx::query_as::<_, SoundboardSound>(
+        "SELECT * FROM soundboard_sounds WHERE server_id = $1 ORDER BY created_at",
+    )
+    .bind(server_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_soundboard_sound(
+    pool: &PgPool,
+    sound_id: Uuid,
+) -> Result<Option<SoundboardSound>, sqlx::Error> {
+    sqlx::query_as::<_, SoundboardSound>("SELECT * FROM soundboard_sounds WHERE id = $1")
+        .bind(sound_id)
+        .fetch_optional(pool)
+        .await
+}
 
 pub async fn delete_soundboard_sound(
     pool: &PgPool,
@@ -2279,7 +3776,8 @@ pub async fn get_bookmarks_for_user(
         SELECT
             mb.message_id, mb.tags, mb.note, mb.created_at AS bookmarked_at,
             m.id, m.instance_id, m.channel_id, m.author_id, m.content,
-            m.reply_to_id, m.edited_at, m.pinned, m.created_at AS msg_created_at,
+            m.reply_to_id, m.edited_at, m.pinned, m.mentions, m.mention_roles,
+            m.mention_everyone, m.silent, m.expires_at, m.created_at AS msg_created_at,
             c.name AS channel_name, c.server_id,
             s.name AS server_name,
             u.id AS author_uid, u.username AS author_username,
@@ -2341,6 +3839,11 @@ pub async fn get_bookmarks_for_user(
                 reply_to_id: row.get("reply_to_id"),
                 edited_at: row.get("edited_at"),
                 pinned: row.get("pinned"),
+                mentions: row.get("mentions"),
+                mention_roles: row.get("mention_roles"),
+                mention_everyone: row.get("mention_everyone"),
+                silent: row.get("silent"),
+                expires_at: row.get("expires_at"),
                 created_at: row.get("msg_created_at"),
             },
             author,
@@ -2979,3 +4482,623 @@ pub async fn get_push_subscriptions_for_users(
     .fetch_all(pool)
     .await
 }
+
+// ── AutoMod ───────────────────────────────────────────
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_automod_rule(
+    pool: &PgPool,
+    id: Uuid,
+    server_id: Uuid,
+    creator_id: Uuid,
+    name: &str,
+    enabled: bool,
+    trigger_type: AutoModTriggerType,
+    trigger_metadata: &AutoModTriggerMetadata,
+    actions: &[AutoModAction],
+    exempt_role_ids: &[Uuid],
+    exempt_channel_ids: &[Uuid],
+) -> Result<AutoModRule, sqlx::Error> {
+    sqlx::query_as::<_, AutoModRule>(
+        r#"
+        INSERT INTO automod_rules
+            (id, server_id, creator_id, name, enabled, trigger_type, trigger_metadata,
+             actions, exempt_role_ids, exempt_channel_ids)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(server_id)
+    .bind(creator_id)
+    .bind(name)
+    .bind(enabled)
+    .bind(trigger_type)
+    .bind(sqlx::types::Json(trigger_metadata))
+    .bind(sqlx::types::Json(actions))
+    .bind(exempt_role_ids)
+    .bind(exempt_channel_ids)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_automod_rule(
+    pool: &PgPool,
+    id: Uuid,
+) -> Result<Option<AutoModRule>, sqlx::Error> {
+    sqlx::query_as::<_, AutoModRule>("SELECT * FROM automod_rules WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn get_server_automod_rules(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Vec<AutoModRule>, sqlx::Error> {
+    sqlx::query_as::<_, AutoModRule>(
+        "SELECT * FROM automod_rules WHERE server_id = $1 ORDER BY created_at",
+    )
+    .bind(server_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn get_enabled_automod_rules(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Vec<AutoModRule>, sqlx::Error> {
+    sqlx::query_as::<_, AutoModRule>(
+        "SELECT * FROM automod_rules WHERE server_id = $1 AND enabled ORDER BY created_at",
+    )
+    .bind(server_id)
+    .fetch_all(pool)
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn update_automod_rule(
+    pool: &PgPool,
+    id: Uuid,
+    name: Option<&str>,
+    enabled: Option<bool>,
+    trigger_metadata: Option<&AutoModTriggerMetadata>,
+    actions: Option<&[AutoModAction]>,
+    exempt_role_ids: Option<&[Uuid]>,
+    exempt_channel_ids: Option<&[Uuid]>,
+) -> Result<AutoModRule, sqlx::Error> {
+    sqlx::query_as::<_, AutoModRule>(
+        r#"
+        UPDATE automod_rules SET
+            name = COALESCE($2, name),
+            enabled = COALESCE($3, enabled),
+            trigger_metadata = COALESCE($4, trigger_metadata),
+            actions = COALESCE($5, actions),
+            exempt_role_ids = COALESCE($6, exempt_role_ids),
+            exempt_channel_ids = COALESCE($7, exempt_channel_ids),
+            updated_at = now()
+        WHERE id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(name)
+    .bind(enabled)
+    .bind(trigger_metadata.map(sqlx::types::Json))
+    .bind(actions.map(sqlx::types::Json))
+    .bind(exempt_role_ids)
+    .bind(exempt_channel_ids)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn delete_automod_rule(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM automod_rules WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+// ── Reports ───────────────────────────────────────────
+
+#[allow(clippy::too_many_arguments)]
+pub async fn create_report(
+    pool: &PgPool,
+    id: Uuid,
+    server_id: Option<Uuid>,
+    reporter_id: Uuid,
+    target_user_id: Option<Uuid>,
+    channel_id: Option<Uuid>,
+    message_id: Option<Uuid>,
+    message_content: Option<&str>,
+    category: ReportCategory,
+    reason: Option<&str>,
+) -> Result<Report, sqlx::Error> {
+    sqlx::query_as::<_, Report>(
+        r#"
+        INSERT INTO reports
+            (id, server_id, reporter_id, target_user_id, channel_id, message_id,
+             message_content, category, reason)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(server_id)
+    .bind(reporter_id)
+    .bind(target_user_id)
+    .bind(channel_id)
+    .bind(message_id)
+    .bind(message_content)
+    .bind(category)
+    .bind(reason)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_report(pool: &PgPool, id: Uuid) -> Result<Option<Report>, sqlx::Error> {
+    sqlx::query_as::<_, Report>("SELECT * FROM reports WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Whether the reporter already has an unhandled report on this message/user.
+pub async fn has_open_report(
+    pool: &PgPool,
+    reporter_id: Uuid,
+    message_id: Option<Uuid>,
+    target_user_id: Option<Uuid>,
+) -> Result<bool, sqlx::Error> {
+    let row: (bool,) = sqlx::query_as(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM reports
+            WHERE reporter_id = $1
+              AND status IN ('open', 'claimed')
+              AND message_id IS NOT DISTINCT FROM $2
+              AND target_user_id IS NOT DISTINCT FROM $3
+        )
+        "#,
+    )
+    .bind(reporter_id)
+    .bind(message_id)
+    .bind(target_user_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(row.0)
+}
+
+pub async fn list_server_reports(
+    pool: &PgPool,
+    server_id: Uuid,
+    status: Option<ReportStatus>,
+    limit: i64,
+) -> Result<Vec<Report>, sqlx::Error> {
+    sqlx::query_as::<_, Report>(
+        r#"
+        SELECT * FROM reports
+        WHERE server_id = $1 AND ($2::report_status IS NULL OR status = $2)
+        ORDER BY created_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(server_id)
+    .bind(status)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Reports for instance admins: those outside any server plus escalations.
+pub async fn list_instance_reports(
+    pool: &PgPool,
+    status: Option<ReportStatus>,
+    limit: i64,
+) -> Result<Vec<Report>, sqlx::Error> {
+    sqlx::query_as::<_, Report>(
+        r#"
+        SELECT * FROM reports
+        WHERE (server_id IS NULL OR escalated)
+          AND ($1::report_status IS NULL OR status = $1)
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(status)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Assign an unhandled report to `assignee_id`. Returns None once it is closed.
+pub async fn claim_report(
+    pool: &PgPool,
+    id: Uuid,
+    assignee_id: Uuid,
+) -> Result<Option<Report>, sqlx::Error> {
+    sqlx::query_as::<_, Report>(
+        r#"
+        UPDATE reports SET status = 'claimed', assignee_id = $2, updated_at = now()
+        WHERE id = $1 AND status IN ('open', 'claimed')
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(assignee_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Resolve or dismiss an unhandled report. Returns None if it was already closed.
+pub async fn close_report(
+    pool: &PgPool,
+    id: Uuid,
+    status: ReportStatus,
+    moderator_id: Uuid,
+    note: Option<&str>,
+    action_taken: Option<&str>,
+) -> Result<Option<Report>, sqlx::Error> {
+    sqlx::query_as::<_, Report>(
+        r#"
+        UPDATE reports SET
+            status = $2,
+            assignee_id = $3,
+            resolution_note = $4,
+            action_taken = $5,
+            updated_at = now(),
+            closed_at = now()
+        WHERE id = $1 AND status IN ('open', 'claimed')
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(moderator_id)
+    .bind(note)
+    .bind(action_taken)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn escalate_report(pool: &PgPool, id: Uuid) -> Result<Option<Report>, sqlx::Error> {
+    sqlx::query_as::<_, Report>(
+        r#"
+        UPDATE reports SET escalated = true, updated_at = now()
+        WHERE id = $1 AND status IN ('open', 'claimed')
+        RETURNING *
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+// ── Server Safety ─────────────────────────────────────
+
+pub async fn get_server_safety_settings(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Option<ServerSafetySettings>, sqlx::Error> {
+    sqlx::query_as::<_, ServerSafetySettings>(
+        "SELECT * FROM server_safety_settings WHERE server_id = $1",
+    )
+    .bind(server_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn ensure_server_safety_settings(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<ServerSafetySettings, sqlx::Error> {
+    sqlx::query_as::<_, ServerSafetySettings>(
+        r#"
+        INSERT INTO server_safety_settings (server_id) VALUES ($1)
+        ON CONFLICT (server_id) DO UPDATE SET server_id = EXCLUDED.server_id
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .fetch_one(pool)
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn update_server_safety_settings(
+    pool: &PgPool,
+    server_id: Uuid,
+    verification_level: Option<VerificationLevel>,
+    raid_protection: Option<bool>,
+    raid_join_threshold: Option<i32>,
+    raid_invite_threshold: Option<i32>,
+    raid_window_secs: Option<i32>,
+    raid_pause_invites: Option<bool>,
+    raid_quarantine_joins: Option<bool>,
+    raid_lockdown_secs: Option<i32>,
+) -> Result<ServerSafetySettings, sqlx::Error> {
+    ensure_server_safety_settings(pool, server_id).await?;
+    sqlx::query_as::<_, ServerSafetySettings>(
+        r#"
+        UPDATE server_safety_settings SET
+            verification_level = COALESCE($2, verification_level),
+            raid_protection = COALESCE($3, raid_protection),
+            raid_join_threshold = COALESCE($4, raid_join_threshold),
+            raid_invite_threshold = COALESCE($5, raid_invite_threshold),
+            raid_window_secs = COALESCE($6, raid_window_secs),
+            raid_pause_invites = COALESCE($7, raid_pause_invites),
+            raid_quarantine_joins = COALESCE($8, raid_quarantine_joins),
+            raid_lockdown_secs = COALESCE($9, raid_lockdown_secs),
+            updated_at = now()
+        WHERE server_id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .bind(verification_level)
+    .bind(raid_protection)
+    .bind(raid_join_threshold)
+    .bind(raid_invite_threshold)
+    .bind(raid_window_secs)
+    .bind(raid_pause_invites)
+    .bind(raid_quarantine_joins)
+    .bind(raid_lockdown_secs)
+    .fetch_one(pool)
+    .await
+}
+
+/// Start or lift a lockdown. Pass `since: None` to lift it.
+pub async fn set_server_lockdown(
+    pool: &PgPool,
+    server_id: Uuid,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    pause_invites: bool,
+    quarantine: bool,
+) -> Result<ServerSafetySettings, sqlx::Error> {
+    sqlx::query_as::<_, ServerSafetySettings>(
+        r#"
+        INSERT INTO server_safety_settings
+            (server_id, lockdown_since, lockdown_until, lockdown_pause_invites, lockdown_quarantine)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (server_id) DO UPDATE SET
+            lockdown_since = $2,
+            lockdown_until = $3,
+            lockdown_pause_invites = $4,
+            lockdown_quarantine = $5,
+            updated_at = now()
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .bind(since)
+    .bind(until)
+    .bind(pause_invites)
+    .bind(quarantine)
+    .fetch_one(pool)
+    .await
+}
+
+/// Start a lockdown for a detected raid unless one is already in effect.
+/// Returns None when another lockdown got there first.
+pub async fn begin_raid_lockdown(
+    pool: &PgPool,
+    server_id: Uuid,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> Result<Option<ServerSafetySettings>, sqlx::Error> {
+    sqlx::query_as::<_, ServerSafetySettings>(
+        r#"
+        UPDATE server_safety_settings SET
+            lockdown_since = $2,
+            lockdown_until = $3,
+            lockdown_pause_invites = raid_pause_invites,
+            lockdown_quarantine = raid_quarantine_joins,
+            updated_at = now()
+        WHERE server_id = $1
+          AND (lockdown_since IS NULL OR lockdown_until <= now())
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .bind(since)
+    .bind(until)
+    .fetch_optional(pool)
+    .await
+}
+
+// ── Member Screening ──────────────────────────────────
+
+pub async fn get_server_screening(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Option<ServerScreening>, sqlx::Error> {
+    sqlx::query_as::<_, ServerScreening>("SELECT * FROM server_screening WHERE server_id = $1")
+        .bind(server_id)
+        .fetch_optional(pool)
+        .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn upsert_server_screening(
+    pool: &PgPool,
+    server_id: Uuid,
+    enabled: bool,
+    description: Option<&str>,
+    rules: &[String],
+    questions: &[String],
+    rules_channel_id: Option<Uuid>,
+    accept_role_id: Option<Uuid>,
+) -> Result<ServerScreening, sqlx::Error> {
+    sqlx::query_as::<_, ServerScreening>(
+        r#"
+        INSERT INTO server_screening
+            (server_id, enabled, description, rules, questions, rules_channel_id, accept_role_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (server_id) DO UPDATE SET
+            enabled = $2,
+            description = $3,
+            rules = $4,
+            questions = $5,
+            rules_channel_id = $6,
+            accept_role_id = $7,
+            updated_at = now()
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .bind(enabled)
+    .bind(description)
+    .bind(sqlx::types::Json(rules))
+    .bind(sqlx::types::Json(questions))
+    .bind(rules_channel_id)
+    .bind(accept_role_id)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn is_member_pending(
+    pool: &PgPool,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let row: Option<(bool,)> = sqlx::query_as(
+        "SELECT pending FROM server_members WHERE server_id = $1 AND user_id = $2",
+    )
+    .bind(server_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.is_some_and(|r| r.0))
+}
+
+/// Mark a pending member as having passed screening. Returns None if they
+/// weren't pending.
+pub async fn accept_member_screening(
+    pool: &PgPool,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<ServerMember>, sqlx::Error> {
+    sqlx::query_as::<_, ServerMember>(
+        r#"
+        UPDATE server_members SET pending = false
+        WHERE server_id = $1 AND user_id = $2 AND pending
+        RETURNING server_id, user_id, nickname, joined_at, communication_disabled_until, pending
+        "#,
+    )
+    .bind(server_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Let every pending member in, e.g. when screening is turned off.
+pub async fn clear_pending_members(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Vec<ServerMember>, sqlx::Error> {
+    sqlx::query_as::<_, ServerMember>(
+        r#"
+        UPDATE server_members SET pending = false
+        WHERE server_id = $1 AND pending
+        RETURNING server_id, user_id, nickname, joined_at, communication_disabled_until, pending
+        "#,
+    )
+    .bind(server_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn create_screening_response(
+    pool: &PgPool,
+    server_id: Uuid,
+    user_id: Uuid,
+    answers: &[QuestionnaireAnswer],
+) -> Result<ScreeningResponse, sqlx::Error> {
+    sqlx::query_as::<_, ScreeningResponse>(
+        r#"
+        INSERT INTO screening_responses (server_id, user_id, answers)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (server_id, user_id) DO UPDATE SET answers = $3, accepted_at = now()
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .bind(user_id)
+    .bind(sqlx::types::Json(answers))
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn get_screening_responses(
+    pool: &PgPool,
+    server_id: Uuid,
+    limit: i64,
+) -> Result<Vec<ScreeningResponse>, sqlx::Error> {
+    sqlx::query_as::<_, ScreeningResponse>(
+        r#"
+        SELECT * FROM screening_responses
+        WHERE server_id = $1
+        ORDER BY accepted_at DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(server_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+// ── Mod Log ───────────────────────────────────────────
+
+pub async fn get_server_log_settings(
+    pool: &PgPool,
+    server_id: Uuid,
+) -> Result<Option<ServerLogSettings>, sqlx::Error> {
+    sqlx::query_as::<_, ServerLogSettings>(
+        "SELECT * FROM server_log_settings WHERE server_id = $1",
+    )
+    .bind(server_id)
+    .fetch_optional(pool)
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn upsert_server_log_settings(
+    pool: &PgPool,
+    server_id: Uuid,
+    channel_id: Option<Uuid>,
+    log_moderation: bool,
+    log_member_joins: bool,
+    log_member_leaves: bool,
+    log_message_edits: bool,
+    log_message_deletes: bool,
+) -> Result<ServerLogSettings, sqlx::Error> {
+    sqlx::query_as::<_, ServerLogSettings>(
+        r#"
+        INSERT INTO server_log_settings
+            (server_id, channel_id, log_moderation, log_member_joins, log_member_leaves,
+             log_message_edits, log_message_deletes)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (server_id) DO UPDATE SET
+            channel_id = $2,
+            log_moderation = $3,
+            log_member_joins = $4,
+            log_member_leaves = $5,
+            log_message_edits = $6,
+            log_message_deletes = $7,
+            updated_at = now()
+        RETURNING *
+        "#,
+    )
+    .bind(server_id)
+    .bind(channel_id)
+    .bind(log_moderation)
+    .bind(log_member_joins)
+    .bind(log_member_leaves)
+    .bind(log_message_edits)
+    .bind(log_message_deletes)
+    .fetch_one(pool)
+    .await
+}
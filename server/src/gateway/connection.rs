@@ -129,6 +129,7 @@ pub async fn handle_connection(state: AppState, socket: WebSocket) {
     sender_task.abort();
 
     if let Some(uid) = user_id {
+        let _ = queries::touch_user_last_seen(&state.db, uid).await;
         tracing::info!(user_id = %uid, session_id = %session_id, "Client disconnected");
     }
 }
@@ -179,6 +180,7 @@ async fn handle_identify(
 
     // Set user as online and cache their server list for presence broadcasts
     state.gateway.set_online(uid, &server_ids);
+    let _ = queries::touch_user_last_seen(&state.db, uid).await;
 
     tracing::info!(user_id = %uid, session_id = %session_id, servers = server_ids.len(), "Client identified");
 
@@ -3,6 +3,7 @@ pub mod connection;
 use std::collections::HashSet;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -22,6 +23,10 @@ pub struct VoiceState {
     pub audio_sharing: bool,
     /// For DM voice calls (server_id=None): the member user IDs to dispatch events to
     pub dm_member_ids: Option<Vec<Uuid>>,
+    /// Stage channels: listening in the audience rather than speaking
+    pub suppress: bool,
+    /// Stage channels: when the listener asked to speak, if they have
+    pub request_to_speak_at: Option<DateTime<Utc>>,
 }
 
 /// In-memory presence for a connected user
@@ -240,6 +245,8 @@ impl GatewayState {
 
     /// Join a voice channel. Returns the previous channel_id if the user was already in one.
     /// For DM voice calls, pass `server_id: None` and `dm_member_ids: Some(vec![...])`.
+    /// Stage listeners join with `suppress` set.
+    #[allow(clippy::too_many_arguments)]
     pub fn voice_join(
         &self,
         user_id: Uuid,
@@ -248,6 +255,7 @@ impl GatewayState {
         self_mute: bool,
         self_deaf: bool,
         dm_member_ids: Option<Vec<Uuid>>,
+        suppress: bool,
     ) -> Option<Uuid> {
         // Remove from previous voice channel if any
         let prev_channel = self.voice_leave(user_id);
@@ -261,6 +269,8 @@ impl GatewayState {
             self_deaf,
             audio_sharing: false,
             dm_member_ids: dm_member_ids.clone(),
+            suppress,
+            request_to_speak_at: None,
         });
         self.voice_channels
             .entry(channel_id)
@@ -275,6 +285,8 @@ impl GatewayState {
             self_mute,
             self_deaf,
             audio_sharing: false,
+            suppress,
+            request_to_speak_at: None,
         };
         if let Some(sid) = server_id {
             self.broadcast_to_server(sid, "VOICE_STATE_UPDATE", &event, None);
@@ -306,6 +318,8 @@ impl GatewayState {
                 self_mute: false,
                 self_deaf: false,
                 audio_sharing: false,
+                suppress: false,
+                request_to_speak_at: None,
             };
             if let Some(sid) = state.server_id {
                 self.broadcast_to_server(sid, "VOICE_STATE_UPDATE", &event, None);
@@ -335,6 +349,8 @@ impl GatewayState {
                 self_mute,
                 self_deaf,
                 audio_sharing,
+                suppress: state.suppress,
+                request_to_speak_at: state.request_to_speak_at,
             };
             if let Some(sid) = state.server_id {
                 self.broadcast_to_server(sid, "VOICE_STATE_UPDATE", &event, None);
@@ -349,6 +365,44 @@ impl GatewayState {
         }
     }
 
+    /// Move a user between a stage's speakers and its audience, and set or
+    /// clear their request to speak. Returns false if they are not in the
+    /// given channel.
+    pub fn voice_update_stage(
+        &self,
+        user_id: Uuid,
+        channel_id: Uuid,
+        suppress: bool,
+        request_to_speak_at: Option<DateTime<Utc>>,
+    ) -> bool {
+        let Some(mut state) = self
+            .voice_states
+            .get_mut(&user_id)
+            .filter(|s| s.channel_id == channel_id)
+        else {
+            return false;
+        };
+        state.suppress = suppress;
+        state.request_to_speak_at = request_to_speak_at;
+
+        let event = VoiceStateUpdateEvent {
+            server_id: state.server_id,
+            channel_id: Some(channel_id),
+            user_id,
+            self_mute: state.self_mute,
+            self_deaf: state.self_deaf,
+            audio_sharing: state.audio_sharing,
+            suppress,
+            request_to_speak_at,
+        };
+        let server_id = state.server_id;
+        drop(state);
+        if let Some(sid) = server_id {
+            self.broadcast_to_server(sid, "VOICE_STATE_UPDATE", &event, None);
+        }
+        true
+    }
+
     /// Get all voice states for a specific channel
     pub fn voice_channel_users(&self, channel_id: Uuid) -> Vec<VoiceState> {
         let user_ids: Vec<Uuid> = self.voice_channels
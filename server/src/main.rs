@@ -91,6 +91,24 @@ async fn main() -> anyhow::Result<()> {
         }
     };
 
+    // Initialize email delivery (optional)
+    let mailer = match config.email {
+        Some(ref ec) => match services::email::Mailer::new(ec, &config.instance) {
+            Ok(mailer) => {
+                tracing::info!(transport = ?ec.transport, "Email enabled");
+                Some(Arc::new(mailer))
+            }
+            Err(e) => {
+                tracing::error!("Failed to initialize email: {e}");
+                None
+            }
+        },
+        None => {
+            tracing::warn!("Email not configured — password reset and verification disabled");
+            None
+        }
+    };
+
     // Build application state
     let state = AppState {
         db,
@@ -99,6 +117,7 @@ async fn main() -> anyhow::Result<()> {
         gateway: Arc::new(GatewayState::new()),
         s3,
         push,
+        mailer,
         started_at: std::time::Instant::now(),
         log_sender: Some(log_sender),
     };
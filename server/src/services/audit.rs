@@ -0,0 +1,178 @@
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use uuid::Uuid;
+
+use crate::db::queries;
+use crate::services::mod_log;
+use crate::state::AppState;
+use crate::types::entities::{AuditAction, AuditLogEntryWithUser};
+
+/// Most entries a single export returns
+pub const MAX_EXPORT_ENTRIES: i64 = 10_000;
+
+pub const CSV_HEADER: &str = "id,created_at,action,user_id,username,target_id,reason,changes\n";
+
+/// Fields that change on every write and would only add noise to a diff
+const IGNORED_FIELDS: &[&str] = &["updated_at"];
+
+// ── Recording ─────────────────────────────────────────
+
+/// Write an audit log entry and mirror it to the server's mod log channel.
+/// Failures are logged rather than returned so auditing never fails the
+/// action being audited.
+pub async fn record(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+    action: AuditAction,
+    target_id: Option<Uuid>,
+    reason: Option<&str>,
+    changes: Option<Value>,
+) {
+    let entry = queries::create_audit_log(
+        &state.db,
+        server_id,
+        user_id,
+        action,
+        target_id,
+        reason,
+        changes,
+    )
+    .await;
+    match entry {
+        Ok(entry) => mod_log::audit_entry(state, &entry),
+        Err(e) => tracing::error!(server_id = %server_id, error = ?e, "Failed to write audit log"),
+    }
+}
+
+/// The action as stored and serialized, e.g. "member_ban".
+pub fn action_name(action: AuditAction) -> String {
+    serde_json::to_value(action)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+// ── Diffs ─────────────────────────────────────────────
+
+/// Before/after diff of two versions of a record, with one
+/// `{"old": .., "new": ..}` pair per top-level field that changed. A
+/// missing record (`None`) diffs as if every field were null.
+pub fn diff(old: &impl Serialize, new: &impl Serialize) -> Value {
+    let old = fields(old);
+    let new = fields(new);
+
+    let mut changes = Map::new();
+    for key in old.keys().chain(new.keys().filter(|k| !old.contains_key(*k))) {
+        if IGNORED_FIELDS.contains(&key.as_str()) {
+            continue;
+        }
+        let before = old.get(key).unwrap_or(&Value::Null);
+        let after = new.get(key).unwrap_or(&Value::Null);
+        if before != after {
+            changes.insert(key.clone(), json!({ "old": before, "new": after }));
+        }
+    }
+    Value::Object(changes)
+}
+
+fn fields(record: &impl Serialize) -> Map<String, Value> {
+    match serde_json::to_value(record) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+/// Diff for an update that touches a single value.
+pub fn change<T: Serialize>(field: &str, old: T, new: T) -> Value {
+    json!({ field: { "old": old, "new": new } })
+}
+
+// ── Export ────────────────────────────────────────────
+
+/// Quote a CSV field when needed. Values that a spreadsheet would run as a
+/// formula get a leading apostrophe, since reasons are free text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+pub fn csv_row(item: &AuditLogEntryWithUser) -> String {
+    let entry = &item.entry;
+    let fields = [
+        entry.id.to_string(),
+        entry.created_at.to_rfc3339(),
+        action_name(entry.action),
+        entry.user_id.to_string(),
+        item.user.username.clone(),
+        entry.target_id.map(|id| id.to_string()).unwrap_or_default(),
+        entry.reason.clone().unwrap_or_default(),
+        entry.changes.as_ref().map(Value::to_string).unwrap_or_default(),
+    ];
+    let mut row = fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(",");
+    row.push('\n');
+    row
+}
+
+pub fn jsonl_row(item: &AuditLogEntryWithUser) -> String {
+    let mut row = serde_json::to_string(item).unwrap_or_default();
+    row.push('\n');
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Record {
+        name: &'static str,
+        topic: Option<&'static str>,
+        position: i32,
+        updated_at: i64,
+    }
+
+    #[test]
+    fn diff_keeps_only_changed_fields() {
+        let old = Record {
+            name: "general",
+            topic: None,
+            position: 0,
+            updated_at: 1,
+        };
+        let new = Record {
+            name: "chat",
+            topic: Some("hi"),
+            position: 0,
+            updated_at: 2,
+        };
+        assert_eq!(
+            diff(&old, &new),
+            json!({
+                "name": { "old": "general", "new": "chat" },
+                "topic": { "old": null, "new": "hi" },
+            })
+        );
+        assert_eq!(diff(&old, &old), json!({}));
+        assert_eq!(diff(&None::<Record>, &old)["name"], json!({ "old": null, "new": "general" }));
+        assert_eq!(change("allow", 1, 3), json!({ "allow": { "old": 1, "new": 3 } }));
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_and_defused() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("=HYPERLINK(1)"), "'=HYPERLINK(1)");
+        assert_eq!(csv_field("-1,2"), "\"'-1,2\"");
+    }
+}
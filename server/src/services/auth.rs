@@ -11,7 +11,11 @@ use uuid::Uuid;
 use crate::config::AppConfig;
 use crate::db::queries;
 use crate::error::ApiError;
-use crate::types::entities::{AuthResponse, PublicUser, TokenResponse};
+use crate::services::email::{EmailTemplate, Mailer};
+use crate::types::entities::{
+    AuthResponse, PublicUser, QuestionnaireAnswer, RegisterRequest, RegistrationMode,
+    RegistrationRequest, RegistrationRequestStatus, TokenResponse, User,
+};
 
 /// Basic email format validation without pulling in a heavy crate.
 fn is_valid_email(email: &str) -> bool {
@@ -40,23 +44,164 @@ pub struct JwtClaims {
     pub exp: i64,
 }
 
-/// Returns (AuthResponse, Option<server_id>) — server_id is set when a server invite
-/// was used as the registration code and the user was auto-joined to that server.
-pub async fn register(
+/// What a code supplied at registration turned out to be.
+enum InviteCode<'a> {
+    Registration(&'a str),
+    Server { code: &'a str, server_id: Uuid },
+}
+
+/// Check a registration code (or, failing that, a server invite) is usable.
+async fn validate_invite_code<'a>(pool: &PgPool, code_str: &'a str) -> Result<InviteCode<'a>, ApiError> {
+    // Check registration_codes table first
+    if let Some(reg_code) = queries::get_registration_code_by_code(pool, code_str).await? {
+        if let Some(expires_at) = reg_code.expires_at
+            && Utc::now() > expires_at
+        {
+            return Err(ApiError::InvalidInput("Registration code has expired".into()));
+        }
+        if let Some(max_uses) = reg_code.max_uses
+            && reg_code.uses >= max_uses
+        {
+            return Err(ApiError::InvalidInput("Registration code has reached its maximum uses".into()));
+        }
+        Ok(InviteCode::Registration(code_str))
+    // Fall back to server invites table
+    } else if let Some(invite) = queries::get_invite_by_code(pool, code_str).await? {
+        if let Some(expires_at) = invite.expires_at
+            && Utc::now() > expires_at
+        {
+            return Err(ApiError::InvalidInput("Invite has expired".into()));
+        }
+        if let Some(max_uses) = invite.max_uses
+            && invite.uses >= max_uses
+        {
+            return Err(ApiError::InvalidInput("Invite has reached its maximum uses".into()));
+        }
+        crate::services::safety::ensure_invites_open(pool, invite.server_id).await?;
+        Ok(InviteCode::Server {
+            code: code_str,
+            server_id: invite.server_id,
+        })
+    } else {
+        Err(ApiError::InvalidInput("Invalid invite code".into()))
+    }
+}
+
+/// Pair each questionnaire question with its answer, requiring every question
+/// to be answered. Shared by the sign-up questionnaire and member screening.
+pub(crate) fn collect_answers(
+    questions: &[String],
+    answers: &[String],
+) -> Result<Vec<QuestionnaireAnswer>, ApiError> {
+    if answers.len() != questions.len() {
+        return Err(ApiError::InvalidInput(format!(
+            "Please answer all {} questions",
+            questions.len()
+        )));
+    }
+    questions
+        .iter()
+        .zip(answers)
+        .map(|(question, answer)| {
+            let answer = answer.trim();
+            if answer.is_empty() || answer.len() > 1000 {
+                return Err(ApiError::InvalidInput(
+                    "Answers must be 1-1000 characters".into(),
+                ));
+            }
+            Ok(QuestionnaireAnswer {
+                question: question.clone(),
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+async fn ensure_identity_available(
+    pool: &PgPool,
+    username: &str,
+    email: &str,
+) -> Result<(), ApiError> {
+    // Check if email already exists
+    if queries::get_user_by_email(pool, email).await?.is_some() {
+        return Err(ApiError::InvalidInput(
+            "Email already registered".into(),
+        ));
+    }
+
+    // Check if username already exists
+    if queries::get_user_by_username(pool, username).await?.is_some() {
+        return Err(ApiError::InvalidInput(
+            "Username already taken".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Create the user row. The first user on the instance becomes an admin with a
+/// trusted address; everyone else is sent a verification link.
+async fn create_account(
     pool: &PgPool,
     config: &AppConfig,
+    mailer: Option<&Mailer>,
     username: &str,
     email: &str,
-    password: &str,
-    invite_code: Option<&str>,
-) -> Result<(AuthResponse, Option<Uuid>), ApiError> {
+    password_hash: &str,
+    is_first_user: bool,
+) -> Result<User, ApiError> {
+    // Get local instance
+    let instance_id = queries::ensure_local_instance(pool, &config.instance.domain).await?;
+
+    // Create user
+    let user_id = Uuid::now_v7();
+    let user = queries::create_user(pool, user_id, instance_id, username, email, password_hash)
+        .await?;
+
+    if is_first_user {
+        queries::set_user_admin(pool, user.id, true).await?;
+        queries::set_user_email_verified(pool, user.id, true).await?;
+    } else if let Some(mailer) = mailer {
+        // Failures are logged so registration still succeeds
+        if let Err(e) = send_verification_token(pool, config, mailer, user.id, email).await {
+            tracing::error!(error = %e, "Failed to send verification email");
+        }
+    }
+
+    // Re-fetch user to get updated is_admin flag
+    let user = queries::get_user_by_id(pool, user.id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    Ok(user)
+}
+
+pub enum RegisterOutcome {
+    /// `joined_server_id` is set when a server invite was used as the
+    /// registration code and the user was auto-joined to that server.
+    Registered {
+        auth: AuthResponse,
+        joined_server_id: Option<Uuid>,
+    },
+    /// Approval mode: the sign-up is queued until an admin reviews it.
+    PendingApproval(RegistrationRequest),
+}
+
+pub async fn register(
+    pool: &PgPool,
+    config: &AppConfig,
+    mailer: Option<&Mailer>,
+    req: &RegisterRequest,
+) -> Result<RegisterOutcome, ApiError> {
+    let username = req.username.as_str();
+    let email = req.email.as_str();
+
     // Validate input
     if username.len() < 2 || username.len() > 32 {
         return Err(ApiError::InvalidInput(
             "Username must be 2-32 characters".into(),
         ));
     }
-    if password.len() < 8 {
+    if req.password.len() < 8 {
         return Err(ApiError::InvalidInput(
             "Password must be at least 8 characters".into(),
         ));
@@ -67,63 +212,46 @@ pub async fn register(
         ));
     }
 
-    // Check if this is the first user (bootstrap: no invite code needed)
+    // Check if this is the first user (bootstrap: no invite code or questionnaire needed)
     let user_count = queries::count_users(pool).await?;
     let is_first_user = user_count == 0;
 
-    // Track whether the code is a server invite (for auto-join after user creation)
-    let mut server_invite_id: Option<Uuid> = None;
+    let settings = queries::get_instance_settings(pool).await?;
+    let invite_code = req.invite_code.as_deref().filter(|c| !c.is_empty());
 
-    // Validate invite code (required unless first user)
+    let mut code: Option<InviteCode> = None;
     if !is_first_user {
-        let code_str = invite_code.unwrap_or("");
-        if code_str.is_empty() {
-            return Err(ApiError::InvalidInput(
-                "Registration requires an invite code".into(),
-            ));
-        }
-
-        // Check registration_codes table first
-        if let Some(reg_code) = queries::get_registration_code_by_code(pool, code_str).await? {
-            if let Some(expires_at) = reg_code.expires_at {
-                if Utc::now() > expires_at {
-                    return Err(ApiError::InvalidInput("Registration code has expired".into()));
-                }
+        match settings.registration_mode {
+            RegistrationMode::Closed => {
+                return Err(ApiError::InvalidInput(
+                    "Registration is currently closed".into(),
+                ));
             }
-            if let Some(max_uses) = reg_code.max_uses {
-                if reg_code.uses >= max_uses {
-                    return Err(ApiError::InvalidInput("Registration code has reached its maximum uses".into()));
-                }
+            RegistrationMode::InviteOnly => {
+                let code_str = invite_code.ok_or(ApiError::InvalidInput(
+                    "Registration requires an invite code".into(),
+                ))?;
+                code = Some(validate_invite_code(pool, code_str).await?);
             }
-        // Fall back to server invites table
-        } else if let Some(invite) = queries::get_invite_by_code(pool, code_str).await? {
-            if let Some(expires_at) = invite.expires_at {
-                if Utc::now() > expires_at {
-                    return Err(ApiError::InvalidInput("Invite has expired".into()));
+            // Codes are optional here; in approval mode a valid one skips the queue
+            RegistrationMode::Open | RegistrationMode::Approval => {
+                if let Some(code_str) = invite_code {
+                    code = Some(validate_invite_code(pool, code_str).await?);
                 }
             }
-            if let Some(max_uses) = invite.max_uses {
-                if invite.uses >= max_uses {
-                    return Err(ApiError::InvalidInput("Invite has reached its maximum uses".into()));
-                }
-            }
-            server_invite_id = Some(invite.server_id);
-        } else {
-            return Err(ApiError::InvalidInput("Invalid invite code".into()));
         }
     }
 
-    // Check if email already exists
-    if queries::get_user_by_email(pool, email).await?.is_some() {
-        return Err(ApiError::InvalidInput(
-            "Email already registered".into(),
-        ));
-    }
+    let answers = if is_first_user {
+        Vec::new()
+    } else {
+        collect_answers(&settings.registration_questions, &req.answers)?
+    };
 
-    // Check if username already exists
-    if queries::get_user_by_username(pool, username).await?.is_some() {
+    ensure_identity_available(pool, username, email).await?;
+    if queries::registration_request_pending_for(pool, email, username).await? {
         return Err(ApiError::InvalidInput(
-            "Username already taken".into(),
+            "A sign-up with this email or username is awaiting approval".into(),
         ));
     }
 
@@ -131,44 +259,185 @@ pub async fn register(
     let salt = SaltString::generate(&mut OsRng);
     let argon2 = Argon2::default();
     let password_hash = argon2
-        .hash_password(password.as_bytes(), &salt)
+        .hash_password(req.password.as_bytes(), &salt)
         .map_err(|e| anyhow::anyhow!("Failed to hash password: {}", e))?
         .to_string();
 
-    // Get local instance
-    let instance_id = queries::ensure_local_instance(pool, &config.instance.domain).await?;
+    if !is_first_user && settings.registration_mode == RegistrationMode::Approval && code.is_none() {
+        let request = queries::create_registration_request(
+            pool,
+            Uuid::now_v7(),
+            username,
+            email,
+            Some(&password_hash),
+            &answers,
+            RegistrationRequestStatus::Pending,
+            None,
+        )
+        .await?;
+        tracing::info!(request_id = %request.id, username = %username, "Registration queued for approval");
+        return Ok(RegisterOutcome::PendingApproval(request));
+    }
 
-    // Create user
-    let user_id = Uuid::now_v7();
-    let user = queries::create_user(pool, user_id, instance_id, username, email, &password_hash)
+    let user = create_account(pool, config, mailer, username, email, &password_hash, is_first_user)
         .await?;
 
-    // First user becomes admin; otherwise increment code usage
-    if is_first_user {
-        queries::set_user_admin(pool, user.id, true).await?;
-    } else if server_invite_id.is_some() {
-        // Server invite: increment invite uses and auto-join server
-        let code_str = invite_code.unwrap_or("");
-        queries::increment_invite_uses(pool, code_str).await?;
-        queries::add_server_member(pool, server_invite_id.unwrap(), user.id).await?;
-    } else {
-        // Registration code
-        queries::increment_registration_code_uses(pool, invite_code.unwrap_or("")).await?;
-    }
+    // Consume the code
+    let joined_server_id = match code {
+        Some(InviteCode::Server { code, server_id }) => {
+            // Server invite: increment invite uses and auto-join server
+            queries::increment_invite_uses(pool, code).await?;
+            queries::add_server_member(pool, server_id, user.id).await?;
+            Some(server_id)
+        }
+        Some(InviteCode::Registration(code)) => {
+            queries::increment_registration_code_uses(pool, code).await?;
+            None
+        }
+        None => None,
+    };
 
-    // Re-fetch user to get updated is_admin flag
-    let user = queries::get_user_by_id(pool, user.id)
-        .await?
-        .ok_or(ApiError::NotFound("User"))?;
+    // Keep questionnaire answers visible to admins even when no approval was needed
+    if !answers.is_empty() {
+        queries::create_registration_request(
+            pool,
+            Uuid::now_v7(),
+            username,
+            email,
+            None,
+            &answers,
+            RegistrationRequestStatus::Approved,
+            Some(user.id),
+        )
+        .await?;
+    }
 
     // Generate tokens
     let (access_token, refresh_token) = create_tokens(pool, config, user.id).await?;
 
-    Ok((AuthResponse {
-        access_token,
-        refresh_token,
-        user: PublicUser::from(user),
-    }, server_invite_id))
+    Ok(RegisterOutcome::Registered {
+        auth: AuthResponse {
+            access_token,
+            refresh_token,
+            user: PublicUser::from(user),
+        },
+        joined_server_id,
+    })
+}
+
+// ── Registration Approval ─────────────────────────────
+
+pub async fn approve_registration(
+    pool: &PgPool,
+    config: &AppConfig,
+    mailer: Option<&Mailer>,
+    request_id: Uuid,
+    reviewer_id: Uuid,
+) -> Result<RegistrationRequest, ApiError> {
+    let request = queries::get_registration_request(pool, request_id)
+        .await?
+        .ok_or(ApiError::NotFound("Registration request"))?;
+    if request.status != RegistrationRequestStatus::Pending {
+        return Err(ApiError::InvalidInput(
+            "Registration request has already been reviewed".into(),
+        ));
+    }
+    let password_hash = request
+        .password_hash
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("Pending registration request has no password"))?;
+
+    ensure_identity_available(pool, &request.username, &request.email).await?;
+
+    // Claim the request before creating the account, so concurrent approvals
+    // can't both create it
+    queries::review_registration_request(
+        pool,
+        request_id,
+        RegistrationRequestStatus::Approved,
+        reviewer_id,
+        None,
+        None,
+    )
+    .await?
+    .ok_or(ApiError::InvalidInput(
+        "Registration request has already been reviewed".into(),
+    ))?;
+
+    let user = match create_account(
+        pool,
+        config,
+        mailer,
+        &request.username,
+        &request.email,
+        password_hash,
+        false,
+    )
+    .await
+    {
+        Ok(user) => user,
+        Err(e) => {
+            queries::reopen_registration_request(pool, request_id, password_hash).await?;
+            return Err(e);
+        }
+    };
+
+    let reviewed = queries::set_registration_request_user(pool, request_id, user.id).await?;
+
+    if let Some(mailer) = mailer {
+        let login_url = mailer.url("/login");
+        if let Err(e) = mailer
+            .send(
+                &request.email,
+                EmailTemplate::RegistrationApproved,
+                &[
+                    ("username", request.username.as_str()),
+                    ("login_url", login_url.as_str()),
+                ],
+            )
+            .await
+        {
+            tracing::error!(error = %e, "Failed to send registration approval email");
+        }
+    }
+
+    Ok(reviewed)
+}
+
+pub async fn reject_registration(
+    pool: &PgPool,
+    mailer: Option<&Mailer>,
+    request_id: Uuid,
+    reviewer_id: Uuid,
+    reason: Option<&str>,
+) -> Result<RegistrationRequest, ApiError> {
+    let reviewed = queries::review_registration_request(
+        pool,
+        request_id,
+        RegistrationRequestStatus::Rejected,
+        reviewer_id,
+        reason,
+        None,
+    )
+    .await?
+    .ok_or(ApiError::NotFound("Pending registration request"))?;
+
+    if let Some(mailer) = mailer
+        && let Err(e) = mailer
+            .send(
+                &reviewed.email,
+                EmailTemplate::RegistrationRejected,
+                &[
+                    ("username", reviewed.username.as_str()),
+                    ("reason", reason.unwrap_or("No reason was given.")),
+                ],
+            )
+            .await
+    {
+        tracing::error!(error = %e, "Failed to send registration rejection email");
+    }
+
+    Ok(reviewed)
 }
 
 pub async fn login(
@@ -229,6 +498,20 @@ pub async fn refresh(
     })
 }
 
+/// Re-confirm a user's password before a sensitive action (account deletion,
+/// ownership transfer). Accounts without a password can never pass.
+pub fn verify_password(user: &User, password: &str) -> Result<(), ApiError> {
+    let password_hash = user
+        .password_hash
+        .as_ref()
+        .ok_or(ApiError::Unauthorized)?;
+    let parsed_hash =
+        PasswordHash::new(password_hash).map_err(|e| anyhow::anyhow!("Invalid hash: {}", e))?;
+    Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .map_err(|_| ApiError::InvalidInput("Incorrect password".into()))
+}
+
 pub fn validate_access_token(config: &AppConfig, token: &str) -> Result<Uuid, ApiError> {
     let token_data = decode::<JwtClaims>(
         token,
@@ -282,11 +565,12 @@ pub fn hash_token(token: &str) -> String {
 pub async fn request_password_reset(
     pool: &PgPool,
     config: &AppConfig,
+    mailer: Option<&Mailer>,
     email: &str,
 ) -> Result<(), ApiError> {
-    let email_config = config.email.as_ref().ok_or_else(|| {
-        ApiError::Internal(anyhow::anyhow!("Email is not configured"))
-    })?;
+    let (Some(email_config), Some(mailer)) = (config.email.as_ref(), mailer) else {
+        return Err(ApiError::Internal(anyhow::anyhow!("Email is not configured")));
+    };
 
     // Look up user — but always return Ok to avoid revealing email existence
     let user = queries::get_user_by_email(pool, email).await?;
@@ -305,20 +589,17 @@ pub async fn request_password_reset(
         queries::create_password_reset_token(pool, Uuid::now_v7(), user.id, &token_hash, expires_at)
             .await?;
 
-        // Build reset URL
-        let reset_url = format!(
-            "https://{}/reset-password?token={}",
-            config.instance.domain, raw_token
-        );
+        let reset_url = mailer.url(&format!("/reset-password?token={}", raw_token));
+        let expires_in = crate::services::email::format_ttl(ttl_secs);
 
         // Send email — suppress errors to avoid leaking info
-        if let Err(e) = crate::services::email::send_password_reset_email(
-            email_config,
-            &config.instance.name,
-            email,
-            &reset_url,
-        )
-        .await
+        if let Err(e) = mailer
+            .send(
+                email,
+                EmailTemplate::PasswordReset,
+                &[("reset_url", reset_url.as_str()), ("expires_in", expires_in.as_str())],
+            )
+            .await
         {
             tracing::error!(error = %e, "Failed to send password reset email");
         }
@@ -362,6 +643,168 @@ pub async fn complete_password_reset(
     Ok(())
 }
 
+// ── Email Verification ────────────────────────────────
+
+/// Issue a verification token for `email` and mail the confirmation link to it.
+/// Any earlier outstanding token for the user is invalidated.
+async fn send_verification_token(
+    pool: &PgPool,
+    config: &AppConfig,
+    mailer: &Mailer,
+    user_id: Uuid,
+    email: &str,
+) -> Result<(), ApiError> {
+    queries::delete_user_email_verification_tokens(pool, user_id).await?;
+
+    let raw_token = Uuid::now_v7().to_string();
+    let token_hash = hash_token(&raw_token);
+
+    let ttl_secs = config
+        .email
+        .as_ref()
+        .and_then(|ec| ec.verification_token_ttl_secs)
+        .unwrap_or(86400);
+    let expires_at = Utc::now() + chrono::Duration::seconds(ttl_secs);
+
+    queries::create_email_verification_token(
+        pool,
+        Uuid::now_v7(),
+        user_id,
+        email,
+        &token_hash,
+        expires_at,
+    )
+    .await?;
+
+    let verify_url = mailer.url(&format!("/verify-email?token={}", raw_token));
+
+    mailer
+        .send(email, EmailTemplate::EmailVerification, &[("verify_url", verify_url.as_str())])
+        .await
+}
+
+fn require_mailer(mailer: Option<&Mailer>) -> Result<&Mailer, ApiError> {
+    mailer.ok_or_else(|| ApiError::Internal(anyhow::anyhow!("Email is not configured")))
+}
+
+pub async fn resend_verification_email(
+    pool: &PgPool,
+    config: &AppConfig,
+    mailer: Option<&Mailer>,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let mailer = require_mailer(mailer)?;
+    let user = queries::get_user_by_id(pool, user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    if user.email_verified {
+        return Err(ApiError::InvalidInput("Email is already verified".into()));
+    }
+    let email = user
+        .email
+        .as_deref()
+        .ok_or(ApiError::InvalidInput("No email address on this account".into()))?;
+
+    send_verification_token(pool, config, mailer, user.id, email).await
+}
+
+/// Start an email change. The new address only replaces the old one once the
+/// link sent to it is confirmed in `verify_email`.
+pub async fn request_email_change(
+    pool: &PgPool,
+    config: &AppConfig,
+    mailer: Option<&Mailer>,
+    user_id: Uuid,
+    new_email: &str,
+    password: &str,
+) -> Result<(), ApiError> {
+    let mailer = require_mailer(mailer)?;
+    if !is_valid_email(new_email) {
+        return Err(ApiError::InvalidInput("Invalid email address".into()));
+    }
+
+    let user = queries::get_user_by_id(pool, user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    verify_password(&user, password)?;
+
+    if user.email.as_deref() == Some(new_email) {
+        return Err(ApiError::InvalidInput(
+            "That is already your email address".into(),
+        ));
+    }
+    if queries::get_user_by_email(pool, new_email).await?.is_some() {
+        return Err(ApiError::InvalidInput("Email already registered".into()));
+    }
+
+    send_verification_token(pool, config, mailer, user.id, new_email).await
+}
+
+/// Confirm a verification token. If the token's address differs from the
+/// user's current one this completes an email change and notifies the old address.
+pub async fn verify_email(
+    pool: &PgPool,
+    mailer: Option<&Mailer>,
+    raw_token: &str,
+) -> Result<(), ApiError> {
+    let token_hash = hash_token(raw_token);
+
+    let token = queries::consume_email_verification_token(pool, &token_hash)
+        .await?
+        .ok_or(ApiError::InvalidInput(
+            "Invalid or expired verification token".into(),
+        ))?;
+
+    let user = queries::get_user_by_id(pool, token.user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+
+    if user.email.as_deref() == Some(token.email.as_str()) {
+        queries::set_user_email_verified(pool, user.id, true).await?;
+        return Ok(());
+    }
+
+    // Email change — make sure nobody claimed the address in the meantime
+    if queries::get_user_by_email(pool, &token.email).await?.is_some() {
+        return Err(ApiError::InvalidInput("Email already registered".into()));
+    }
+    queries::update_user_email(pool, user.id, &token.email).await?;
+
+    if let (Some(old_email), Some(mailer)) = (user.email.as_deref(), mailer)
+        && let Err(e) = mailer
+            .send(
+                old_email,
+                EmailTemplate::EmailChanged,
+                &[("new_email", token.email.as_str())],
+            )
+            .await
+    {
+        tracing::error!(error = %e, "Failed to send email change notice");
+    }
+
+    Ok(())
+}
+
+/// Reject posting for unverified users when the instance requires verification.
+pub async fn ensure_can_post(
+    pool: &PgPool,
+    config: &AppConfig,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    if !config.instance.require_email_verification {
+        return Ok(());
+    }
+    let user = queries::get_user_by_id(pool, user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    if !user.email_verified && !user.bot {
+        return Err(ApiError::InvalidInput(
+            "Verify your email address before posting".into(),
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,11 +830,13 @@ mod tests {
             instance: crate::config::InstanceConfig {
                 domain: "test.local".into(),
                 name: "Test".into(),
+                require_email_verification: false,
             },
             s3: None,
             livekit: None,
             gif: None,
             email: None,
+            digest: None,
             github: None,
             web_push: None,
             admin_dashboard: None,
@@ -497,4 +942,24 @@ mod tests {
         let result = validate_access_token(&config, "not-a-jwt");
         assert!(result.is_err());
     }
+
+    // ── Registration questionnaire ─────────────────────
+
+    #[test]
+    fn answers_are_paired_with_questions() {
+        let questions = vec!["Why join?".to_string(), "Referred by?".to_string()];
+        let answers = vec!["  to chat ".to_string(), "a friend".to_string()];
+        let paired = collect_answers(&questions, &answers).unwrap();
+        assert_eq!(paired.len(), 2);
+        assert_eq!(paired[0].question, "Why join?");
+        assert_eq!(paired[0].answer, "to chat");
+    }
+
+    #[test]
+    fn missing_or_blank_answers_are_rejected() {
+        let questions = vec!["Why join?".to_string()];
+        assert!(collect_answers(&questions, &[]).is_err());
+        assert!(collect_answers(&questions, &["   ".to_string()]).is_err());
+        assert!(collect_answers(&[], &[]).unwrap().is_empty());
+    }
 }
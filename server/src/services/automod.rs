@@ -0,0 +1,677 @@
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock};
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use regex::{Regex, RegexBuilder};
+use uuid::Uuid;
+
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::permissions as perm_service;
+use crate::state::AppState;
+use crate::types::entities::{
+    AuditAction, AutoModAction, AutoModRule, AutoModTriggerMetadata, AutoModTriggerType, Channel,
+    PublicUser,
+};
+use crate::types::events::{MessageCreateEvent, MessageDeleteEvent, ServerMemberUpdateEvent};
+use crate::types::permissions::Permissions;
+
+pub const MAX_RULES_PER_SERVER: usize = 25;
+const MAX_KEYWORDS: usize = 1000;
+const MAX_KEYWORD_LEN: usize = 60;
+const MAX_REGEX_PATTERNS: usize = 10;
+const MAX_REGEX_LEN: usize = 260;
+/// Compiled-program budget per pattern so a rule can't make every message expensive
+const REGEX_SIZE_LIMIT: usize = 1 << 16;
+/// Longest timeout a moderator or AutoMod rule can apply (28 days)
+pub const MAX_TIMEOUT_SECS: i64 = 28 * 86400;
+const DEFAULT_BLOCK_MESSAGE: &str = "Your message was blocked by this server's AutoMod";
+
+static RE_INVITE_LINK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:https?://)?(?:[a-z0-9-]+\.)+[a-z]{2,}/invite/[a-z0-9]+|\bdiscord(?:app)?\.(?:gg|com/invite)/[a-z0-9-]+")
+        .expect("RE_INVITE_LINK is a valid regex")
+});
+static RE_MENTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<@[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}>|@\w{2,32}")
+        .expect("RE_MENTION is a valid regex")
+});
+static RE_LINK_HOST: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)https?://(?:[^@/\s]*@)?([a-z0-9.-]+)").expect("RE_LINK_HOST is a valid regex")
+});
+
+/// Compiled triggers by rule id, tagged with the `updated_at` they were built from
+type CompiledRules = DashMap<Uuid, (DateTime<Utc>, Arc<CompiledTrigger>)>;
+static COMPILED_RULES: LazyLock<CompiledRules> = LazyLock::new(DashMap::new);
+
+// ── Rule validation ───────────────────────────────────
+
+/// Check a rule's trigger configuration and actions before it is saved.
+pub fn validate_rule(
+    trigger_type: AutoModTriggerType,
+    metadata: &AutoModTriggerMetadata,
+    actions: &[AutoModAction],
+) -> Result<(), String> {
+    match trigger_type {
+        AutoModTriggerType::Keyword => {
+            if metadata.keywords.is_empty() || metadata.keywords.len() > MAX_KEYWORDS {
+                return Err(format!("Keyword rules need 1-{MAX_KEYWORDS} keywords"));
+            }
+            if metadata
+                .keywords
+                .iter()
+                .any(|k| k.trim_matches('*').trim().is_empty() || k.len() > MAX_KEYWORD_LEN)
+            {
+                return Err(format!("Keywords must be 1-{MAX_KEYWORD_LEN} characters"));
+            }
+            compile_keywords(&metadata.keywords)
+                .map_err(|_| "Keyword list is too large, split it across rules".to_string())?;
+        }
+        AutoModTriggerType::Regex => {
+            if metadata.regex_patterns.is_empty()
+                || metadata.regex_patterns.len() > MAX_REGEX_PATTERNS
+            {
+                return Err(format!("Regex rules need 1-{MAX_REGEX_PATTERNS} patterns"));
+            }
+            for pattern in &metadata.regex_patterns {
+                if pattern.len() > MAX_REGEX_LEN {
+                    return Err(format!("Patterns must be at most {MAX_REGEX_LEN} characters"));
+                }
+                compile_pattern(pattern).map_err(|e| format!("Invalid regex {pattern:?}: {e}"))?;
+            }
+        }
+        AutoModTriggerType::InviteLink => {}
+        AutoModTriggerType::MentionSpam => match metadata.mention_limit {
+            Some(1..=50) => {}
+            _ => return Err("mention_limit must be 1-50".into()),
+        },
+        AutoModTriggerType::RepeatedMessage => {
+            if !matches!(metadata.repeat_limit, Some(1..=20)) {
+                return Err("repeat_limit must be 1-20".into());
+            }
+            if !matches!(metadata.repeat_window_secs, Some(5..=3600)) {
+                return Err("repeat_window_secs must be 5-3600".into());
+            }
+        }
+        AutoModTriggerType::LinkFilter => {
+            if metadata.allowed_domains.is_empty() && metadata.blocked_domains.is_empty() {
+                return Err("Link filters need allowed_domains or blocked_domains".into());
+            }
+        }
+    }
+
+    if actions.is_empty() {
+        return Err("Rules need at least one action".into());
+    }
+    for action in actions {
+        match action {
+            AutoModAction::BlockMessage {
+                custom_message: Some(m),
+            } if m.len() > 150 => {
+                return Err("Block messages must be at most 150 characters".into());
+            }
+            AutoModAction::Timeout { duration_secs } if !(1..=MAX_TIMEOUT_SECS).contains(duration_secs) => {
+                return Err(format!("Timeouts must be 1-{MAX_TIMEOUT_SECS} seconds"));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn compile_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+}
+
+/// All of a rule's keywords as one alternation.
+fn compile_keywords(keywords: &[String]) -> Result<Option<Regex>, regex::Error> {
+    if keywords.is_empty() {
+        return Ok(None);
+    }
+    let pattern = keywords
+        .iter()
+        .map(|k| keyword_pattern(k))
+        .collect::<Vec<_>>()
+        .join("|");
+    compile_pattern(&pattern).map(Some)
+}
+
+// ── Compiled rules ────────────────────────────────────
+
+/// The regexes behind a keyword or regex trigger, built once per rule.
+#[derive(Debug, Default)]
+pub struct CompiledTrigger {
+    keywords: Option<Regex>,
+    patterns: Vec<Regex>,
+}
+
+impl CompiledTrigger {
+    pub fn compile(
+        trigger_type: AutoModTriggerType,
+        metadata: &AutoModTriggerMetadata,
+    ) -> Result<Self, regex::Error> {
+        let mut compiled = Self::default();
+        match trigger_type {
+            AutoModTriggerType::Keyword => compiled.keywords = compile_keywords(&metadata.keywords)?,
+            AutoModTriggerType::Regex => {
+                compiled.patterns = metadata
+                    .regex_patterns
+                    .iter()
+                    .map(|p| compile_pattern(p))
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => {}
+        }
+        Ok(compiled)
+    }
+}
+
+/// Compile a rule into the cache, replacing any older version of it.
+pub fn cache_rule(rule: &AutoModRule) -> Arc<CompiledTrigger> {
+    let compiled = CompiledTrigger::compile(rule.trigger_type, &rule.trigger_metadata)
+        .unwrap_or_else(|e| {
+            // Only rules saved before the current limits can get here
+            tracing::warn!(rule_id = %rule.id, error = %e, "AutoMod rule no longer compiles");
+            CompiledTrigger::default()
+        });
+    let compiled = Arc::new(compiled);
+    COMPILED_RULES.insert(rule.id, (rule.updated_at, compiled.clone()));
+    compiled
+}
+
+pub fn forget_rule(rule_id: Uuid) {
+    COMPILED_RULES.remove(&rule_id);
+}
+
+/// The cached compiled trigger for `rule`, rebuilt if the rule changed since.
+fn compiled_trigger(rule: &AutoModRule) -> Arc<CompiledTrigger> {
+    if let Some(entry) = COMPILED_RULES.get(&rule.id)
+        && entry.0 == rule.updated_at
+    {
+        return entry.1.clone();
+    }
+    cache_rule(rule)
+}
+
+// ── Content matching ──────────────────────────────────
+
+/// Test `content` against a content-based trigger. Returns the text that
+/// matched. `RepeatedMessage` depends on history and is checked separately.
+pub fn match_content(
+    trigger_type: AutoModTriggerType,
+    metadata: &AutoModTriggerMetadata,
+    compiled: &CompiledTrigger,
+    content: &str,
+) -> Option<String> {
+    match trigger_type {
+        AutoModTriggerType::Keyword => match_keywords(metadata, compiled, content),
+        AutoModTriggerType::Regex => compiled
+            .patterns
+            .iter()
+            .find_map(|re| re.find(content).map(|m| m.as_str().to_string())),
+        AutoModTriggerType::InviteLink => {
+            RE_INVITE_LINK.find(content).map(|m| m.as_str().to_string())
+        }
+        AutoModTriggerType::MentionSpam => {
+            let limit = metadata.mention_limit.unwrap_or(u32::MAX) as usize;
+            let count = count_mentions(content);
+            (count >= limit).then(|| format!("{count} mentions"))
+        }
+        AutoModTriggerType::LinkFilter => match_link_filter(metadata, content),
+        AutoModTriggerType::RepeatedMessage => None,
+    }
+}
+
+/// Turn a keyword into a pattern: plain keywords match whole words, and a `*`
+/// at either end lets that side match inside a longer word.
+fn keyword_pattern(keyword: &str) -> String {
+    let keyword = keyword.trim();
+    let prefix = if keyword.starts_with('*') { "" } else { r"\b" };
+    let suffix = if keyword.ends_with('*') { "" } else { r"\b" };
+    format!("{prefix}{}{suffix}", regex::escape(keyword.trim_matches('*')))
+}
+
+fn match_keywords(
+    metadata: &AutoModTriggerMetadata,
+    compiled: &CompiledTrigger,
+    content: &str,
+) -> Option<String> {
+    compiled
+        .keywords
+        .as_ref()?
+        .find_iter(content)
+        .map(|m| m.as_str())
+        .find(|m| !metadata.allow_list.iter().any(|a| a.eq_ignore_ascii_case(m)))
+        .map(str::to_string)
+}
+
+/// Distinct `<@uuid>`, `@username`, `@everyone` and `@here` mentions.
+fn count_mentions(content: &str) -> usize {
+    RE_MENTION
+        .find_iter(content)
+        .map(|m| m.as_str().to_lowercase())
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn domain_matches(host: &str, domain: &str) -> bool {
+    let domain = domain.trim().trim_start_matches("*.").to_lowercase();
+    host == domain || host.ends_with(&format!(".{domain}"))
+}
+
+fn match_link_filter(metadata: &AutoModTriggerMetadata, content: &str) -> Option<String> {
+    RE_LINK_HOST
+        .captures_iter(content)
+        .map(|cap| cap[1].trim_end_matches('.').to_lowercase())
+        .find(|host| {
+            let blocked = metadata.blocked_domains.iter().any(|d| domain_matches(host, d));
+            let not_allowed = !metadata.allowed_domains.is_empty()
+                && !metadata.allowed_domains.iter().any(|d| domain_matches(host, d));
+            blocked || not_allowed
+        })
+}
+
+/// Threads inherit their parent channel's exemption.
+pub fn is_exempt(rule: &AutoModRule, channel: &Channel, member_role_ids: &[Uuid]) -> bool {
+    rule.exempt_channel_ids.contains(&channel.id)
+        || channel
+            .parent_id
+            .is_some_and(|p| rule.exempt_channel_ids.contains(&p))
+        || member_role_ids.iter().any(|r| rule.exempt_role_ids.contains(r))
+}
+
+// ── Enforcement ───────────────────────────────────────
+
+/// Reject posting while the member is timed out.
+pub async fn ensure_not_timed_out(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let member = queries::get_server_member(&state.db, server_id, user_id).await?;
+    if let Some(until) = member.and_then(|m| m.communication_disabled_until)
+        && until > Utc::now()
+    {
+        return Err(ApiError::InvalidInput(format!(
+            "You are timed out in this server until {}",
+            until.to_rfc3339()
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+pub struct AutoModVerdict {
+    /// A matching rule asked for the message to be removed once posted
+    pub delete_after_send: bool,
+}
+
+/// Run the server's AutoMod rules against a message about to be posted or
+/// edited. Timeouts and alerts are applied here; a blocking rule returns an
+/// error carrying its custom message. Server owners and members with
+/// MANAGE_SERVER are never filtered.
+pub async fn check_message(
+    state: &AppState,
+    channel: &Channel,
+    author_id: Uuid,
+    from_webhook: bool,
+    content: &str,
+) -> Result<AutoModVerdict, ApiError> {
+    let mut verdict = AutoModVerdict::default();
+    let Some(server_id) = channel.server_id else {
+        return Ok(verdict);
+    };
+
+    let rules = queries::get_enabled_automod_rules(&state.db, server_id).await?;
+    if rules.is_empty() {
+        return Ok(verdict);
+    }
+
+    let member_role_ids = if from_webhook {
+        Vec::new()
+    } else {
+        let server = queries::get_server_by_id(&state.db, server_id)
+            .await?
+            .ok_or(ApiError::NotFound("Server"))?;
+        if perm_service::has_server_permission(
+            &state.db,
+            server_id,
+            author_id,
+            server.owner_id,
+            Permissions::MANAGE_SERVER,
+        )
+        .await?
+        {
+            return Ok(verdict);
+        }
+        queries::get_member_role_ids(&state.db, server_id, author_id).await?
+    };
+
+    let mut block_message: Option<String> = None;
+    for rule in &rules {
+        if is_exempt(rule, channel, &member_role_ids) {
+            continue;
+        }
+        let matched = match rule.trigger_type {
+            AutoModTriggerType::RepeatedMessage => {
+                repeated_message(state, rule, author_id, content).await
+            }
+            trigger_type => match_content(
+                trigger_type,
+                &rule.trigger_metadata,
+                &compiled_trigger(rule),
+                content,
+            ),
+        };
+        let Some(matched) = matched else {
+            continue;
+        };
+
+        let blocks = rule
+            .actions
+            .iter()
+            .any(|a| matches!(a, AutoModAction::BlockMessage { .. }));
+
+        for action in rule.actions.iter() {
+            match action {
+                AutoModAction::BlockMessage { custom_message } => {
+                    if block_message.is_none() {
+                        block_message = Some(
+                            custom_message
+                                .clone()
+                                .unwrap_or_else(|| DEFAULT_BLOCK_MESSAGE.to_string()),
+                        );
+                    }
+                }
+                AutoModAction::DeleteMessage => verdict.delete_after_send = true,
+                // Webhooks aren't members, so there's nobody to time out
+                AutoModAction::Timeout { duration_secs } if !from_webhook => {
+                    let until = Utc::now() + chrono::Duration::seconds(*duration_secs);
+                    if let Ok(Some(member)) =
+                        queries::set_member_timeout(&state.db, server_id, author_id, Some(until)).await
+                    {
+                        state.gateway.broadcast_to_server(
+                            server_id,
+                            "SERVER_MEMBER_UPDATE",
+                            &ServerMemberUpdateEvent { server_id, member },
+                            None,
+                        );
+                    }
+                }
+                AutoModAction::Timeout { .. } => {}
+                AutoModAction::SendAlert { channel_id } => {
+                    let alert = format!(
+                        "**AutoMod** rule \"{}\" {} a message from <@{}> in #{}\nMatched: `{}`\n> {}",
+                        rule.name,
+                        if blocks { "blocked" } else { "flagged" },
+                        author_id,
+                        channel.name.as_deref().unwrap_or("unknown"),
+                        matched.replace('`', "'"),
+                        excerpt(content).replace('\n', "\n> "),
+                    );
+                    if let Err(e) =
+                        post_system_message(state, server_id, *channel_id, "AutoMod", &alert).await
+                    {
+                        tracing::warn!(rule_id = %rule.id, error = ?e, "Failed to post AutoMod alert");
+                    }
+                }
+            }
+        }
+
+        audit_service::record(
+            state,
+            server_id,
+            author_id,
+            AuditAction::AutomodTrigger,
+            Some(rule.id),
+            Some(&rule.name),
+            Some(serde_json::json!({
+                "channel_id": channel.id,
+                "trigger_type": rule.trigger_type,
+                "matched_content": matched,
+                "content": excerpt(content),
+                "actions": &*rule.actions,
+                "webhook": from_webhook,
+            })),
+        )
+        .await;
+    }
+
+    match block_message {
+        Some(message) => Err(ApiError::InvalidInput(message)),
+        None => Ok(verdict),
+    }
+}
+
+/// Remove a message that a `DeleteMessage` action let through.
+pub async fn remove_message(
+    state: &AppState,
+    server_id: Uuid,
+    channel_id: Uuid,
+    message_id: Uuid,
+) -> Result<(), ApiError> {
+    queries::delete_message(&state.db, message_id).await?;
+    let event = MessageDeleteEvent {
+        id: message_id,
+        channel_id,
+        server_id: Some(server_id),
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "MESSAGE_DELETE", &event, None);
+    Ok(())
+}
+
+/// Count identical messages from the author in Redis, keyed per rule so each
+/// rule keeps its own window.
+async fn repeated_message(
+    state: &AppState,
+    rule: &AutoModRule,
+    author_id: Uuid,
+    content: &str,
+) -> Option<String> {
+    let limit = rule.trigger_metadata.repeat_limit? as i64;
+    let window = rule.trigger_metadata.repeat_window_secs?;
+    let normalized = content.trim().to_lowercase();
+    let key = format!(
+        "automod_repeat:{}:{}:{}",
+        rule.id,
+        author_id,
+        crate::services::auth::hash_token(&normalized)
+    );
+
+    let mut redis = state.redis.clone();
+    let count: i64 = redis::cmd("INCR").arg(&key).query_async(&mut redis).await.ok()?;
+    if count == 1 {
+        let _: Result<(), _> = redis::cmd("EXPIRE")
+            .arg(&key)
+            .arg(window)
+            .query_async(&mut redis)
+            .await;
+    }
+
+    (count > limit).then(|| format!("{count} identical messages"))
+}
+
+fn excerpt(content: &str) -> String {
+    match content.char_indices().nth(300) {
+        Some((idx, _)) => format!("{}…", &content[..idx]),
+        None => content.to_string(),
+    }
+}
+
+/// Author shown on messages the server posts itself.
+pub fn system_author(name: &str) -> PublicUser {
+    PublicUser {
+        id: Uuid::nil(),
+        username: name.to_string(),
+        display_name: None,
+        avatar_url: None,
+        bio: None,
+        status: "online".to_string(),
+        custom_status: None,
+        timezone: None,
+        theme_preference: None,
+        bot: true,
+    }
+}
+
+/// Post an authorless notice into a channel of `server_id` and broadcast it,
+/// shown as coming from `author_name`.
+pub async fn post_system_message(
+    state: &AppState,
+    server_id: Uuid,
+    channel_id: Uuid,
+    author_name: &str,
+    content: &str,
+) -> Result<(), ApiError> {
+    let channel = queries::get_channel_by_id(&state.db, channel_id)
+        .await?
+        .ok_or(ApiError::NotFound("Channel"))?;
+    if channel.server_id != Some(server_id) {
+        return Err(ApiError::NotFound("Channel"));
+    }
+
+    let instance_id =
+        queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
+    let message_id = Uuid::now_v7();
+    let message =
+        queries::create_system_message(&state.db, message_id, instance_id, channel_id, content)
+            .await?;
+    let _ = queries::update_channel_last_message(&state.db, channel_id, message_id).await;
+
+    let event = MessageCreateEvent {
+        message,
+        author: system_author(author_name),
+    };
+    state
+        .gateway
+        .broadcast_to_server(server_id, "MESSAGE_CREATE", &event, None);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(
+        trigger_type: AutoModTriggerType,
+        metadata: &AutoModTriggerMetadata,
+        content: &str,
+    ) -> Option<String> {
+        let compiled = CompiledTrigger::compile(trigger_type, metadata).unwrap();
+        match_content(trigger_type, metadata, &compiled, content)
+    }
+
+    fn keywords(list: &[&str]) -> AutoModTriggerMetadata {
+        AutoModTriggerMetadata {
+            keywords: list.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn plain_keywords_match_whole_words_only() {
+        let meta = keywords(&["cat"]);
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "a CAT here"), Some("CAT".into()));
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "concatenate"), None);
+    }
+
+    #[test]
+    fn wildcard_keywords_match_partial_words() {
+        let meta = keywords(&["*cat*", "dog*"]);
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "concatenate"), Some("cat".into()));
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "doghouse"), Some("dog".into()));
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "hotdog"), None);
+    }
+
+    #[test]
+    fn allow_list_suppresses_matches() {
+        let mut meta = keywords(&["ass*"]);
+        meta.allow_list = vec!["ass".into()];
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "ass"), None);
+    }
+
+    #[test]
+    fn keyword_special_characters_are_literal() {
+        let meta = keywords(&["a.b"]);
+        assert_eq!(check(AutoModTriggerType::Keyword, &meta, "axb"), None);
+        assert!(check(AutoModTriggerType::Keyword, &meta, "say a.b now").is_some());
+    }
+
+    #[test]
+    fn invite_links_are_detected() {
+        let meta = AutoModTriggerMetadata::default();
+        assert!(check(AutoModTriggerType::InviteLink, &meta, "join https://chat.example.com/invite/abc123").is_some());
+        assert!(check(AutoModTriggerType::InviteLink, &meta, "discord.gg/xyz").is_some());
+        assert!(check(AutoModTriggerType::InviteLink, &meta, "https://example.com/docs").is_none());
+    }
+
+    #[test]
+    fn mention_spam_counts_distinct_mentions() {
+        let meta = AutoModTriggerMetadata {
+            mention_limit: Some(3),
+            ..Default::default()
+        };
+        assert!(check(AutoModTriggerType::MentionSpam, &meta, "@alice @bob @alice").is_none());
+        assert!(check(AutoModTriggerType::MentionSpam, &meta, "@alice @bob @carol").is_some());
+    }
+
+    #[test]
+    fn link_filter_honours_allow_and_block_lists() {
+        let blocked = AutoModTriggerMetadata {
+            blocked_domains: vec!["bad.com".into()],
+            ..Default::default()
+        };
+        assert!(check(AutoModTriggerType::LinkFilter, &blocked, "see https://cdn.bad.com/x").is_some());
+        assert!(check(AutoModTriggerType::LinkFilter, &blocked, "see https://notbad.com").is_none());
+
+        let allowed = AutoModTriggerMetadata {
+            allowed_domains: vec!["example.com".into()],
+            ..Default::default()
+        };
+        assert!(check(AutoModTriggerType::LinkFilter, &allowed, "https://docs.example.com/a").is_none());
+        assert_eq!(
+            check(AutoModTriggerType::LinkFilter, &allowed, "https://other.org/a"),
+            Some("other.org".into())
+        );
+    }
+
+    #[test]
+    fn validation_rejects_bad_regex_and_empty_actions() {
+        let meta = AutoModTriggerMetadata {
+            regex_patterns: vec!["(unclosed".into()],
+            ..Default::default()
+        };
+        let block = [AutoModAction::BlockMessage { custom_message: None }];
+        assert!(validate_rule(AutoModTriggerType::Regex, &meta, &block).is_err());
+        assert!(validate_rule(AutoModTriggerType::InviteLink, &AutoModTriggerMetadata::default(), &[]).is_err());
+        assert!(validate_rule(AutoModTriggerType::InviteLink, &AutoModTriggerMetadata::default(), &block).is_ok());
+    }
+
+    #[test]
+    fn validation_bounds_timeouts() {
+        let meta = AutoModTriggerMetadata::default();
+        let too_long = [AutoModAction::Timeout { duration_secs: MAX_TIMEOUT_SECS + 1 }];
+        assert!(validate_rule(AutoModTriggerType::InviteLink, &meta, &too_long).is_err());
+    }
+
+    #[test]
+    fn validation_rejects_oversized_keyword_lists() {
+        let small = keywords(&["spam", "scam*"]);
+        let block = [AutoModAction::BlockMessage { custom_message: None }];
+        assert!(validate_rule(AutoModTriggerType::Keyword, &small, &block).is_ok());
+
+        let list: Vec<String> = (0..MAX_KEYWORDS).map(|i| format!("{}{i}", "x".repeat(50))).collect();
+        let huge = AutoModTriggerMetadata {
+            keywords: list,
+            ..Default::default()
+        };
+        assert!(validate_rule(AutoModTriggerType::Keyword, &huge, &block).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::Write;
+
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::config::DigestConfig;
+use crate::db::queries::{DigestDmRow, DigestMentionRow, NotificationPreference};
+use crate::services::email::escape_html;
+
+pub const FREQUENCIES: &[&str] = &["daily", "weekly", "never"];
+
+pub fn default_frequency(config: &DigestConfig) -> &str {
+    match config.default_frequency.as_deref() {
+        Some(f) if FREQUENCIES.contains(&f) => f,
+        _ => "daily",
+    }
+}
+
+pub fn generate_unsubscribe_token() -> String {
+    rand::rng()
+        .sample_iter(&rand::distr::Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+/// Same precedence as push notifications: a channel preference overrides the
+/// server one, and muted or "nothing" suppresses the entry.
+pub fn is_suppressed(
+    prefs: &[NotificationPreference],
+    channel_id: Uuid,
+    server_id: Option<Uuid>,
+) -> bool {
+    let channel_pref = prefs.iter().find(|p| p.target_id == channel_id);
+    let server_pref = server_id.and_then(|sid| prefs.iter().find(|p| p.target_id == sid));
+    match channel_pref.or(server_pref) {
+        Some(pref) => pref.muted || pref.notification_level == "nothing",
+        None => false,
+    }
+}
+
+pub struct DigestSummary {
+    pub text: String,
+    pub html: String,
+}
+
+/// Render the digest body, grouping mentions by server (rows arrive ordered
+/// by server name) followed by direct messages.
+pub fn build_summary(mentions: &[DigestMentionRow], dms: &[DigestDmRow]) -> DigestSummary {
+    let mut text = String::new();
+    let mut html = String::new();
+
+    let mut current_server: Option<Uuid> = None;
+    for row in mentions {
+        if current_server != row.server_id {
+            if current_server.is_some() {
+                html.push_str("</ul>");
+            }
+            current_server = row.server_id;
+            let server_name = row.server_name.as_deref().unwrap_or("Unknown server");
+            let _ = writeln!(text, "{server_name}");
+            let _ = write!(html, "<p><strong>{}</strong></p><ul>", escape_html(server_name));
+        }
+        let channel_name = row.channel_name.as_deref().unwrap_or("unknown");
+        let mentions_label = plural(row.mention_count as i64, "mention");
+        let _ = writeln!(text, "  #{channel_name}: {mentions_label}");
+        let _ = write!(html, "<li>#{}: {}</li>", escape_html(channel_name), mentions_label);
+    }
+    if current_server.is_some() {
+        html.push_str("</ul>");
+        text.push('\n');
+    }
+
+    if !dms.is_empty() {
+        text.push_str("Direct messages\n");
+        html.push_str("<p><strong>Direct messages</strong></p><ul>");
+        for row in dms {
+            let messages_label = plural(row.message_count, "message");
+            let _ = writeln!(text, "  {}: {}", row.senders, messages_label);
+            let _ = write!(html, "<li>{}: {}</li>", escape_html(&row.senders), messages_label);
+        }
+        html.push_str("</ul>");
+    }
+
+    DigestSummary { text, html }
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 new {unit}")
+    } else {
+        format!("{n} new {unit}s")
+    }
+}
@@ -0,0 +1,80 @@
+pub mod templates;
+pub mod transport;
+
+use crate::config::{EmailConfig, InstanceConfig};
+use crate::error::ApiError;
+
+pub use templates::EmailTemplate;
+pub(crate) use templates::escape_html;
+use templates::Templates;
+use transport::{EmailTransport, RenderedEmail};
+
+/// Renders templates and hands the result to the configured transport.
+/// Every outgoing email — resets, verification, digests, moderation notices —
+/// goes through [`Mailer::send`].
+pub struct Mailer {
+    transport: Box<dyn EmailTransport>,
+    templates: Templates,
+    from_address: String,
+    instance_name: String,
+    instance_domain: String,
+}
+
+impl Mailer {
+    pub fn new(config: &EmailConfig, instance: &InstanceConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            transport: transport::from_config(config)?,
+            templates: Templates::load(config.templates_dir.as_deref())?,
+            from_address: config.from_address.clone(),
+            instance_name: instance.name.clone(),
+            instance_domain: instance.domain.clone(),
+        })
+    }
+
+    /// Absolute URL on this instance for `path` (which must start with `/`).
+    pub fn url(&self, path: &str) -> String {
+        format!("https://{}{}", self.instance_domain, path)
+    }
+
+    /// Render `template` with `vars` (plus `instance_name`) and deliver it to `to`.
+    pub async fn send(
+        &self,
+        to: &str,
+        template: EmailTemplate,
+        vars: &[(&str, &str)],
+    ) -> Result<(), ApiError> {
+        let mut all_vars: Vec<(&str, &str)> = vec![("instance_name", self.instance_name.as_str())];
+        all_vars.extend_from_slice(vars);
+
+        let (subject, text, html) = self.templates.render(template, &all_vars);
+        let email = RenderedEmail {
+            from: self.from_address.clone(),
+            to: to.to_string(),
+            subject,
+            html,
+            text,
+        };
+
+        self.transport.send(&email).await?;
+
+        tracing::info!(to = %to, template = template.name(), "Email sent");
+        Ok(())
+    }
+}
+
+/// Human-readable duration for "this link expires in …" copy.
+pub fn format_ttl(secs: i64) -> String {
+    match secs {
+        s if s >= 86400 && s % 86400 == 0 => plural(s / 86400, "day"),
+        s if s >= 3600 && s % 3600 == 0 => plural(s / 3600, "hour"),
+        s => plural((s / 60).max(1), "minute"),
+    }
+}
+
+fn plural(n: i64, unit: &str) -> String {
+    if n == 1 {
+        format!("1 {unit}")
+    } else {
+        format!("{n} {unit}s")
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Every email the server sends. Each template has a subject, a plain-text
+/// body and an HTML body, any of which can be overridden from
+/// `email.templates_dir` as `<name>.subject.txt`, `<name>.txt` and `<name>.html`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmailTemplate {
+    PasswordReset,
+    EmailVerification,
+    EmailChanged,
+    MentionDigest,
+    RegistrationApproved,
+    RegistrationRejected,
+    ModerationNotice,
+}
+
+impl EmailTemplate {
+    pub const ALL: &'static [EmailTemplate] = &[
+        EmailTemplate::PasswordReset,
+        EmailTemplate::EmailVerification,
+        EmailTemplate::EmailChanged,
+        EmailTemplate::MentionDigest,
+        EmailTemplate::RegistrationApproved,
+        EmailTemplate::RegistrationRejected,
+        EmailTemplate::ModerationNotice,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            EmailTemplate::PasswordReset => "password_reset",
+            EmailTemplate::EmailVerification => "email_verification",
+            EmailTemplate::EmailChanged => "email_changed",
+            EmailTemplate::MentionDigest => "mention_digest",
+            EmailTemplate::RegistrationApproved => "registration_approved",
+            EmailTemplate::RegistrationRejected => "registration_rejected",
+            EmailTemplate::ModerationNotice => "moderation_notice",
+        }
+    }
+
+    /// Built-in (subject, text, html) used when no override exists.
+    fn defaults(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            EmailTemplate::PasswordReset => (
+                "Reset your {{instance_name}} password",
+                "You requested a password reset for your {{instance_name}} account.\n\n\
+                 Reset your password: {{reset_url}}\n\n\
+                 This link expires in {{expires_in}}.\n\
+                 If you didn't request this, you can safely ignore this email.",
+                "<p>You requested a password reset for your <strong>{{instance_name}}</strong> account.</p>\
+                 <p><a href=\"{{reset_url}}\">Click here to reset your password</a></p>\
+                 <p>This link expires in {{expires_in}}.</p>\
+                 <p>If you didn't request this, you can safely ignore this email.</p>",
+            ),
+            EmailTemplate::EmailVerification => (
+                "Confirm your email for {{instance_name}}",
+                "Please confirm this address for your {{instance_name}} account.\n\n\
+                 Verify your email: {{verify_url}}\n\n\
+                 If you didn't sign up or request this change, you can safely ignore this email.",
+                "<p>Please confirm this address for your <strong>{{instance_name}}</strong> account.</p>\
+                 <p><a href=\"{{verify_url}}\">Click here to verify your email</a></p>\
+                 <p>If you didn't sign up or request this change, you can safely ignore this email.</p>",
+            ),
+            EmailTemplate::EmailChanged => (
+                "Your {{instance_name}} email address was changed",
+                "The email address on your {{instance_name}} account was changed to {{new_email}}.\n\n\
+                 If you didn't make this change, reset your password and contact an administrator.",
+                "<p>The email address on your <strong>{{instance_name}}</strong> account was changed to \
+                 <strong>{{new_email}}</strong>.</p>\
+                 <p>If you didn't make this change, reset your password and contact an administrator.</p>",
+            ),
+            EmailTemplate::MentionDigest => (
+                "You have unread messages on {{instance_name}}",
+                "Hi {{username}}, here's what you missed on {{instance_name}}:\n\n\
+                 {{summary_text}}\n\
+                 Catch up: {{open_url}}\n\n\
+                 Change how often you get these in your notification settings, or unsubscribe: {{unsubscribe_url}}",
+                "<p>Hi {{username}}, here's what you missed on <strong>{{instance_name}}</strong>:</p>\
+                 {{summary_html}}\
+                 <p><a href=\"{{open_url}}\">Catch up on {{instance_name}}</a></p>\
+                 <p style=\"font-size: 12px; color: #888;\">Change how often you get these in your notification \
+                 settings, or <a href=\"{{unsubscribe_url}}\">unsubscribe</a>.</p>",
+            ),
+            EmailTemplate::RegistrationApproved => (
+                "Your {{instance_name}} account has been approved",
+                "Hi {{username}}, an administrator approved your sign-up for {{instance_name}}.\n\n\
+                 Log in: {{login_url}}",
+                "<p>Hi {{username}}, an administrator approved your sign-up for \
+                 <strong>{{instance_name}}</strong>.</p>\
+                 <p><a href=\"{{login_url}}\">Log in to {{instance_name}}</a></p>",
+            ),
+            EmailTemplate::RegistrationRejected => (
+                "Your {{instance_name}} sign-up was not approved",
+                "Hi {{username}}, your sign-up for {{instance_name}} was not approved.\n\n\
+                 Reason: {{reason}}",
+                "<p>Hi {{username}}, your sign-up for <strong>{{instance_name}}</strong> was not approved.</p>\
+                 <p>Reason: {{reason}}</p>",
+            ),
+            EmailTemplate::ModerationNotice => (
+                "A moderation update from {{server_name}}",
+                "Hi {{username}}, {{notice}}\n\n\
+                 Moderator note: {{note}}",
+                "<p>Hi {{username}}, {{notice}}</p>\
+                 <p>Moderator note: {{note}}</p>",
+            ),
+        }
+    }
+}
+
+/// Wraps every HTML body. Overridable as `layout.html`; must contain `{{content}}`.
+const DEFAULT_LAYOUT: &str = "<!DOCTYPE html>\
+<html><body style=\"font-family: sans-serif; line-height: 1.5; color: #222;\">\
+{{content}}\
+<hr style=\"border: none; border-top: 1px solid #ddd;\">\
+<p style=\"font-size: 12px; color: #888;\">Sent by {{instance_name}}</p>\
+</body></html>";
+
+struct TemplateParts {
+    subject: String,
+    text: String,
+    html: String,
+}
+
+pub struct Templates {
+    parts: HashMap<EmailTemplate, TemplateParts>,
+    layout: String,
+}
+
+impl Templates {
+    /// Load the built-in templates, replacing any part that has an override
+    /// file in `dir`.
+    pub fn load(dir: Option<&str>) -> anyhow::Result<Self> {
+        let dir = dir.map(Path::new);
+        let read = |file: String, fallback: &str| -> anyhow::Result<String> {
+            if let Some(dir) = dir {
+                let path = dir.join(&file);
+                if path.exists() {
+                    return Ok(std::fs::read_to_string(&path)?);
+                }
+            }
+            Ok(fallback.to_string())
+        };
+
+        let mut parts = HashMap::new();
+        for &template in EmailTemplate::ALL {
+            let (subject, text, html) = template.defaults();
+            let name = template.name();
+            parts.insert(
+                template,
+                TemplateParts {
+                    subject: read(format!("{name}.subject.txt"), subject)?
+                        .trim()
+                        .to_string(),
+                    text: read(format!("{name}.txt"), text)?,
+                    html: read(format!("{name}.html"), html)?,
+                },
+            );
+        }
+
+        Ok(Self {
+            parts,
+            layout: read("layout.html".into(), DEFAULT_LAYOUT)?,
+        })
+    }
+
+    /// Render (subject, text, html) for `template` with `vars`.
+    pub fn render(&self, template: EmailTemplate, vars: &[(&str, &str)]) -> (String, String, String) {
+        let parts = &self.parts[&template];
+        let subject = substitute(&parts.subject, vars, false);
+        let text = substitute(&parts.text, vars, false);
+        let content = substitute(&parts.html, vars, true);
+
+        // `{{content}}` is not in `vars`, so it survives the first pass untouched
+        let html = substitute(&self.layout, vars, true).replace("{{content}}", &content);
+
+        (subject, text, html)
+    }
+}
+
+/// Replace `{{ key }}` placeholders. Unknown keys are left untouched so a typo
+/// in an override is visible in the output rather than silently dropped.
+/// When escaping, keys ending in `_html` are trusted markup and inserted as-is.
+fn substitute(source: &str, vars: &[(&str, &str)], escape: bool) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            out.push_str(&rest[start..]);
+            return out;
+        };
+        let key = after[..end].trim();
+        match vars.iter().find(|(k, _)| *k == key) {
+            Some((_, value)) if escape && !key.ends_with("_html") => {
+                out.push_str(&escape_html(value))
+            }
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+pub(crate) fn escape_html(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitute_replaces_known_keys() {
+        let out = substitute(
+            "Hi {{name}}, welcome to {{ instance_name }}!",
+            &[("name", "ada"), ("instance_name", "Drocsid")],
+            false,
+        );
+        assert_eq!(out, "Hi ada, welcome to Drocsid!");
+    }
+
+    #[test]
+    fn substitute_leaves_unknown_keys() {
+        let out = substitute("Hello {{missing}}", &[], false);
+        assert_eq!(out, "Hello {{missing}}");
+    }
+
+    #[test]
+    fn substitute_escapes_html_values() {
+        let out = substitute("<b>{{v}}</b>", &[("v", "<script>\"x\"</script>")], true);
+        assert_eq!(out, "<b>&lt;script&gt;&quot;x&quot;&lt;/script&gt;</b>");
+    }
+
+    #[test]
+    fn substitute_keeps_html_suffixed_values_raw() {
+        let out = substitute("{{list_html}}", &[("list_html", "<ul><li>a</li></ul>")], true);
+        assert_eq!(out, "<ul><li>a</li></ul>");
+    }
+
+    #[test]
+    fn built_in_templates_render_with_instance_name() {
+        let templates = Templates::load(None).unwrap();
+        let (subject, text, html) = templates.render(
+            EmailTemplate::EmailVerification,
+            &[("instance_name", "Drocsid"), ("verify_url", "https://x/verify")],
+        );
+        assert_eq!(subject, "Confirm your email for Drocsid");
+        assert!(text.contains("https://x/verify"));
+        assert!(html.contains("Sent by Drocsid"));
+        assert!(!html.contains("{{"));
+    }
+}
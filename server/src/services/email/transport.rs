@@ -0,0 +1,207 @@
+use std::path::PathBuf;
+
+use futures_util::future::BoxFuture;
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+use crate::config::{EmailConfig, EmailTransportKind, SmtpConfig, SmtpTls};
+
+/// A fully rendered message ready to hand to a transport.
+#[derive(Debug, Clone)]
+pub struct RenderedEmail {
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+impl RenderedEmail {
+    fn to_message(&self) -> anyhow::Result<lettre::Message> {
+        let from: Mailbox = self
+            .from
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid from_address: {e}"))?;
+        let to: Mailbox = self
+            .to
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid recipient address: {e}"))?;
+
+        lettre::Message::builder()
+            .from(from)
+            .to(to)
+            .subject(self.subject.clone())
+            .multipart(MultiPart::alternative_plain_html(
+                self.text.clone(),
+                self.html.clone(),
+            ))
+            .map_err(|e| anyhow::anyhow!("Failed to build email: {e}"))
+    }
+}
+
+/// A delivery backend. Implementations only move bytes; rendering happens in
+/// [`super::Mailer`] so every transport sends identical content.
+pub trait EmailTransport: Send + Sync {
+    fn send<'a>(&'a self, email: &'a RenderedEmail) -> BoxFuture<'a, anyhow::Result<()>>;
+}
+
+/// Build the transport selected by `email.transport`.
+pub fn from_config(config: &EmailConfig) -> anyhow::Result<Box<dyn EmailTransport>> {
+    Ok(match config.transport {
+        EmailTransportKind::Resend => {
+            let api_key = config
+                .resend_api_key
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("email.resend_api_key is not configured"))?;
+            Box::new(ResendTransport {
+                api_key,
+                http: reqwest::Client::new(),
+            })
+        }
+        EmailTransportKind::Smtp => {
+            let smtp = config
+                .smtp
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("email.smtp is not configured"))?;
+            Box::new(SmtpTransport::new(smtp)?)
+        }
+        EmailTransportKind::Maildir => {
+            let root = config
+                .maildir_path
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("email.maildir_path is not configured"))?;
+            Box::new(MaildirTransport::new(root)?)
+        }
+        EmailTransportKind::Log => Box::new(LogTransport),
+    })
+}
+
+// ── Resend ────────────────────────────────────────────
+
+pub struct ResendTransport {
+    api_key: String,
+    http: reqwest::Client,
+}
+
+impl EmailTransport for ResendTransport {
+    fn send<'a>(&'a self, email: &'a RenderedEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let body = serde_json::json!({
+                "from": email.from,
+                "to": [email.to],
+                "subject": email.subject,
+                "html": email.html,
+                "text": email.text,
+            });
+
+            let response = self
+                .http
+                .post("https://api.resend.com/emails")
+                .header("Authorization", format!("Bearer {}", self.api_key))
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to send email: {e}"))?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                tracing::error!(status = %status, body = %text, "Resend API error");
+                anyhow::bail!("Email delivery failed: {status}");
+            }
+
+            Ok(())
+        })
+    }
+}
+
+// ── SMTP ──────────────────────────────────────────────
+
+pub struct SmtpTransport {
+    inner: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpTransport {
+    pub fn new(config: &SmtpConfig) -> anyhow::Result<Self> {
+        let mut builder = match config.tls {
+            SmtpTls::Starttls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)?,
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        };
+        if let Some(port) = config.port {
+            builder = builder.port(port);
+        }
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        Ok(Self {
+            inner: builder.build(),
+        })
+    }
+}
+
+impl EmailTransport for SmtpTransport {
+    fn send<'a>(&'a self, email: &'a RenderedEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let message = email.to_message()?;
+            self.inner
+                .send(message)
+                .await
+                .map_err(|e| anyhow::anyhow!("SMTP delivery failed: {e}"))?;
+            Ok(())
+        })
+    }
+}
+
+// ── Maildir ───────────────────────────────────────────
+
+/// Development/test backend: delivers into a local Maildir (`tmp/` → `new/`)
+/// that any mail client or test can read.
+pub struct MaildirTransport {
+    root: PathBuf,
+}
+
+impl MaildirTransport {
+    pub fn new(root: &str) -> anyhow::Result<Self> {
+        let root = PathBuf::from(root);
+        for sub in ["tmp", "new", "cur"] {
+            std::fs::create_dir_all(root.join(sub))?;
+        }
+        Ok(Self { root })
+    }
+}
+
+impl EmailTransport for MaildirTransport {
+    fn send<'a>(&'a self, email: &'a RenderedEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let message = email.to_message()?;
+            let filename = format!(
+                "{}.{}.drocsid",
+                chrono::Utc::now().timestamp(),
+                uuid::Uuid::now_v7().simple()
+            );
+            // Write to tmp/ then rename into new/ so readers never see a partial file
+            let tmp_path = self.root.join("tmp").join(&filename);
+            tokio::fs::write(&tmp_path, message.formatted()).await?;
+            tokio::fs::rename(&tmp_path, self.root.join("new").join(&filename)).await?;
+
+            tracing::info!(to = %email.to, subject = %email.subject, file = %filename, "Email delivered to maildir");
+            Ok(())
+        })
+    }
+}
+
+// ── Log ───────────────────────────────────────────────
+
+/// Writes the plain-text body to the server log instead of sending anything.
+pub struct LogTransport;
+
+impl EmailTransport for LogTransport {
+    fn send<'a>(&'a self, email: &'a RenderedEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            tracing::info!(to = %email.to, subject = %email.subject, body = %email.text, "Email (log transport)");
+            Ok(())
+        })
+    }
+}
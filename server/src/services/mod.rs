@@ -1,7 +1,12 @@
+pub mod audit;
 pub mod auth;
+pub mod automod;
+pub mod digest;
 pub mod email;
 pub mod log_broadcast;
+pub mod mod_log;
 pub mod permissions;
 pub mod push;
+pub mod safety;
 pub mod scheduler;
 pub mod uploads;
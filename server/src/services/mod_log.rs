@@ -0,0 +1,283 @@
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::automod as automod_service;
+use crate::state::AppState;
+use crate::types::entities::{AuditAction, AuditLogEntry, Channel, Message, ServerLogSettings};
+
+/// Author name shown on mod log posts
+const AUTHOR_NAME: &str = "Mod Log";
+/// Longest excerpt of a message quoted in a log post
+const MAX_QUOTE_CHARS: usize = 1500;
+/// Longest rendering of a single changed value
+const MAX_VALUE_CHARS: usize = 200;
+/// Most changed fields listed for one audit entry
+const MAX_CHANGE_LINES: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogCategory {
+    Moderation,
+    MemberJoins,
+    MemberLeaves,
+    MessageEdits,
+    MessageDeletes,
+}
+
+impl LogCategory {
+    fn enabled(self, settings: &ServerLogSettings) -> bool {
+        match self {
+            LogCategory::Moderation => settings.log_moderation,
+            LogCategory::MemberJoins => settings.log_member_joins,
+            LogCategory::MemberLeaves => settings.log_member_leaves,
+            LogCategory::MessageEdits => settings.log_message_edits,
+            LogCategory::MessageDeletes => settings.log_message_deletes,
+        }
+    }
+}
+
+// ── Formatting ────────────────────────────────────────
+
+/// Quote message content as a block, cut down to a readable length.
+fn quote(content: &str) -> String {
+    if content.is_empty() {
+        return "> *(no text)*".to_string();
+    }
+    let excerpt = match content.char_indices().nth(MAX_QUOTE_CHARS) {
+        Some((idx, _)) => format!("{}…", &content[..idx]),
+        None => content.to_string(),
+    };
+    format!("> {}", excerpt.replace('\n', "\n> "))
+}
+
+fn render_value(value: &Value) -> String {
+    let text = match value {
+        Value::Null => "none".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let text = text.replace('\n', " ").replace('`', "'");
+    match text.char_indices().nth(MAX_VALUE_CHARS) {
+        Some((idx, _)) => format!("{}…", &text[..idx]),
+        None => text,
+    }
+}
+
+/// Actions whose target is a member, so it can be shown as a mention.
+fn targets_member(action: AuditAction) -> bool {
+    matches!(
+        action,
+        AuditAction::MemberKick
+            | AuditAction::MemberBan
+            | AuditAction::MemberUnban
+            | AuditAction::MemberTimeout
+            | AuditAction::MemberRoleAdd
+            | AuditAction::MemberRoleRemove
+            | AuditAction::ServerOwnerTransfer
+    )
+}
+
+pub fn format_audit_entry(entry: &AuditLogEntry) -> String {
+    let mut out = format!(
+        "**{}** by <@{}>",
+        audit_service::action_name(entry.action).replace('_', " "),
+        entry.user_id
+    );
+    match entry.target_id {
+        Some(target) if targets_member(entry.action) => out.push_str(&format!(" on <@{target}>")),
+        Some(target) => out.push_str(&format!(" on `{target}`")),
+        None => {}
+    }
+    if let Some(reason) = entry.reason.as_deref().filter(|r| !r.is_empty()) {
+        out.push_str(&format!("\nReason: {}", render_value(&Value::from(reason))));
+    }
+
+    if let Some(Value::Object(changes)) = &entry.changes {
+        for (field, value) in changes.iter().take(MAX_CHANGE_LINES) {
+            let line = match value.as_object() {
+                Some(diff) if diff.contains_key("old") && diff.contains_key("new") => format!(
+                    "\n• `{field}`: {} → {}",
+                    render_value(&diff["old"]),
+                    render_value(&diff["new"])
+                ),
+                _ => format!("\n• `{field}`: {}", render_value(value)),
+            };
+            out.push_str(&line);
+        }
+        if changes.len() > MAX_CHANGE_LINES {
+            out.push_str(&format!("\n• …and {} more", changes.len() - MAX_CHANGE_LINES));
+        }
+    }
+    out
+}
+
+pub fn format_message_edit(message: &Message, channel_name: &str, new_content: &str) -> String {
+    format!(
+        "**Message edited** by <@{}> in #{}\n**Before:**\n{}\n**After:**\n{}",
+        message.author_id.unwrap_or_default(),
+        channel_name,
+        quote(message.content.as_deref().unwrap_or_default()),
+        quote(new_content),
+    )
+}
+
+pub fn format_message_delete(message: &Message, channel_name: &str, deleted_by: Uuid) -> String {
+    let author = message.author_id.unwrap_or_default();
+    let by = if deleted_by == author {
+        String::new()
+    } else {
+        format!(" (deleted by <@{deleted_by}>)")
+    };
+    format!(
+        "**Message deleted** from <@{}> in #{}{}\n{}",
+        author,
+        channel_name,
+        by,
+        quote(message.content.as_deref().unwrap_or_default()),
+    )
+}
+
+// ── Posting ───────────────────────────────────────────
+
+/// Post to the server's log channel when `category` is switched on. Runs in
+/// the background so logging never slows down or fails the action. Events
+/// from the log channel itself are skipped.
+fn post(
+    state: &AppState,
+    server_id: Uuid,
+    category: LogCategory,
+    from_channel: Option<Uuid>,
+    content: String,
+) {
+    let state = state.clone();
+    tokio::spawn(async move {
+        if let Err(e) = post_now(&state, server_id, category, from_channel, &content).await {
+            tracing::warn!(server_id = %server_id, error = ?e, "Failed to post to mod log");
+        }
+    });
+}
+
+async fn post_now(
+    state: &AppState,
+    server_id: Uuid,
+    category: LogCategory,
+    from_channel: Option<Uuid>,
+    content: &str,
+) -> Result<(), ApiError> {
+    let Some(settings) = queries::get_server_log_settings(&state.db, server_id).await? else {
+        return Ok(());
+    };
+    let Some(channel_id) = settings.channel_id else {
+        return Ok(());
+    };
+    if !category.enabled(&settings) || from_channel == Some(channel_id) {
+        return Ok(());
+    }
+    automod_service::post_system_message(state, server_id, channel_id, AUTHOR_NAME, content).await
+}
+
+pub fn audit_entry(state: &AppState, entry: &AuditLogEntry) {
+    post(
+        state,
+        entry.server_id,
+        LogCategory::Moderation,
+        None,
+        format_audit_entry(entry),
+    );
+}
+
+pub fn member_join(state: &AppState, server_id: Uuid, user_id: Uuid) {
+    let content = format!("**Member joined**: <@{user_id}>");
+    post(state, server_id, LogCategory::MemberJoins, None, content);
+}
+
+pub fn member_leave(state: &AppState, server_id: Uuid, user_id: Uuid) {
+    let content = format!("**Member left**: <@{user_id}>");
+    post(state, server_id, LogCategory::MemberLeaves, None, content);
+}
+
+/// Log an edit. `message` must still hold the content from before the edit.
+pub fn message_edit(state: &AppState, channel: &Channel, message: &Message, new_content: &str) {
+    let Some(server_id) = channel.server_id else {
+        return;
+    };
+    let content =
+        format_message_edit(message, channel.name.as_deref().unwrap_or("unknown"), new_content);
+    post(state, server_id, LogCategory::MessageEdits, Some(channel.id), content);
+}
+
+pub fn message_delete(state: &AppState, channel: &Channel, message: &Message, deleted_by: Uuid) {
+    let Some(server_id) = channel.server_id else {
+        return;
+    };
+    let content =
+        format_message_delete(message, channel.name.as_deref().unwrap_or("unknown"), deleted_by);
+    post(state, server_id, LogCategory::MessageDeletes, Some(channel.id), content);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn message(content: &str) -> Message {
+        Message {
+            id: Uuid::now_v7(),
+            instance_id: Uuid::nil(),
+            channel_id: Uuid::nil(),
+            author_id: Some(Uuid::from_u128(1)),
+            content: Some(content.to_string()),
+            reply_to_id: None,
+            edited_at: None,
+            pinned: false,
+            mentions: Vec::new(),
+            mention_roles: Vec::new(),
+            mention_everyone: false,
+            silent: false,
+            expires_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn audit_entries_list_reason_and_changes() {
+        let entry = AuditLogEntry {
+            id: Uuid::nil(),
+            server_id: Uuid::nil(),
+            user_id: Uuid::from_u128(1),
+            action: AuditAction::MemberTimeout,
+            target_id: Some(Uuid::from_u128(2)),
+            reason: Some("spam".into()),
+            changes: Some(json!({
+                "communication_disabled_until": { "old": null, "new": "2026-01-01T00:00:00Z" },
+            })),
+            created_at: Utc::now(),
+        };
+        let text = format_audit_entry(&entry);
+        assert!(text.starts_with(&format!(
+            "**member timeout** by <@{}> on <@{}>",
+            Uuid::from_u128(1),
+            Uuid::from_u128(2)
+        )));
+        assert!(text.contains("\nReason: spam"));
+        assert!(text.contains("• `communication_disabled_until`: none → 2026-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn edits_and_deletes_keep_the_original_content() {
+        let original = message("first line\nsecond line");
+        let edit = format_message_edit(&original, "general", "fixed");
+        assert!(edit.contains("**Before:**\n> first line\n> second line\n**After:**\n> fixed"));
+
+        let by_author = format_message_delete(&original, "general", Uuid::from_u128(1));
+        assert!(!by_author.contains("deleted by"));
+        let by_mod = format_message_delete(&original, "general", Uuid::from_u128(9));
+        assert!(by_mod.contains(&format!("(deleted by <@{}>)", Uuid::from_u128(9))));
+
+        let long = "x".repeat(MAX_QUOTE_CHARS + 10);
+        assert!(quote(&long).ends_with('…'));
+    }
+}
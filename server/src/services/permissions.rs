@@ -11,6 +11,8 @@ use crate::types::permissions::Permissions;
 /// 1. Start with @everyone role permissions
 /// 2. OR all the user's additional role permissions
 /// 3. If ADMINISTRATOR is set, return ALL permissions
+///
+/// Members still pending member screening get no server-level permissions.
 pub async fn compute_server_permissions(
     pool: &PgPool,
     server_id: Uuid,
@@ -22,6 +24,10 @@ pub async fn compute_server_permissions(
         return Ok(Permissions::all());
     }
 
+    if queries::is_member_pending(pool, server_id, user_id).await? {
+        return Ok(Permissions::empty());
+    }
+
     let roles = queries::get_server_roles(pool, server_id).await?;
     let member_role_ids = queries::get_member_role_ids(pool, server_id, user_id).await?;
 
@@ -36,6 +42,8 @@ pub async fn compute_server_permissions(
 /// 3. Apply @everyone channel overrides (deny, then allow)
 /// 4. Apply all role overrides for the user's roles (OR together, deny then allow)
 /// 5. Apply member-specific override (deny, then allow)
+///
+/// Members still pending member screening can only read the rules channel.
 pub async fn compute_channel_permissions(
     pool: &PgPool,
     server_id: Uuid,
@@ -48,6 +56,14 @@ pub async fn compute_channel_permissions(
         return Ok(Permissions::all());
     }
 
+    if queries::is_member_pending(pool, server_id, user_id).await? {
+        let screening = queries::get_server_screening(pool, server_id).await?;
+        return Ok(pending_member_permissions(
+            channel_id,
+            screening.and_then(|s| s.rules_channel_id),
+        ));
+    }
+
     let roles = queries::get_server_roles(pool, server_id).await?;
     let member_role_ids = queries::get_member_role_ids(pool, server_id, user_id).await?;
 
@@ -116,6 +132,15 @@ fn compute_base_permissions(roles: &[Role], member_role_ids: &[Uuid]) -> Permiss
     perms
 }
 
+/// What a member who hasn't passed screening may do in a channel.
+fn pending_member_permissions(channel_id: Uuid, rules_channel_id: Option<Uuid>) -> Permissions {
+    if rules_channel_id == Some(channel_id) {
+        Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY
+    } else {
+        Permissions::empty()
+    }
+}
+
 /// Apply channel-level overrides to base permissions.
 fn apply_channel_overrides(
     base: Permissions,
@@ -272,4 +297,26 @@ mod tests {
         let perms = apply_channel_overrides(base, &roles, &[], &overrides, user_id);
         assert!(perms.contains(Permissions::SEND_MESSAGES));
     }
+
+    #[test]
+    fn test_default_everyone_can_post_in_threads() {
+        let everyone_id = Uuid::now_v7();
+        let roles = vec![make_role(everyone_id, Permissions::default().bits(), true)];
+        let base = compute_base_permissions(&roles, &[]);
+        let perms = apply_channel_overrides(base, &roles, &[], &[], Uuid::now_v7());
+        assert!(perms.contains(
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES_IN_THREADS
+        ));
+    }
+
+    #[test]
+    fn test_pending_members_only_read_rules_channel() {
+        let rules = Uuid::now_v7();
+        let other = Uuid::now_v7();
+        let perms = pending_member_permissions(rules, Some(rules));
+        assert!(perms.contains(Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY));
+        assert!(!perms.contains(Permissions::SEND_MESSAGES));
+        assert!(pending_member_permissions(other, Some(rules)).is_empty());
+        assert!(pending_member_permissions(rules, None).is_empty());
+    }
 }
@@ -127,8 +127,33 @@ pub async fn send_push_for_message(
         _ => None,
     };
 
+    let thread = match queries::get_thread_metadata(&state.db, channel_id).await {
+        Ok(thread) => thread,
+        Err(e) => {
+            tracing::error!("Failed to get thread metadata for push: {e}");
+            return;
+        }
+    };
+
     // Collect recipient user IDs
-    let recipient_ids: Vec<Uuid> = if let Some(sid) = server_id {
+    let recipient_ids: Vec<Uuid> = if thread.is_some() {
+        // Thread — only its members, plus anyone mentioned into it
+        match queries::get_thread_member_ids(&state.db, channel_id).await {
+            Ok(mut ids) => {
+                for uid in mentioned_user_ids {
+                    if !ids.contains(uid) {
+                        ids.push(*uid);
+                    }
+                }
+                ids.retain(|uid| *uid != author_id);
+                ids
+            }
+            Err(e) => {
+                tracing::error!("Failed to get thread members for push: {e}");
+                return;
+            }
+        }
+    } else if let Some(sid) = server_id {
         // Server channel — get all members
         match queries::get_server_members(&state.db, sid).await {
             Ok(members) => members
@@ -0,0 +1,325 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::permissions as perm_service;
+use crate::state::AppState;
+use crate::types::entities::{AuditAction, ServerSafetySettings, VerificationLevel};
+use crate::types::events::{RaidAlertEvent, ServerLockdownUpdateEvent};
+use crate::types::permissions::Permissions;
+
+/// Account age required from the medium verification level up
+pub const MIN_ACCOUNT_AGE_SECS: i64 = 5 * 60;
+/// Membership age required at the high verification level
+pub const MIN_MEMBERSHIP_SECS: i64 = 10 * 60;
+
+// ── Pure checks ───────────────────────────────────────
+
+/// Whether a lockdown is in effect at `now`.
+pub fn is_lockdown_active(settings: &ServerSafetySettings, now: DateTime<Utc>) -> bool {
+    settings.lockdown_since.is_some() && settings.lockdown_until.is_none_or(|until| until > now)
+}
+
+/// Whether a member who joined at `joined_at` is held back by the lockdown.
+pub fn is_quarantined(
+    settings: &ServerSafetySettings,
+    joined_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> bool {
+    is_lockdown_active(settings, now)
+        && settings.lockdown_quarantine
+        && settings.lockdown_since.is_some_and(|since| joined_at >= since)
+}
+
+/// Check a member against the server's verification level, returning the
+/// reason they can't post yet.
+pub fn check_verification(
+    level: VerificationLevel,
+    email_verified: bool,
+    account_created_at: DateTime<Utc>,
+    joined_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), String> {
+    if level >= VerificationLevel::Low && !email_verified {
+        return Err("This server requires a verified email address to post".into());
+    }
+    if level >= VerificationLevel::Medium
+        && (now - account_created_at).num_seconds() < MIN_ACCOUNT_AGE_SECS
+    {
+        return Err(format!(
+            "Your account must be at least {} minutes old to post in this server",
+            MIN_ACCOUNT_AGE_SECS / 60
+        ));
+    }
+    if level >= VerificationLevel::High && (now - joined_at).num_seconds() < MIN_MEMBERSHIP_SECS {
+        return Err(format!(
+            "You must be a member of this server for {} minutes before posting",
+            MIN_MEMBERSHIP_SECS / 60
+        ));
+    }
+    Ok(())
+}
+
+// ── Enforcement ───────────────────────────────────────
+
+/// Refuse new members while a lockdown has invites paused.
+pub async fn ensure_invites_open(pool: &PgPool, server_id: Uuid) -> Result<(), ApiError> {
+    let settings = queries::get_server_safety_settings(pool, server_id).await?;
+    if settings.is_some_and(|s| is_lockdown_active(&s, Utc::now()) && s.lockdown_pause_invites) {
+        return Err(ApiError::InvalidInput(
+            "This server isn't accepting new members right now".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject posting when the member doesn't meet the verification level or
+/// joined during a lockdown. The owner and members with any role are
+/// trusted, so moderators can let someone through by giving them a role.
+pub async fn ensure_can_post(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), ApiError> {
+    let Some(settings) = queries::get_server_safety_settings(&state.db, server_id).await? else {
+        return Ok(());
+    };
+    let now = Utc::now();
+    if settings.verification_level == VerificationLevel::None
+        && !(is_lockdown_active(&settings, now) && settings.lockdown_quarantine)
+    {
+        return Ok(());
+    }
+
+    let Some(member) = queries::get_server_member(&state.db, server_id, user_id).await? else {
+        return Ok(());
+    };
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+    if server.owner_id == user_id
+        || !queries::get_member_role_ids(&state.db, server_id, user_id)
+            .await?
+            .is_empty()
+    {
+        return Ok(());
+    }
+
+    if is_quarantined(&settings, member.joined_at, now) {
+        return Err(ApiError::InvalidInput(
+            "This server is in lockdown; new members can't post until a moderator lets them in"
+                .into(),
+        ));
+    }
+
+    let user = queries::get_user_by_id(&state.db, user_id)
+        .await?
+        .ok_or(ApiError::NotFound("User"))?;
+    check_verification(
+        settings.verification_level,
+        user.email_verified,
+        user.created_at,
+        member.joined_at,
+        now,
+    )
+    .map_err(ApiError::InvalidInput)
+}
+
+// ── Raid detection ────────────────────────────────────
+
+/// Count a join towards the raid thresholds and lock the server down when
+/// one is crossed. Failures are logged rather than failing the join.
+pub async fn record_join(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+    invite_code: Option<&str>,
+) {
+    if let Err(e) = detect_raid(state, server_id, user_id, invite_code).await {
+        tracing::error!(server_id = %server_id, error = ?e, "Raid detection failed");
+    }
+}
+
+async fn detect_raid(
+    state: &AppState,
+    server_id: Uuid,
+    user_id: Uuid,
+    invite_code: Option<&str>,
+) -> Result<(), ApiError> {
+    let Some(settings) = queries::get_server_safety_settings(&state.db, server_id).await? else {
+        return Ok(());
+    };
+    if !settings.raid_protection {
+        return Ok(());
+    }
+
+    let window = settings.raid_window_secs;
+    let server_joins = count_join(state, &format!("raid_joins:{server_id}"), window).await;
+    let invite_joins = match invite_code {
+        Some(code) => count_join(state, &format!("raid_joins:{server_id}:{code}"), window).await,
+        None => 0,
+    };
+
+    let (joins, tripped_invite) = if invite_joins >= settings.raid_invite_threshold as i64 {
+        (invite_joins, invite_code)
+    } else if server_joins >= settings.raid_join_threshold as i64 {
+        (server_joins, None)
+    } else {
+        return Ok(());
+    };
+
+    // Hold back everyone who joined during the window, not just later joins
+    let now = Utc::now();
+    let since = now - chrono::Duration::seconds(window as i64);
+    let until = now + chrono::Duration::seconds(settings.raid_lockdown_secs as i64);
+    let Some(locked) = queries::begin_raid_lockdown(&state.db, server_id, since, until).await?
+    else {
+        return Ok(());
+    };
+
+    tracing::warn!(
+        server_id = %server_id,
+        joins,
+        invite_code = ?tripped_invite,
+        "Raid detected; server locked down"
+    );
+
+    // Audit log (attributed to the join that tripped the threshold)
+    audit_service::record(
+        state,
+        server_id,
+        user_id,
+        AuditAction::RaidDetected,
+        None,
+        None,
+        Some(serde_json::json!({
+            "joins": joins,
+            "window_secs": window,
+            "invite_code": tripped_invite,
+            "lockdown_until": until,
+            "pause_invites": locked.lockdown_pause_invites,
+            "quarantine_joins": locked.lockdown_quarantine,
+        })),
+    )
+    .await;
+
+    broadcast_lockdown(state, &locked);
+
+    let alert = RaidAlertEvent {
+        server_id,
+        joins,
+        window_secs: window,
+        invite_code: tripped_invite.map(str::to_string),
+        lockdown_until: locked.lockdown_until,
+    };
+    let server = queries::get_server_by_id(&state.db, server_id)
+        .await?
+        .ok_or(ApiError::NotFound("Server"))?;
+    for moderator_id in state.gateway.get_online_server_user_ids(server_id) {
+        let perms = perm_service::compute_server_permissions(
+            &state.db,
+            server_id,
+            moderator_id,
+            server.owner_id,
+        )
+        .await?;
+        if perms.intersects(Permissions::MANAGE_SERVER | Permissions::MODERATE_MEMBERS) {
+            state
+                .gateway
+                .dispatch_to_user(moderator_id, "RAID_ALERT", &alert);
+        }
+    }
+
+    Ok(())
+}
+
+/// Fixed-window join counter. Redis errors count as zero so an outage
+/// never blocks joins. The window is created with its expiry in the same
+/// transaction, so a counter can never be left without one.
+async fn count_join(state: &AppState, key: &str, window_secs: i32) -> i64 {
+    let mut redis = state.redis.clone();
+    let (count,): (i64,) = redis::pipe()
+        .atomic()
+        .cmd("SET").arg(key).arg(0).arg("NX").arg("EX").arg(window_secs).ignore()
+        .cmd("INCR").arg(key)
+        .query_async(&mut redis)
+        .await
+        .unwrap_or((0,));
+    count
+}
+
+pub fn broadcast_lockdown(state: &AppState, settings: &ServerSafetySettings) {
+    let event = ServerLockdownUpdateEvent {
+        server_id: settings.server_id,
+        lockdown_since: settings.lockdown_since,
+        lockdown_until: settings.lockdown_until,
+        pause_invites: settings.lockdown_pause_invites,
+        quarantine_joins: settings.lockdown_quarantine,
+    };
+    state.gateway.broadcast_to_server(
+        settings.server_id,
+        "SERVER_LOCKDOWN_UPDATE",
+        &event,
+        None,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn settings(
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> ServerSafetySettings {
+        ServerSafetySettings {
+            server_id: Uuid::nil(),
+            verification_level: VerificationLevel::None,
+            raid_protection: true,
+            raid_join_threshold: 20,
+            raid_invite_threshold: 10,
+            raid_window_secs: 60,
+            raid_pause_invites: true,
+            raid_quarantine_joins: true,
+            raid_lockdown_secs: 1800,
+            lockdown_since: since,
+            lockdown_until: until,
+            lockdown_pause_invites: true,
+            lockdown_quarantine: true,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn lockdown_expires_and_only_catches_later_joins() {
+        let now = Utc::now();
+        let s = settings(Some(now - Duration::minutes(5)), Some(now + Duration::minutes(5)));
+        assert!(is_lockdown_active(&s, now));
+        assert!(is_quarantined(&s, now - Duration::minutes(1), now));
+        assert!(!is_quarantined(&s, now - Duration::minutes(10), now));
+        assert!(!is_lockdown_active(&s, now + Duration::minutes(6)));
+
+        let open_ended = settings(Some(now), None);
+        assert!(is_lockdown_active(&open_ended, now + Duration::days(30)));
+        assert!(!is_lockdown_active(&settings(None, None), now));
+    }
+
+    #[test]
+    fn verification_levels_are_cumulative() {
+        let now = Utc::now();
+        let fresh = now - Duration::minutes(1);
+        let old = now - Duration::days(1);
+
+        assert!(check_verification(VerificationLevel::None, false, fresh, fresh, now).is_ok());
+        assert!(check_verification(VerificationLevel::Low, false, old, old, now).is_err());
+        assert!(check_verification(VerificationLevel::Low, true, fresh, fresh, now).is_ok());
+        assert!(check_verification(VerificationLevel::Medium, true, fresh, old, now).is_err());
+        assert!(check_verification(VerificationLevel::Medium, true, old, fresh, now).is_ok());
+        assert!(check_verification(VerificationLevel::High, true, old, fresh, now).is_err());
+        assert!(check_verification(VerificationLevel::High, true, old, old, now).is_ok());
+    }
+}
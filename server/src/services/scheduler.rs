@@ -1,24 +1,78 @@
 use tokio::time::{interval, Duration};
 use uuid::Uuid;
 
+use crate::api::channels::resolve_post_target;
 use crate::db::queries;
+use crate::error::ApiError;
+use crate::services::audit as audit_service;
+use crate::services::automod as automod_service;
+use crate::services::digest;
+use crate::services::email::EmailTemplate;
 use crate::state::AppState;
-use crate::types::entities::PublicUser;
-use crate::types::events::{DmChannelCreateEvent, MessageCreateEvent, PollCloseEvent};
+use crate::types::entities::{AuditAction, PublicUser};
+use crate::types::events::{
+    BanDeleteEvent, DmChannelCreateEvent, MessageCreateEvent, MessageDeleteBulkEvent,
+    PollCloseEvent, ThreadUpdateEvent,
+};
+
+/// Email digests don't need 30s granularity; run them every 20 ticks (~10 min).
+const DIGEST_EVERY_TICKS: u64 = 20;
+/// Edit history retention is counted in days; purge roughly hourly.
+const REVISION_PURGE_EVERY_TICKS: u64 = 120;
+/// Auto-archive periods are at least an hour; check idle threads every minute.
+const THREAD_ARCHIVE_EVERY_TICKS: u64 = 2;
+/// Disappearing-message timers are set in minutes; purge expired ones every minute.
+const MESSAGE_EXPIRY_EVERY_TICKS: u64 = 2;
+/// Retention periods are counted in days; purge old messages roughly hourly.
+const RETENTION_PURGE_EVERY_TICKS: u64 = 120;
+/// Messages deleted per query while purging.
+const MESSAGE_PURGE_BATCH: i64 = 500;
+/// Most purge batches per kind in one run; the rest wait for the next run.
+const MAX_PURGE_BATCHES_PER_RUN: usize = 10;
 
 /// Spawn the scheduled message processor.
 /// Runs until the server shuts down.
 pub fn spawn_scheduler(state: AppState) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         let mut ticker = interval(Duration::from_secs(30));
+        let mut ticks: u64 = 0;
         loop {
             ticker.tick().await;
+            ticks += 1;
             if let Err(e) = process_due_messages(&state).await {
                 tracing::error!(error = %e, "Scheduler: failed to process due messages");
             }
             if let Err(e) = process_expired_polls(&state).await {
                 tracing::error!(error = %e, "Scheduler: failed to process expired polls");
             }
+            if let Err(e) = process_expired_bans(&state).await {
+                tracing::error!(error = %e, "Scheduler: failed to process expired bans");
+            }
+            if ticks.is_multiple_of(THREAD_ARCHIVE_EVERY_TICKS)
+                && let Err(e) = process_idle_threads(&state).await
+            {
+                tracing::error!(error = %e, "Scheduler: failed to archive idle threads");
+            }
+            if ticks.is_multiple_of(MESSAGE_EXPIRY_EVERY_TICKS)
+                && let Err(e) = process_expired_messages(&state).await
+            {
+                tracing::error!(error = %e, "Scheduler: failed to purge expired messages");
+            }
+            if ticks.is_multiple_of(RETENTION_PURGE_EVERY_TICKS)
+                && let Err(e) = process_message_retention(&state).await
+            {
+                tracing::error!(error = %e, "Scheduler: failed to apply message retention");
+            }
+            if ticks.is_multiple_of(REVISION_PURGE_EVERY_TICKS)
+                && let Err(e) = process_expired_revisions(&state).await
+            {
+                tracing::error!(error = %e, "Scheduler: failed to purge message revisions");
+            }
+            if ticks.is_multiple_of(DIGEST_EVERY_TICKS)
+                && let Err(e) = process_email_digests(&state).await
+            {
+                tracing::error!(error = %e, "Scheduler: failed to process email digests");
+            }
         }
     })
 }
@@ -35,20 +89,41 @@ async fn process_due_messages(state: &AppState) -> Result<(), anyhow::Error> {
         queries::ensure_local_instance(&state.db, &state.config.instance.domain).await?;
 
     for scheduled in due {
-        // Verify the channel still exists
-        let channel = match queries::get_channel_by_id(&state.db, scheduled.channel_id).await? {
-            Some(ch) => ch,
+        // Verify user still exists
+        let author = match queries::get_user_by_id(&state.db, scheduled.author_id).await? {
+            Some(u) => u,
             None => {
-                // Channel deleted; discard
                 let _ = queries::delete_scheduled_message_by_id(&state.db, scheduled.id).await;
                 continue;
             }
         };
 
-        // Verify user still exists
-        let author = match queries::get_user_by_id(&state.db, scheduled.author_id).await? {
-            Some(u) => u,
-            None => {
+        // Apply the same gates as sending it now: the channel still exists,
+        // permissions, locked threads, timeouts, quarantine, slowmode and AutoMod
+        let gates = async {
+            let (channel, ..) =
+                resolve_post_target(state, scheduled.channel_id, scheduled.author_id).await?;
+            let verdict = automod_service::check_message(
+                state,
+                &channel,
+                scheduled.author_id,
+                false,
+                &scheduled.content,
+            )
+            .await?;
+            Ok::<_, ApiError>((channel, verdict))
+        }
+        .await;
+        let channel = match gates {
+            Ok((channel, verdict)) if !verdict.delete_after_send => channel,
+            // Slowmode runs out on its own; try again next tick
+            Err(ApiError::RateLimited { .. }) => continue,
+            rejected => {
+                tracing::info!(
+                    scheduled_id = %scheduled.id,
+                    reason = ?rejected.err(),
+                    "Scheduler: dropping scheduled message the author can no longer send"
+                );
                 let _ = queries::delete_scheduled_message_by_id(&state.db, scheduled.id).await;
                 continue;
             }
@@ -64,6 +139,7 @@ async fn process_due_messages(state: &AppState) -> Result<(), anyhow::Error> {
             scheduled.author_id,
             &scheduled.content,
             scheduled.reply_to_id,
+            false,
         )
         .await
         {
@@ -82,6 +158,7 @@ async fn process_due_messages(state: &AppState) -> Result<(), anyhow::Error> {
         let _ =
             queries::update_channel_last_message(&state.db, scheduled.channel_id, message_id)
                 .await;
+        let _ = queries::record_thread_activity(&state.db, scheduled.channel_id).await;
 
         // Check if author can use @everyone/@here
         let can_mention_everyone = if let Some(sid) = channel.server_id {
@@ -94,20 +171,40 @@ async fn process_due_messages(state: &AppState) -> Result<(), anyhow::Error> {
         } else { false };
 
         // Parse mentions (same logic as send_message)
-        let mentioned_user_ids = crate::api::channels::parse_mentions(
+        let mentions = crate::api::channels::parse_mentions(
             &state.db,
             &state.gateway,
             &scheduled.content,
             scheduled.author_id,
             channel.server_id,
             can_mention_everyone,
+            None,
         )
         .await;
-        if !mentioned_user_ids.is_empty() {
+        let message = if mentions.is_empty() {
+            message
+        } else {
+            match queries::set_message_mentions(
+                &state.db,
+                message_id,
+                &mentions.users,
+                &mentions.roles,
+                mentions.everyone,
+            )
+            .await
+            {
+                Ok(msg) => msg,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Scheduler: failed to store mentions");
+                    message
+                }
+            }
+        };
+        if !mentions.notified.is_empty() {
             let _ = queries::increment_mention_counts(
                 &state.db,
                 scheduled.channel_id,
-                &mentioned_user_ids,
+                &mentions.notified,
             )
             .await;
         }
@@ -211,3 +308,276 @@ async fn process_expired_polls(state: &AppState) -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+async fn process_idle_threads(state: &AppState) -> Result<(), anyhow::Error> {
+    let archived = queries::archive_idle_threads(&state.db).await?;
+    if archived.is_empty() {
+        return Ok(());
+    }
+
+    tracing::info!(count = archived.len(), "Scheduler: auto-archived idle threads");
+
+    for metadata in archived {
+        let Some(channel) = queries::get_channel_by_id(&state.db, metadata.channel_id).await?
+        else {
+            continue;
+        };
+        let parent_channel_id = metadata.parent_channel_id;
+        let event = ThreadUpdateEvent {
+            server_id: channel.server_id,
+            channel,
+            metadata,
+        };
+        crate::api::channels::dispatch_thread_event(
+            state,
+            event.server_id,
+            parent_channel_id,
+            "THREAD_UPDATE",
+            &event,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn process_expired_revisions(state: &AppState) -> Result<(), anyhow::Error> {
+    let deleted = queries::delete_expired_message_revisions(&state.db).await?;
+    if deleted > 0 {
+        tracing::info!(count = deleted, "Scheduler: purged expired message revisions");
+    }
+    Ok(())
+}
+
+async fn process_expired_messages(state: &AppState) -> Result<(), anyhow::Error> {
+    let mut total = 0;
+    for _ in 0..MAX_PURGE_BATCHES_PER_RUN {
+        let batch = queries::get_expired_messages(&state.db, MESSAGE_PURGE_BATCH).await?;
+        let done = (batch.len() as i64) < MESSAGE_PURGE_BATCH;
+        total += purge_messages(state, batch).await?;
+        if done {
+            break;
+        }
+    }
+    if total > 0 {
+        tracing::info!(count = total, "Scheduler: purged disappearing messages");
+    }
+    Ok(())
+}
+
+async fn process_message_retention(state: &AppState) -> Result<(), anyhow::Error> {
+    let cap = queries::get_instance_settings(&state.db)
+        .await?
+        .message_retention_days;
+    let mut total = 0;
+
+    if cap > 0 {
+        for _ in 0..MAX_PURGE_BATCHES_PER_RUN {
+            let batch =
+                queries::get_messages_past_instance_retention(&state.db, cap, MESSAGE_PURGE_BATCH)
+                    .await?;
+            let done = (batch.len() as i64) < MESSAGE_PURGE_BATCH;
+            total += purge_messages(state, batch).await?;
+            if done {
+                break;
+            }
+        }
+    }
+
+    if queries::any_channel_retention(&state.db).await? {
+        for _ in 0..MAX_PURGE_BATCHES_PER_RUN {
+            let batch =
+                queries::get_messages_past_channel_retention(&state.db, MESSAGE_PURGE_BATCH)
+                    .await?;
+            let done = (batch.len() as i64) < MESSAGE_PURGE_BATCH;
+            total += purge_messages(state, batch).await?;
+            if done {
+                break;
+            }
+        }
+    }
+
+    if total > 0 {
+        tracing::info!(count = total, "Scheduler: purged messages past retention");
+    }
+    Ok(())
+}
+
+/// Delete (message_id, channel_id, server_id) rows along with their
+/// attachment files, then send one MESSAGE_DELETE_BULK per channel.
+/// Reactions, polls and edit history go with the messages via cascades.
+async fn purge_messages(
+    state: &AppState,
+    messages: Vec<(Uuid, Uuid, Option<Uuid>)>,
+) -> Result<u64, anyhow::Error> {
+    if messages.is_empty() {
+        return Ok(0);
+    }
+
+    let ids: Vec<Uuid> = messages.iter().map(|(id, _, _)| *id).collect();
+    let deleted = crate::services::uploads::delete_messages_and_attachments(state, &ids).await?;
+
+    let mut by_channel: std::collections::HashMap<(Uuid, Option<Uuid>), Vec<Uuid>> =
+        std::collections::HashMap::new();
+    for (id, channel_id, server_id) in messages {
+        by_channel.entry((channel_id, server_id)).or_default().push(id);
+    }
+    for ((channel_id, server_id), ids) in by_channel {
+        let event = MessageDeleteBulkEvent {
+            ids,
+            channel_id,
+            server_id,
+        };
+        match server_id {
+            Some(sid) => {
+                state
+                    .gateway
+                    .broadcast_to_server(sid, "MESSAGE_DELETE_BULK", &event, None);
+            }
+            None => {
+                for member in queries::get_dm_members(&state.db, channel_id).await? {
+                    state
+                        .gateway
+                        .dispatch_to_user(member.id, "MESSAGE_DELETE_BULK", &event);
+                }
+            }
+        }
+    }
+
+    Ok(deleted)
+}
+
+async fn process_expired_bans(state: &AppState) -> Result<(), anyhow::Error> {
+    let expired = queries::get_expired_bans(&state.db, 100).await?;
+    if expired.is_empty() {
+        return Ok(());
+    }
+
+    tracing::info!(count = expired.len(), "Scheduler: lifting expired bans");
+
+    for ban in expired {
+        // Skip bans that were lifted or extended since we fetched them
+        if !queries::delete_expired_ban(&state.db, ban.server_id, ban.user_id).await? {
+            continue;
+        }
+
+        // Audit log (attributed to the moderator who issued the ban)
+        audit_service::record(
+            state,
+            ban.server_id,
+            ban.moderator_id,
+            AuditAction::MemberUnban,
+            Some(ban.user_id),
+            Some("Temporary ban expired"),
+            None,
+        )
+        .await;
+
+        let event = BanDeleteEvent {
+            server_id: ban.server_id,
+            user_id: ban.user_id,
+        };
+        state
+            .gateway
+            .broadcast_to_server(ban.server_id, "BAN_DELETE", &event, None);
+
+        tracing::info!(
+            server_id = %ban.server_id,
+            user_id = %ban.user_id,
+            "Scheduler: lifted expired ban"
+        );
+    }
+
+    Ok(())
+}
+
+async fn process_email_digests(state: &AppState) -> Result<(), anyhow::Error> {
+    let (Some(mailer), Some(digest_config)) = (state.mailer.as_ref(), state.config.digest.as_ref())
+    else {
+        return Ok(());
+    };
+
+    let threshold_secs = digest_config.offline_threshold_secs.unwrap_or(86400);
+    let default_frequency = digest::default_frequency(digest_config);
+
+    let candidates =
+        queries::get_digest_candidates(&state.db, threshold_secs, default_frequency, 100).await?;
+    if candidates.is_empty() {
+        return Ok(());
+    }
+
+    let mut sent = 0;
+    for candidate in candidates {
+        if state.gateway.is_online(candidate.user_id) {
+            continue;
+        }
+
+        let settings = queries::ensure_digest_settings(
+            &state.db,
+            candidate.user_id,
+            default_frequency,
+            &digest::generate_unsubscribe_token(),
+        )
+        .await?;
+
+        // Only report what arrived since the user was last around or last emailed
+        let since = candidate
+            .last_digest_at
+            .map_or(candidate.last_seen_at, |t| t.max(candidate.last_seen_at));
+
+        let prefs = queries::get_notification_preferences(&state.db, candidate.user_id).await?;
+        let mentions: Vec<_> =
+            queries::get_unread_mentions_since(&state.db, candidate.user_id, since)
+                .await?
+                .into_iter()
+                .filter(|m| !digest::is_suppressed(&prefs, m.channel_id, m.server_id))
+                .collect();
+        let dms: Vec<_> = queries::get_unread_dms_since(&state.db, candidate.user_id, since)
+            .await?
+            .into_iter()
+            .filter(|d| !digest::is_suppressed(&prefs, d.channel_id, None))
+            .collect();
+
+        // Stamp even when there's nothing to send so idle users aren't re-checked every run
+        queries::mark_digest_sent(&state.db, candidate.user_id).await?;
+
+        if mentions.is_empty() && dms.is_empty() {
+            continue;
+        }
+
+        let summary = digest::build_summary(&mentions, &dms);
+        let open_url = mailer.url("/");
+        let unsubscribe_url = mailer.url(&format!(
+            "/api/v1/users/digest/unsubscribe?token={}",
+            settings.unsubscribe_token
+        ));
+
+        match mailer
+            .send(
+                &candidate.email,
+                EmailTemplate::MentionDigest,
+                &[
+                    ("username", candidate.username.as_str()),
+                    ("summary_text", summary.text.as_str()),
+                    ("summary_html", summary.html.as_str()),
+                    ("open_url", open_url.as_str()),
+                    ("unsubscribe_url", unsubscribe_url.as_str()),
+                ],
+            )
+            .await
+        {
+            Ok(()) => sent += 1,
+            Err(e) => tracing::error!(
+                user_id = %candidate.user_id,
+                error = %e,
+                "Scheduler: failed to send digest email"
+            ),
+        }
+    }
+
+    if sent > 0 {
+        tracing::info!(count = sent, "Scheduler: sent email digests");
+    }
+
+    Ok(())
+}
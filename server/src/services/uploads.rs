@@ -1,7 +1,11 @@
 use aws_sdk_s3::primitives::ByteStream;
 
+use uuid::Uuid;
+
 use crate::config::S3Config;
+use crate::db::queries;
 use crate::error::ApiError;
+use crate::state::AppState;
 
 /// Upload bytes to S3/MinIO and return the public file URL.
 pub async fn upload_to_s3(
@@ -29,6 +33,47 @@ pub async fn upload_to_s3(
     Ok(file_url)
 }
 
+/// Delete an uploaded object given the public URL `upload_to_s3` returned.
+/// URLs that don't point into the bucket are ignored.
+pub async fn delete_from_s3(
+    client: &aws_sdk_s3::Client,
+    config: &S3Config,
+    file_url: &str,
+) -> Result<(), ApiError> {
+    let prefix = format!("{}/", config.public_url.trim_end_matches('/'));
+    let Some(object_key) = file_url.strip_prefix(&prefix) else {
+        return Ok(());
+    };
+
+    client
+        .delete_object()
+        .bucket(&config.bucket)
+        .key(object_key)
+        .send()
+        .await
+        .map_err(|e| ApiError::Internal(e.into()))?;
+    Ok(())
+}
+
+/// Delete messages in bulk along with the attachment objects no other message
+/// still references. Returns how many messages were deleted.
+pub async fn delete_messages_and_attachments(
+    state: &AppState,
+    ids: &[Uuid],
+) -> Result<u64, ApiError> {
+    let urls = queries::get_attachment_urls_for_messages(&state.db, ids).await?;
+    let deleted = queries::delete_messages(&state.db, ids).await?;
+
+    if let (Some(s3), Some(s3_config)) = (&state.s3, &state.config.s3) {
+        for url in &urls {
+            if let Err(e) = delete_from_s3(s3, s3_config, url).await {
+                tracing::warn!(url = %url, error = ?e, "Failed to delete attachment");
+            }
+        }
+    }
+    Ok(deleted)
+}
+
 /// Extract a single file from a multipart upload.
 /// Returns (filename, content_type, bytes).
 pub async fn extract_multipart_file(
@@ -4,6 +4,7 @@ use sqlx::PgPool;
 
 use crate::config::AppConfig;
 use crate::gateway::GatewayState;
+use crate::services::email::Mailer;
 use crate::services::push::PushService;
 
 #[derive(Clone)]
@@ -14,6 +15,7 @@ pub struct AppState {
     pub gateway: Arc<GatewayState>,
     pub s3: Option<aws_sdk_s3::Client>,
     pub push: Option<Arc<PushService>>,
+    pub mailer: Option<Arc<Mailer>>,
     pub started_at: std::time::Instant,
     pub log_sender: Option<tokio::sync::broadcast::Sender<String>>,
 }
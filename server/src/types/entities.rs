@@ -14,6 +14,7 @@ pub struct User {
     pub username: String,
     pub display_name: Option<String>,
     pub email: Option<String>,
+    pub email_verified: bool,
     #[serde(skip_serializing)]
     pub password_hash: Option<String>,
     pub avatar_url: Option<String>,
@@ -140,6 +141,8 @@ pub struct Server {
     pub banner_position: i16,
     pub owner_id: Uuid,
     pub default_channel_id: Option<Uuid>,
+    /// Days to keep message edit history; 0 keeps it for the message's lifetime
+    pub edit_history_retention_days: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -155,6 +158,9 @@ pub enum ChannelType {
     Category,
     Dm,
     GroupDm,
+    Forum,
+    Announcement,
+    Stage,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -170,6 +176,12 @@ pub struct Channel {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_message_id: Option<Uuid>,
+    pub rate_limit_per_user: i32,
+    pub require_tag: bool,
+    /// Messages older than this many days are deleted (0 = keep)
+    pub retention_days: i32,
+    /// Disappearing-message timer for new messages (0 = off)
+    pub message_ttl_minutes: i32,
 }
 
 // ── Read States ───────────────────────────────────────
@@ -198,9 +210,31 @@ pub struct Message {
     pub reply_to_id: Option<Uuid>,
     pub edited_at: Option<DateTime<Utc>>,
     pub pinned: bool,
+    /// Users mentioned directly by ID or username
+    pub mentions: Vec<Uuid>,
+    /// Roles mentioned with `<@&role_id>` that the author was allowed to ping
+    pub mention_roles: Vec<Uuid>,
+    /// Whether an @everyone or @here mention went through
+    pub mention_everyone: bool,
+    /// Sent without mention counts or push notifications
+    pub silent: bool,
+    /// When a disappearing message will be deleted
+    pub expires_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
+/// An earlier version of an edited message.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MessageRevision {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub content: Option<String>,
+    /// When this version was posted (or the edit that produced it)
+    pub created_at: DateTime<Utc>,
+    /// When an edit replaced it
+    pub replaced_at: DateTime<Utc>,
+}
+
 // ── Scheduled Messages ────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -317,6 +351,9 @@ pub struct ServerMember {
     pub user_id: Uuid,
     pub nickname: Option<String>,
     pub joined_at: DateTime<Utc>,
+    pub communication_disabled_until: Option<DateTime<Utc>>,
+    /// Hasn't accepted the server's rules screen yet
+    pub pending: bool,
 }
 
 /// Enriched member data with user info and presence for the member list
@@ -326,11 +363,20 @@ pub struct ServerMemberWithUser {
     pub user_id: Uuid,
     pub nickname: Option<String>,
     pub joined_at: DateTime<Utc>,
+    pub communication_disabled_until: Option<DateTime<Utc>>,
+    pub pending: bool,
     pub user: PublicUser,
     pub status: String,
     pub role_ids: Vec<Uuid>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SetMemberTimeoutRequest {
+    /// How long the member can't post for; `None` or 0 lifts the timeout
+    pub duration_secs: Option<i64>,
+    pub reason: Option<String>,
+}
+
 // ── Roles ──────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -380,6 +426,41 @@ pub struct Attachment {
     pub created_at: DateTime<Utc>,
 }
 
+// ── Forwards ──────────────────────────────────────────
+
+/// Snapshot of a forwarded message. The source IDs are never serialized
+/// directly; `origin` is filled in for viewers who can see the source.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct MessageForward {
+    pub message_id: Uuid,
+    #[serde(skip)]
+    pub source_message_id: Option<Uuid>,
+    #[serde(skip)]
+    pub source_channel_id: Option<Uuid>,
+    pub content: Option<String>,
+    pub attachments: sqlx::types::Json<Vec<Attachment>>,
+    pub source_created_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    #[sqlx(skip)]
+    pub origin: Option<ForwardOrigin>,
+}
+
+/// Where a forwarded message came from, for jumping to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ForwardOrigin {
+    pub message_id: Uuid,
+    pub channel_id: Uuid,
+    pub server_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForwardMessageRequest {
+    /// Channel or DM to forward into
+    pub channel_id: Uuid,
+    /// Optional comment posted with the forward
+    pub content: Option<String>,
+}
+
 // ── Reactions ─────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -459,6 +540,49 @@ pub struct ThreadMetadata {
     pub locked: bool,
     pub message_count: i32,
     pub created_at: DateTime<Utc>,
+    pub owner_id: Option<Uuid>,
+    pub auto_archive_minutes: i32,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub last_activity_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ThreadMember {
+    pub channel_id: Uuid,
+    pub user_id: Uuid,
+    pub joined_at: DateTime<Utc>,
+}
+
+// ── Forums ───────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ForumTag {
+    pub id: Uuid,
+    pub channel_id: Uuid,
+    pub name: String,
+    pub emoji: Option<String>,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A forum post as listed in its forum: the thread, its tags and the
+/// reactions on its starter message.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ForumPost {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub metadata: ThreadMetadata,
+    pub name: Option<String>,
+    pub tag_ids: Vec<Uuid>,
+    pub reaction_count: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForumSortOrder {
+    #[default]
+    LatestActivity,
+    CreationDate,
 }
 
 // ── Search ───────────────────────────────────────────
@@ -485,6 +609,74 @@ pub struct RegisterRequest {
     pub email: String,
     pub password: String,
     pub invite_code: Option<String>,
+    /// Answers to the instance's sign-up questionnaire, in question order.
+    #[serde(default)]
+    pub answers: Vec<String>,
+}
+
+// ── Registration Modes ────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "registration_mode", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RegistrationMode {
+    Open,
+    InviteOnly,
+    Approval,
+    Closed,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct InstanceSettings {
+    pub registration_mode: RegistrationMode,
+    pub registration_questions: sqlx::types::Json<Vec<String>>,
+    /// Instance-wide cap on message age in days (0 = no cap)
+    pub message_retention_days: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRegistrationSettingsRequest {
+    pub mode: Option<RegistrationMode>,
+    pub questions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRetentionSettingsRequest {
+    pub message_retention_days: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "registration_request_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RegistrationRequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A questionnaire answer stored with the question as it was asked, so later
+/// edits to the questionnaire don't change what approvers see.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionnaireAnswer {
+    pub question: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct RegistrationRequest {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: Option<String>,
+    pub answers: sqlx::types::Json<Vec<QuestionnaireAnswer>>,
+    pub status: RegistrationRequestStatus,
+    pub user_id: Option<Uuid>,
+    pub reviewer_id: Option<Uuid>,
+    pub review_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
 }
 
 // ── Registration Codes ────────────────────────────────
@@ -548,12 +740,71 @@ pub struct CreateChannelRequest {
 pub struct UpdateChannelRequest {
     pub name: Option<String>,
     pub topic: Option<String>,
+    pub rate_limit_per_user: Option<i32>,
+    pub require_tag: Option<bool>,
+    pub retention_days: Option<i32>,
+    pub message_ttl_minutes: Option<i32>,
+}
+
+/// One entry of a bulk channel reorder. Omitted fields are left as they are;
+/// `parent_id: null` moves the channel out of its category.
+#[derive(Debug, Deserialize)]
+pub struct ChannelPositionUpdate {
+    pub id: Uuid,
+    pub position: Option<i32>,
+    #[serde(default, deserialize_with = "deserialize_some")]
+    pub parent_id: Option<Option<Uuid>>,
+    /// Replace the channel's overrides with its (new) category's
+    #[serde(default)]
+    pub lock_permissions: bool,
+}
+
+/// Deserialize a present field as `Some`, so `null` and a missing field can
+/// be told apart on `Option<Option<T>>`.
+fn deserialize_some<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Mention kinds that can be parsed out of message content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AllowedMentionType {
+    Users,
+    Roles,
+    Everyone,
+}
+
+/// Limits which mentions in a message's content actually ping. Leaving it
+/// off keeps the defaults: user messages parse everything but don't ping
+/// the replied-to author, and webhook messages ping nobody. Sending it
+/// parses only what it lists.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AllowedMentions {
+    #[serde(default)]
+    pub parse: Vec<AllowedMentionType>,
+    /// Users that may be pinged when `users` isn't in `parse`
+    #[serde(default)]
+    pub users: Vec<Uuid>,
+    /// Roles that may be pinged when `roles` isn't in `parse`
+    #[serde(default)]
+    pub roles: Vec<Uuid>,
+    /// Whether a reply pings the author of the message it replies to
+    #[serde(default)]
+    pub replied_user: bool,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct SendMessageRequest {
     pub content: String,
     pub reply_to_id: Option<Uuid>,
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Deliver without mention counts or push notifications
+    #[serde(default)]
+    pub silent: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -612,6 +863,13 @@ pub struct CastVoteRequest {
 pub struct MessageQuery {
     pub before: Option<Uuid>,
     pub after: Option<Uuid>,
+    /// Window centered on this message; takes precedence over before/after
+    pub around: Option<Uuid>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessageLinkQuery {
     pub limit: Option<i64>,
 }
 
@@ -650,6 +908,31 @@ pub struct EditMessageRequest {
     pub content: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BulkDeleteMessagesRequest {
+    pub messages: Vec<Uuid>,
+    pub reason: Option<String>,
+}
+
+/// Filters for a moderator purge. All given filters must match; `limit`
+/// caps how many of the newest matching messages are deleted.
+#[derive(Debug, Deserialize)]
+pub struct PurgeMessagesRequest {
+    pub limit: Option<i64>,
+    pub author_id: Option<Uuid>,
+    #[serde(default)]
+    pub has_links: bool,
+    #[serde(default)]
+    pub has_attachments: bool,
+    pub after: Option<DateTime<Utc>>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkDeleteResponse {
+    pub deleted: u64,
+}
+
 #[derive(Debug, Serialize)]
 pub struct UploadUrlResponse {
     pub upload_url: String,
@@ -697,6 +980,48 @@ pub struct AddGroupDmRecipientsRequest {
 pub struct CreateThreadRequest {
     pub name: String,
     pub message_id: Option<Uuid>,
+    pub auto_archive_minutes: Option<i32>,
+    /// Starter message; required when posting to a forum
+    pub content: Option<String>,
+    /// Forum tags to apply to the post
+    pub tag_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateThreadRequest {
+    pub name: Option<String>,
+    pub archived: Option<bool>,
+    pub locked: Option<bool>,
+    pub auto_archive_minutes: Option<i32>,
+    pub tag_ids: Option<Vec<Uuid>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThreadListQuery {
+    #[serde(default)]
+    pub archived: bool,
+    /// Forums only
+    #[serde(default)]
+    pub sort: ForumSortOrder,
+    /// Forums only
+    pub tag_id: Option<Uuid>,
+    /// Forums only: posts that sort after this post
+    pub before: Option<Uuid>,
+    /// Forums only
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateForumTagRequest {
+    pub name: String,
+    pub emoji: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateForumTagRequest {
+    pub name: Option<String>,
+    pub emoji: Option<String>,
+    pub position: Option<i32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -721,6 +1046,23 @@ pub struct VoiceStateUpdate {
     pub audio_sharing: Option<bool>,
 }
 
+// ── Stage Instances ─────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StageInstance {
+    pub channel_id: Uuid,
+    pub server_id: Uuid,
+    pub topic: String,
+    pub started_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetStageInstanceRequest {
+    pub topic: String,
+}
+
 // ── Invites ─────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -751,6 +1093,7 @@ pub struct Ban {
     pub moderator_id: Uuid,
     pub reason: Option<String>,
     pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -763,6 +1106,10 @@ pub struct BanWithUser {
 #[derive(Debug, Deserialize)]
 pub struct CreateBanRequest {
     pub reason: Option<String>,
+    /// Lift the ban automatically after this long; omit for a permanent ban
+    pub duration_secs: Option<i64>,
+    /// Also delete the user's messages in this server from the last N seconds
+    pub delete_message_seconds: Option<i64>,
 }
 
 // ── Audit Log ───────────────────────────────────────────
@@ -789,6 +1136,29 @@ pub enum AuditAction {
     MessageDelete,
     MessagePin,
     MessageUnpin,
+    ServerOwnerTransfer,
+    AutomodRuleCreate,
+    AutomodRuleUpdate,
+    AutomodRuleDelete,
+    AutomodTrigger,
+    MemberTimeout,
+    ReportResolve,
+    ReportDismiss,
+    ReportEscalate,
+    MessageBulkDelete,
+    ServerSafetyUpdate,
+    ServerLockdown,
+    RaidDetected,
+    ScreeningUpdate,
+    MemberRoleAdd,
+    MemberRoleRemove,
+    ChannelOverrideUpdate,
+    ChannelOverrideDelete,
+    SoundboardSoundCreate,
+    SoundboardSoundDelete,
+    ThreadUpdate,
+    PollClose,
+    LogSettingsUpdate,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -814,10 +1184,32 @@ pub struct AuditLogEntryWithUser {
 pub struct AuditLogQuery {
     pub action: Option<AuditAction>,
     pub user_id: Option<Uuid>,
+    pub target_id: Option<Uuid>,
+    /// Only entries created at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only entries created before this time
+    pub until: Option<DateTime<Utc>>,
     pub before: Option<Uuid>,
     pub limit: Option<i64>,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditExportFormat {
+    Csv,
+    Jsonl,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditLogExportQuery {
+    pub format: AuditExportFormat,
+    pub action: Option<AuditAction>,
+    pub user_id: Option<Uuid>,
+    pub target_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
 // ── Webhooks ────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -830,6 +1222,23 @@ pub struct Webhook {
     pub avatar_url: Option<String>,
     pub token: String,
     pub created_at: DateTime<Utc>,
+    /// Set on follower webhooks: the announcement channel they relay
+    pub source_channel_id: Option<Uuid>,
+}
+
+/// A channel following an announcement channel, as seen from the source
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ChannelFollower {
+    pub webhook_id: Uuid,
+    pub server_id: Uuid,
+    pub channel_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FollowChannelRequest {
+    /// Channel on the follower's server that published messages land in
+    pub webhook_channel_id: Uuid,
 }
 
 #[derive(Debug, Deserialize)]
@@ -848,6 +1257,9 @@ pub struct ExecuteWebhookRequest {
     pub content: String,
     pub username: Option<String>,
     pub avatar_url: Option<String>,
+    pub allowed_mentions: Option<AllowedMentions>,
+    #[serde(default)]
+    pub silent: bool,
 }
 
 // ── Soundboard ──────────────────────────────────────────
@@ -869,3 +1281,310 @@ pub struct SoundboardSound {
 pub struct SetJoinSoundRequest {
     pub sound_id: Uuid,
 }
+
+// ── AutoMod ───────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "automod_trigger_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AutoModTriggerType {
+    Keyword,
+    Regex,
+    InviteLink,
+    MentionSpam,
+    RepeatedMessage,
+    LinkFilter,
+}
+
+/// Trigger configuration. Which fields are used depends on the trigger type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutoModTriggerMetadata {
+    /// Keyword: words or phrases; `*` at either end matches partial words
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Keyword: matches that are exactly one of these are ignored
+    #[serde(default)]
+    pub allow_list: Vec<String>,
+    /// Regex: patterns tested against the whole message
+    #[serde(default)]
+    pub regex_patterns: Vec<String>,
+    /// MentionSpam: number of distinct mentions that triggers the rule
+    pub mention_limit: Option<u32>,
+    /// RepeatedMessage: identical messages allowed within the window
+    pub repeat_limit: Option<u32>,
+    pub repeat_window_secs: Option<u64>,
+    /// LinkFilter: if non-empty, links to any other domain trigger the rule
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+    /// LinkFilter: links to these domains (or their subdomains) trigger the rule
+    #[serde(default)]
+    pub blocked_domains: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AutoModAction {
+    /// Reject the message, showing `custom_message` to the author
+    BlockMessage { custom_message: Option<String> },
+    /// Let the message through, then remove it
+    DeleteMessage,
+    /// Stop the author posting in the server for a while
+    Timeout { duration_secs: i64 },
+    /// Post a notice in a moderator channel
+    SendAlert { channel_id: Uuid },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AutoModRule {
+    pub id: Uuid,
+    pub server_id: Uuid,
+    pub creator_id: Option<Uuid>,
+    pub name: String,
+    pub enabled: bool,
+    pub trigger_type: AutoModTriggerType,
+    pub trigger_metadata: sqlx::types::Json<AutoModTriggerMetadata>,
+    pub actions: sqlx::types::Json<Vec<AutoModAction>>,
+    pub exempt_role_ids: Vec<Uuid>,
+    pub exempt_channel_ids: Vec<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAutoModRuleRequest {
+    pub name: String,
+    pub enabled: Option<bool>,
+    pub trigger_type: AutoModTriggerType,
+    #[serde(default)]
+    pub trigger_metadata: AutoModTriggerMetadata,
+    pub actions: Vec<AutoModAction>,
+    #[serde(default)]
+    pub exempt_role_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub exempt_channel_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateAutoModRuleRequest {
+    pub name: Option<String>,
+    pub enabled: Option<bool>,
+    pub trigger_metadata: Option<AutoModTriggerMetadata>,
+    pub actions: Option<Vec<AutoModAction>>,
+    pub exempt_role_ids: Option<Vec<Uuid>>,
+    pub exempt_channel_ids: Option<Vec<Uuid>>,
+}
+
+// ── Reports ───────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "report_category", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportCategory {
+    Spam,
+    Harassment,
+    Hate,
+    Nsfw,
+    Violence,
+    SelfHarm,
+    Impersonation,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "report_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    Open,
+    Claimed,
+    Resolved,
+    Dismissed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Report {
+    pub id: Uuid,
+    pub server_id: Option<Uuid>,
+    pub reporter_id: Option<Uuid>,
+    pub target_user_id: Option<Uuid>,
+    pub channel_id: Option<Uuid>,
+    pub message_id: Option<Uuid>,
+    pub message_content: Option<String>,
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+    pub status: ReportStatus,
+    pub assignee_id: Option<Uuid>,
+    pub resolution_note: Option<String>,
+    pub action_taken: Option<String>,
+    pub escalated: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateReportRequest {
+    pub category: ReportCategory,
+    pub reason: Option<String>,
+    /// User reports only: the server the behaviour happened in. Without it
+    /// the report goes to the instance admins.
+    pub server_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReportQuery {
+    pub status: Option<ReportStatus>,
+    pub limit: Option<i64>,
+}
+
+/// Follow-up action taken when resolving a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportAction {
+    DeleteMessage,
+    Kick,
+    Ban,
+}
+
+impl ReportAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReportAction::DeleteMessage => "delete_message",
+            ReportAction::Kick => "kick",
+            ReportAction::Ban => "ban",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CloseReportRequest {
+    #[serde(default)]
+    pub actions: Vec<ReportAction>,
+    pub note: Option<String>,
+}
+
+// ── Server Safety ─────────────────────────────────────
+
+/// What a member needs before they can post in a server.
+/// Low: verified email. Medium: also an account older than 5 minutes.
+/// High: also a member of the server for 10 minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "verification_level", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationLevel {
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ServerSafetySettings {
+    pub server_id: Uuid,
+    pub verification_level: VerificationLevel,
+    pub raid_protection: bool,
+    pub raid_join_threshold: i32,
+    pub raid_invite_threshold: i32,
+    pub raid_window_secs: i32,
+    pub raid_pause_invites: bool,
+    pub raid_quarantine_joins: bool,
+    pub raid_lockdown_secs: i32,
+    pub lockdown_since: Option<DateTime<Utc>>,
+    pub lockdown_until: Option<DateTime<Utc>>,
+    pub lockdown_pause_invites: bool,
+    pub lockdown_quarantine: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateServerSafetyRequest {
+    pub verification_level: Option<VerificationLevel>,
+    pub raid_protection: Option<bool>,
+    pub raid_join_threshold: Option<i32>,
+    pub raid_invite_threshold: Option<i32>,
+    pub raid_window_secs: Option<i32>,
+    pub raid_pause_invites: Option<bool>,
+    pub raid_quarantine_joins: Option<bool>,
+    pub raid_lockdown_secs: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetLockdownRequest {
+    pub enabled: bool,
+    /// Refuse invites while locked down (default true)
+    pub pause_invites: Option<bool>,
+    /// Members who join while locked down can't post (default true)
+    pub quarantine_joins: Option<bool>,
+    /// Lift automatically after this long; omit to keep it until lifted
+    pub duration_secs: Option<i64>,
+}
+
+// ── Member Screening ──────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ServerScreening {
+    pub server_id: Uuid,
+    pub enabled: bool,
+    pub description: Option<String>,
+    pub rules: sqlx::types::Json<Vec<String>>,
+    pub questions: sqlx::types::Json<Vec<String>>,
+    pub rules_channel_id: Option<Uuid>,
+    pub accept_role_id: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateScreeningRequest {
+    pub enabled: bool,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<String>,
+    #[serde(default)]
+    pub questions: Vec<String>,
+    pub rules_channel_id: Option<Uuid>,
+    pub accept_role_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AcceptScreeningRequest {
+    /// Answers to the screening questions, in question order.
+    #[serde(default)]
+    pub answers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ScreeningResponse {
+    pub server_id: Uuid,
+    pub user_id: Uuid,
+    pub answers: sqlx::types::Json<Vec<QuestionnaireAnswer>>,
+    pub accepted_at: DateTime<Utc>,
+}
+
+// ── Mod Log ───────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ServerLogSettings {
+    pub server_id: Uuid,
+    pub channel_id: Option<Uuid>,
+    pub log_moderation: bool,
+    pub log_member_joins: bool,
+    pub log_member_leaves: bool,
+    pub log_message_edits: bool,
+    pub log_message_deletes: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetLogSettingsRequest {
+    /// Channel to post to; null turns logging off
+    pub channel_id: Option<Uuid>,
+    /// Audit log entries (default true)
+    pub log_moderation: Option<bool>,
+    /// Members joining (default true)
+    pub log_member_joins: Option<bool>,
+    /// Members leaving (default true)
+    pub log_member_leaves: Option<bool>,
+    /// Edited messages with their previous content (default true)
+    pub log_message_edits: Option<bool>,
+    /// Deleted messages with their content (default true)
+    pub log_message_deletes: Option<bool>,
+}
@@ -1,10 +1,11 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
 use super::entities::{
-    Attachment, ChannelOverride, Message, PublicUser, ReactionGroup, Role, Server, ServerMember,
-    SoundboardSound,
+    Attachment, ChannelOverride, Message, MessageForward, PublicUser, ReactionGroup, Role, Server,
+    ServerMember, SoundboardSound,
 };
 
 // ── Gateway Opcodes ────────────────────────────────────
@@ -124,6 +125,14 @@ pub struct MessageCreateEvent {
     pub author: PublicUser,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct MessageCreateWithForwardEvent {
+    #[serde(flatten)]
+    pub message: Message,
+    pub author: PublicUser,
+    pub forward: MessageForward,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct MessageCreateWithExtrasEvent {
     #[serde(flatten)]
@@ -147,6 +156,13 @@ pub struct MessageDeleteEvent {
     pub server_id: Option<Uuid>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct MessageDeleteBulkEvent {
+    pub ids: Vec<Uuid>,
+    pub channel_id: Uuid,
+    pub server_id: Option<Uuid>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ReactionAddEvent {
     pub message_id: Uuid,
@@ -191,6 +207,12 @@ pub struct ServerMemberRemoveEvent {
     pub user_id: Uuid,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerMemberUpdateEvent {
+    pub server_id: Uuid,
+    pub member: ServerMember,
+}
+
 // ── Role Events ───────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize)]
@@ -227,6 +249,13 @@ pub struct ChannelOverrideUpdateEvent {
     pub overrides: Vec<ChannelOverride>,
 }
 
+/// Channels whose position or category changed in one reorder
+#[derive(Debug, Clone, Serialize)]
+pub struct ChannelUpdateBatchEvent {
+    pub server_id: Uuid,
+    pub channels: Vec<super::entities::Channel>,
+}
+
 // ── Link Collection Events ───────────────────────────
 
 #[derive(Debug, Clone, Serialize)]
@@ -286,6 +315,50 @@ pub struct ThreadCreateEvent {
     pub metadata: super::entities::ThreadMetadata,
     pub parent_channel_id: Uuid,
     pub server_id: Option<Uuid>,
+    /// Forum tags applied to the post; empty for regular threads
+    pub tag_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadUpdateEvent {
+    pub channel: super::entities::Channel,
+    pub metadata: super::entities::ThreadMetadata,
+    pub server_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadMembersUpdateEvent {
+    pub channel_id: Uuid,
+    pub server_id: Option<Uuid>,
+    pub added_user_ids: Vec<Uuid>,
+    pub removed_user_ids: Vec<Uuid>,
+}
+
+// ── Announcement Events ───────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MessagePublishEvent {
+    pub channel_id: Uuid,
+    pub message_id: Uuid,
+    /// Following channels the message was delivered to
+    pub follower_count: usize,
+}
+
+// ── Forum Events ──────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ForumTagsUpdateEvent {
+    pub channel_id: Uuid,
+    pub server_id: Uuid,
+    pub tags: Vec<super::entities::ForumTag>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ForumPostTagsUpdateEvent {
+    pub channel_id: Uuid,
+    pub parent_channel_id: Uuid,
+    pub server_id: Option<Uuid>,
+    pub tag_ids: Vec<Uuid>,
 }
 
 // ── Voice Events ──────────────────────────────────────
@@ -298,6 +371,16 @@ pub struct VoiceStateUpdateEvent {
     pub self_mute: bool,
     pub self_deaf: bool,
     pub audio_sharing: bool,
+    /// Stage channels: in the audience rather than speaking
+    pub suppress: bool,
+    /// Stage channels: pending request to speak
+    pub request_to_speak_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StageInstanceDeleteEvent {
+    pub server_id: Uuid,
+    pub channel_id: Uuid,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -350,6 +433,28 @@ pub struct BanDeleteEvent {
     pub user_id: Uuid,
 }
 
+// ── Server Safety Events ──────────────────────────────
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerLockdownUpdateEvent {
+    pub server_id: Uuid,
+    pub lockdown_since: Option<DateTime<Utc>>,
+    pub lockdown_until: Option<DateTime<Utc>>,
+    pub pause_invites: bool,
+    pub quarantine_joins: bool,
+}
+
+/// Sent to online moderators when raid protection trips.
+#[derive(Debug, Clone, Serialize)]
+pub struct RaidAlertEvent {
+    pub server_id: Uuid,
+    pub joins: i64,
+    pub window_secs: i32,
+    /// Set when a single invite crossed its threshold
+    pub invite_code: Option<String>,
+    pub lockdown_until: Option<DateTime<Utc>>,
+}
+
 // ── Soundboard Events ──────────────────────────────────
 
 #[derive(Debug, Clone, Serialize)]
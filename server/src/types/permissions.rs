@@ -31,6 +31,7 @@ bitflags! {
         const MANAGE_ROLES           = 1 << 28;
         const MANAGE_WEBHOOKS        = 1 << 29;
         const MANAGE_EXPRESSIONS     = 1 << 30;
+        const SEND_ANNOUNCEMENTS     = 1 << 31;
         const MANAGE_THREADS         = 1 << 34;
         const SEND_MESSAGES_IN_THREADS = 1 << 38;
         const MODERATE_MEMBERS       = 1 << 40;
@@ -44,6 +45,7 @@ impl Default for Permissions {
         // Default permissions for @everyone role in a new server
         Self::VIEW_CHANNEL
             | Self::SEND_MESSAGES
+            | Self::SEND_MESSAGES_IN_THREADS
             | Self::READ_MESSAGE_HISTORY
             | Self::ADD_REACTIONS
             | Self::CONNECT